@@ -6,25 +6,1415 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `base64_decode`, `base64_encode`, `calculate_checksum`, `ensure_directory_exists`, `get_encryption_key`, `initialize`, `notes_dir`, `xor_encrypt_decrypt`
 
-Future<bool> saveNoteToDisk({required String title, required String content}) =>
-    RustLib.instance.api.crateApiSaveNoteToDisk(title: title, content: content);
+            // These functions are ignored because they are not marked as `pub`: `base64_decode`, `base64_encode`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
 
-Future<String> loadNoteFromDisk({required String title}) =>
-    RustLib.instance.api.crateApiLoadNoteFromDisk(title: title);
 
-Future<String> listNoteTitles() =>
-    RustLib.instance.api.crateApiListNoteTitles();
+            /// Opens the vault at `root_path`, a directory or database file depending
+/// on `storage`, and returns where it lives.
+Future<String>  openVault({required String rootPath , required StorageKind storage }) => RustLib.instance.api.crateApiOpenVault(rootPath: rootPath, storage: storage);
 
-Future<void> deleteNoteFromDisk({required String title}) =>
-    RustLib.instance.api.crateApiDeleteNoteFromDisk(title: title);
+/// Creates the note when `note.id` is empty, otherwise updates the note
+/// with that id if it is still at `note.revision`. Timestamps and the next
+/// revision are filled in here, not by the caller. For a new note,
+/// `draft_id` is the id its drafts were saved under, and that draft is
+/// discarded; it may be empty.
+Future<SaveResult>  saveNote({required Note note , required String draftId }) => RustLib.instance.api.crateApiSaveNote(note: note, draftId: draftId);
 
-Future<String> encryptText({required String text}) =>
-    RustLib.instance.api.crateApiEncryptText(text: text);
+Future<Note>  getNote({required String id }) => RustLib.instance.api.crateApiGetNote(id: id);
 
-Future<String> decryptText({required String encryptedText}) =>
-    RustLib.instance.api.crateApiDecryptText(encryptedText: encryptedText);
+/// Changes only the title; the id, and with it the file, stays the same.
+Future<Note>  renameNote({required String id , required String newTitle }) => RustLib.instance.api.crateApiRenameNote(id: id, newTitle: newTitle);
 
-Future<String> getNotesDirectory() =>
-    RustLib.instance.api.crateApiGetNotesDirectory();
+Future<List<NoteSummary>>  listNotes() => RustLib.instance.api.crateApiListNotes();
+
+Future<void>  deleteNote({required String id }) => RustLib.instance.api.crateApiDeleteNote(id: id);
+
+Future<String>  encryptText({required String text }) => RustLib.instance.api.crateApiEncryptText(text: text);
+
+Future<String>  decryptText({required String encryptedText }) => RustLib.instance.api.crateApiDecryptText(encryptedText: encryptedText);
+
+Future<String>  getNotesDirectory() => RustLib.instance.api.crateApiGetNotesDirectory();
+
+Future<VaultSettings>  getVaultSettings() => RustLib.instance.api.crateApiGetVaultSettings();
+
+Future<void>  setVaultSettings({required VaultSettings settings }) => RustLib.instance.api.crateApiSetVaultSettings(settings: settings);
+
+Future<List<RevisionInfo>>  listRevisions({required String noteId }) => RustLib.instance.api.crateApiListRevisions(noteId: noteId);
+
+Future<Note>  getRevision({required String noteId , required int revision }) => RustLib.instance.api.crateApiGetRevision(noteId: noteId, revision: revision);
+
+/// Diffs the content of two revisions of the same note, `from` being the
+/// older side.
+Future<List<DiffHunk>>  diffRevisions({required String noteId , required int fromRevision , required int toRevision , required DiffGranularity granularity }) => RustLib.instance.api.crateApiDiffRevisions(noteId: noteId, fromRevision: fromRevision, toRevision: toRevision, granularity: granularity);
+
+/// Brings back an old revision as a new one on top of the history, so the
+/// versions in between stay available.
+Future<Note>  restoreRevision({required String noteId , required int revision }) => RustLib.instance.api.crateApiRestoreRevision(noteId: noteId, revision: revision);
+
+Future<Folder>  createFolder({required String name , String? parentId }) => RustLib.instance.api.crateApiCreateFolder(name: name, parentId: parentId);
+
+Future<Folder>  renameFolder({required String id , required String newName }) => RustLib.instance.api.crateApiRenameFolder(id: id, newName: newName);
+
+Future<Folder>  moveFolder({required String id , String? newParentId }) => RustLib.instance.api.crateApiMoveFolder(id: id, newParentId: newParentId);
+
+/// Refuses to delete a folder that still has notes or subfolders unless
+/// `recursive` is set, in which case all of them are deleted with it.
+Future<void>  deleteFolder({required String id , required bool recursive }) => RustLib.instance.api.crateApiDeleteFolder(id: id, recursive: recursive);
+
+Future<List<FolderInfo>>  listFolders() => RustLib.instance.api.crateApiListFolders();
+
+/// Lists the notes in a folder, or at the top level when `folder_id` is
+/// `None`. With `include_subfolders`, notes in nested folders are included
+/// too, which for `None` means every note in the vault.
+Future<List<NoteSummary>>  listNotesInFolder({String? folderId , required bool includeSubfolders }) => RustLib.instance.api.crateApiListNotesInFolder(folderId: folderId, includeSubfolders: includeSubfolders);
+
+Future<Note>  moveNote({required String id , String? folderId }) => RustLib.instance.api.crateApiMoveNote(id: id, folderId: folderId);
+
+Future<List<TagCount>>  listTags() => RustLib.instance.api.crateApiListTags();
+
+/// Returns the number of notes that were retagged.
+Future<int>  renameTag({required String oldTag , required String newTag }) => RustLib.instance.api.crateApiRenameTag(oldTag: oldTag, newTag: newTag);
+
+/// Folds all of `sources` into `target`. Returns the number of notes that
+/// were retagged.
+Future<int>  mergeTags({required List<String> sources , required String target }) => RustLib.instance.api.crateApiMergeTags(sources: sources, target: target);
+
+Future<List<NoteSummary>>  listNotesByTags({required TagQuery query }) => RustLib.instance.api.crateApiListNotesByTags(query: query);
+
+/// Encrypts the file at `source_path` into the vault and attaches it to the
+/// note. The source file is left untouched.
+Future<Attachment>  addAttachment({required String noteId , required String sourcePath , required String fileName , required String mimeType }) => RustLib.instance.api.crateApiAddAttachment(noteId: noteId, sourcePath: sourcePath, fileName: fileName, mimeType: mimeType);
+
+Future<List<Attachment>>  listAttachments({required String noteId }) => RustLib.instance.api.crateApiListAttachments(noteId: noteId);
+
+/// Returns up to `length` bytes of the attachment starting at `offset`;
+/// fewer when the range runs past the end.
+Future<Uint8List>  readAttachmentRange({required String id , required BigInt offset , required BigInt length }) => RustLib.instance.api.crateApiReadAttachmentRange(id: id, offset: offset, length: length);
+
+/// Writes the decrypted attachment to `destination_path`, replacing any file
+/// there. Returns the number of bytes written.
+Future<BigInt>  exportAttachment({required String id , required String destinationPath }) => RustLib.instance.api.crateApiExportAttachment(id: id, destinationPath: destinationPath);
+
+Future<void>  deleteAttachment({required String id }) => RustLib.instance.api.crateApiDeleteAttachment(id: id);
+
+/// Deletes stored chunks that no note, revision or attachment refers to any
+/// more. Chunks are normally freed as soon as their last user goes; this
+/// also catches those left behind by an interrupted write.
+Future<GcReport>  collectGarbage() => RustLib.instance.api.crateApiCollectGarbage();
+
+/// Streams changes other processes make to the open vault. Changes made
+/// through this API are not reported. A new call ends the previous stream.
+Stream<VaultEvent>  watchVault() => RustLib.instance.api.crateApiWatchVault();
+
+/// Reads every file in the vault and reports what is damaged, orphaned or
+/// out of sync, without changing anything.
+Future<VaultCheckReport>  checkVault() => RustLib.instance.api.crateApiCheckVault();
+
+/// Moves damaged files into a quarantine directory inside the vault,
+/// restores damaged notes from their history where possible, and rebuilds
+/// the index and chunk counts from what survives.
+Future<RepairReport>  repairVault({required RepairOptions options }) => RustLib.instance.api.crateApiRepairVault(options: options);
+
+/// Returns `line_count` lines of the note starting at `first_line`, counted
+/// from 0. A window past the last line comes back empty.
+Future<NoteWindow>  readNoteLines({required String id , required int firstLine , required int lineCount }) => RustLib.instance.api.crateApiReadNoteLines(id: id, firstLine: firstLine, lineCount: lineCount);
+
+/// Returns up to `length` bytes of the note's content starting at `offset`,
+/// without splitting a character: the window's `offset` says where it
+/// actually starts.
+Future<NoteWindow>  readNoteRange({required String id , required BigInt offset , required BigInt length }) => RustLib.instance.api.crateApiReadNoteRange(id: id, offset: offset, length: length);
+
+/// An id for the drafts of a note that has not been saved yet, so that
+/// two such notes being edited keep separate drafts.
+Future<String>  newDraftId() => RustLib.instance.api.crateApiNewDraftId();
+
+/// Stores the editor's current content for `note_id`, or for a new note
+/// under `draft_id` when `note_id` is empty, replacing the previous draft.
+/// Cheap enough to call every few seconds. Saving the note discards its
+/// draft.
+Future<void>  saveDraft({required String noteId , required String draftId , required String title , required String content }) => RustLib.instance.api.crateApiSaveDraft(noteId: noteId, draftId: draftId, title: title, content: content);
+
+/// Drafts left behind by editing sessions that ended without a save, for
+/// offering recovery on launch. Newest first.
+Future<List<Draft>>  pendingDrafts() => RustLib.instance.api.crateApiPendingDrafts();
+
+/// Takes the same ids as `save_draft`.
+Future<void>  discardDraft({required String noteId , required String draftId }) => RustLib.instance.api.crateApiDiscardDraft(noteId: noteId, draftId: draftId);
+
+Future<VaultStats>  vaultStats() => RustLib.instance.api.crateApiVaultStats();
+
+/// Notes matching a query, best match first.
+///
+/// Plain words match the title or content exactly, as the start of a
+/// longer word, or with a typo or two. Quoted phrases must appear as
+/// written. `tag:`, `in:` (a folder name or path, with its subfolders),
+/// `modified:` and `created:` (`2026-01-31`, optionally after `>`, `>=`,
+/// `<` or `<=`, in UTC), `is:pinned`, `is:locked` and `has:attachment`
+/// filter on metadata, and a leading `-` excludes what a clause matches.
+/// Content hidden with `encrypt_text` is not searched.
+Future<SearchResults>  searchNotes({required String query , required int limit }) => RustLib.instance.api.crateApiSearchNotes(query: query, limit: limit);
+
+/// Lists every match of `query` across the vault without changing anything.
+Future<ReplacePreview>  previewReplace({required ReplaceQuery query }) => RustLib.instance.api.crateApiPreviewReplace(query: query);
+
+/// Replaces the `chosen` matches from `preview_replace`, saving each changed
+/// note as a new revision. All notes are written or none are.
+Future<ReplaceReport>  applyReplace({required ReplaceQuery query , required List<ReplaceMatch> chosen }) => RustLib.instance.api.crateApiApplyReplace(query: query, chosen: chosen);
+
+/// Runs `search_notes`, streaming hits as each batch of candidates is
+/// scored, so the first ones show before the whole vault is searched. With
+/// a `limit`, the search stops at the first `limit` hits found, which are
+/// not necessarily the best. A new call, or `cancel_search`, stops the
+/// previous search; its stream then closes without a `done` batch.
+Stream<SearchBatch>  searchStream({required String query , required int limit }) => RustLib.instance.api.crateApiSearchStream(query: query, limit: limit);
+
+Future<void>  cancelSearch() => RustLib.instance.api.crateApiCancelSearch();
+
+/// Up to `limit` titles, tags or folder names with a word starting with
+/// `prefix`, those used most and most recently first. Without a `kind`, all
+/// three are suggested.
+Future<List<Suggestion>>  suggest({required String prefix , SuggestionKind? kind , required int limit }) => RustLib.instance.api.crateApiSuggest(prefix: prefix, kind: kind, limit: limit);
+
+/// Notes that a suggestion, or the note, tag or folder it stands for, was
+/// used, so it ranks higher next time.
+Future<void>  recordSuggestionUse({required SuggestionKind kind , required String id }) => RustLib.instance.api.crateApiRecordSuggestionUse(kind: kind, id: id);
+
+            /// A binary file linked to a note. The contents are encrypted in the vault
+/// and read back in ranges or exported, never handed over whole.
+class Attachment  {
+                final String id;
+final String noteId;
+final String fileName;
+final String mimeType;
+/// Plaintext size in bytes.
+final BigInt size;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 createdAt;
+
+                const Attachment({required this.id ,required this.noteId ,required this.fileName ,required this.mimeType ,required this.size ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^noteId.hashCode^fileName.hashCode^mimeType.hashCode^size.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Attachment &&
+                runtimeType == other.runtimeType
+                && id == other.id&& noteId == other.noteId&& fileName == other.fileName&& mimeType == other.mimeType&& size == other.size&& createdAt == other.createdAt;
+        
+            }
+
+/// Whether note content is compressed before it is encrypted. Compression
+/// makes the stored size depend on what a note says, so vaults where that
+/// side channel matters can turn it off.
+class CompressionSettings  {
+                final bool enabled;
+/// Notes smaller than this many bytes are stored uncompressed.
+final int minNoteSize;
+
+                const CompressionSettings({required this.enabled ,required this.minNoteSize ,});
+
+                static Future<CompressionSettings>  default_()=>RustLib.instance.api.crateApiCompressionSettingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^minNoteSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompressionSettings &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& minNoteSize == other.minNoteSize;
+        
+            }
+
+class DiffChange  {
+                final DiffOp op;
+final String text;
+
+                const DiffChange({required this.op ,required this.text ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => op.hashCode^text.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiffChange &&
+                runtimeType == other.runtimeType
+                && op == other.op&& text == other.text;
+        
+            }
+
+enum DiffGranularity {
+                    line,
+word,
+                    ;
+                    
+                }
+
+/// A run of changes with some unchanged context around it. Starts and
+/// lengths count lines or words, depending on the requested granularity.
+class DiffHunk  {
+                final int oldStart;
+final int oldLen;
+final int newStart;
+final int newLen;
+final List<DiffChange> changes;
+
+                const DiffHunk({required this.oldStart ,required this.oldLen ,required this.newStart ,required this.newLen ,required this.changes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => oldStart.hashCode^oldLen.hashCode^newStart.hashCode^newLen.hashCode^changes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiffHunk &&
+                runtimeType == other.runtimeType
+                && oldStart == other.oldStart&& oldLen == other.oldLen&& newStart == other.newStart&& newLen == other.newLen&& changes == other.changes;
+        
+            }
+
+enum DiffOp {
+                    equal,
+insert,
+delete,
+                    ;
+                    
+                }
+
+/// Editor content that has not been saved yet, kept so that it survives the
+/// app being killed.
+class Draft  {
+                /// The note being edited, or empty for a note that was never saved.
+final String noteId;
+/// For a note that was never saved, the id from `new_draft_id` that
+/// its editor saves the draft under; empty otherwise.
+final String draftId;
+final String title;
+final String content;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 savedAt;
+
+                const Draft({required this.noteId ,required this.draftId ,required this.title ,required this.content ,required this.savedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noteId.hashCode^draftId.hashCode^title.hashCode^content.hashCode^savedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Draft &&
+                runtimeType == other.runtimeType
+                && noteId == other.noteId&& draftId == other.draftId&& title == other.title&& content == other.content&& savedAt == other.savedAt;
+        
+            }
+
+/// A notebook or nested folder. Folders exist only in the vault metadata;
+/// notes point at theirs through `Note::folder_id`.
+class Folder  {
+                final String id;
+final String name;
+/// `None` for top-level folders.
+final String? parentId;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 createdAt;
+
+                const Folder({required this.id ,required this.name ,this.parentId ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^parentId.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Folder &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& parentId == other.parentId&& createdAt == other.createdAt;
+        
+            }
+
+class FolderInfo  {
+                final Folder folder;
+/// Notes directly in this folder.
+final int noteCount;
+/// Notes in this folder and all folders below it.
+final int totalNoteCount;
+
+                const FolderInfo({required this.folder ,required this.noteCount ,required this.totalNoteCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => folder.hashCode^noteCount.hashCode^totalNoteCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FolderInfo &&
+                runtimeType == other.runtimeType
+                && folder == other.folder&& noteCount == other.noteCount&& totalNoteCount == other.totalNoteCount;
+        
+            }
+
+class GcReport  {
+                /// Chunks still used by a note, revision or attachment.
+final int liveChunks;
+final int removedChunks;
+final BigInt freedBytes;
+
+                const GcReport({required this.liveChunks ,required this.removedChunks ,required this.freedBytes ,});
+
+                static Future<GcReport>  default_()=>RustLib.instance.api.crateApiGcReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => liveChunks.hashCode^removedChunks.hashCode^freedBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GcReport &&
+                runtimeType == other.runtimeType
+                && liveChunks == other.liveChunks&& removedChunks == other.removedChunks&& freedBytes == other.freedBytes;
+        
+            }
+
+/// A note with its metadata. The whole struct, not just `content`, is
+/// serialized into the encrypted envelope on disk.
+class Note  {
+                final String id;
+final String title;
+final String content;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 createdAt;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 modifiedAt;
+final List<String> tags;
+final bool pinned;
+/// ARGB value, as used by Flutter's `Color`.
+final int? color;
+/// Set when `content` holds ciphertext from `encrypt_text`.
+final bool contentEncrypted;
+/// Bumped on every save. A save is based on the revision it carries and
+/// is refused if the stored note has moved on since.
+final int revision;
+/// `None` for notes at the top level of the vault.
+final String? folderId;
+
+                const Note({required this.id ,required this.title ,required this.content ,required this.createdAt ,required this.modifiedAt ,required this.tags ,required this.pinned ,this.color ,required this.contentEncrypted ,required this.revision ,this.folderId ,});
+
+                static Future<Note>  default_()=>RustLib.instance.api.crateApiNoteDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^content.hashCode^createdAt.hashCode^modifiedAt.hashCode^tags.hashCode^pinned.hashCode^color.hashCode^contentEncrypted.hashCode^revision.hashCode^folderId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Note &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& content == other.content&& createdAt == other.createdAt&& modifiedAt == other.modifiedAt&& tags == other.tags&& pinned == other.pinned&& color == other.color&& contentEncrypted == other.contentEncrypted&& revision == other.revision&& folderId == other.folderId;
+        
+            }
+
+class NoteStats  {
+                final String id;
+final String title;
+/// Plaintext size of the content.
+final BigInt contentBytes;
+/// The note file plus the chunks its content is in. A chunk shared with
+/// other notes counts in full for each of them.
+final BigInt storedBytes;
+final int revisionCount;
+final BigInt revisionBytes;
+final int attachmentCount;
+/// Plaintext size of the note's attachments.
+final BigInt attachmentBytes;
+
+                const NoteStats({required this.id ,required this.title ,required this.contentBytes ,required this.storedBytes ,required this.revisionCount ,required this.revisionBytes ,required this.attachmentCount ,required this.attachmentBytes ,});
+
+                static Future<NoteStats>  default_()=>RustLib.instance.api.crateApiNoteStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^contentBytes.hashCode^storedBytes.hashCode^revisionCount.hashCode^revisionBytes.hashCode^attachmentCount.hashCode^attachmentBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteStats &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& contentBytes == other.contentBytes&& storedBytes == other.storedBytes&& revisionCount == other.revisionCount&& revisionBytes == other.revisionBytes&& attachmentCount == other.attachmentCount&& attachmentBytes == other.attachmentBytes;
+        
+            }
+
+/// The metadata of a note without its content, as listed from the index.
+class NoteSummary  {
+                final String id;
+final String title;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 createdAt;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 modifiedAt;
+final List<String> tags;
+final bool pinned;
+/// ARGB value, as used by Flutter's `Color`.
+final int? color;
+final String? folderId;
+
+                const NoteSummary({required this.id ,required this.title ,required this.createdAt ,required this.modifiedAt ,required this.tags ,required this.pinned ,this.color ,this.folderId ,});
+
+                static Future<NoteSummary>  default_()=>RustLib.instance.api.crateApiNoteSummaryDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^createdAt.hashCode^modifiedAt.hashCode^tags.hashCode^pinned.hashCode^color.hashCode^folderId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteSummary &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& createdAt == other.createdAt&& modifiedAt == other.modifiedAt&& tags == other.tags&& pinned == other.pinned&& color == other.color&& folderId == other.folderId;
+        
+            }
+
+/// A slice of a note's content, for editors that load large notes lazily.
+class NoteWindow  {
+                final String text;
+/// Byte offset of `text` in the content.
+final BigInt offset;
+/// The line `text` starts on, counted from 0.
+final int firstLine;
+/// Size of the whole content in bytes.
+final BigInt totalSize;
+final int totalLines;
+
+                const NoteWindow({required this.text ,required this.offset ,required this.firstLine ,required this.totalSize ,required this.totalLines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^offset.hashCode^firstLine.hashCode^totalSize.hashCode^totalLines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NoteWindow &&
+                runtimeType == other.runtimeType
+                && text == other.text&& offset == other.offset&& firstLine == other.firstLine&& totalSize == other.totalSize&& totalLines == other.totalLines;
+        
+            }
+
+/// One `repair_vault` run's worth of quarantined files.
+class QuarantineStats  {
+                /// When the repair ran, in milliseconds since the Unix epoch.
+final PlatformInt64 quarantinedAt;
+final int fileCount;
+final BigInt bytes;
+
+                const QuarantineStats({required this.quarantinedAt ,required this.fileCount ,required this.bytes ,});
+
+                static Future<QuarantineStats>  default_()=>RustLib.instance.api.crateApiQuarantineStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => quarantinedAt.hashCode^fileCount.hashCode^bytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QuarantineStats &&
+                runtimeType == other.runtimeType
+                && quarantinedAt == other.quarantinedAt&& fileCount == other.fileCount&& bytes == other.bytes;
+        
+            }
+
+/// A part of a search query that was left out of the search.
+class QueryDiagnostic  {
+                final QueryDiagnosticKind kind;
+final String message;
+/// Where in the query, in UTF-16 code units.
+final int start;
+final int end;
+
+                const QueryDiagnostic({required this.kind ,required this.message ,required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode^start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryDiagnostic &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message&& start == other.start&& end == other.end;
+        
+            }
+
+enum QueryDiagnosticKind {
+                    /// A `"` without a closing one; the rest of the query is the phrase.
+unterminatedQuote,
+/// `name:` is not a filter; the clause is searched for as text.
+unknownField,
+/// A filter such as `tag:` with nothing after it.
+missingValue,
+/// An `is:` or `has:` value that is not supported.
+invalidValue,
+/// A `modified:` or `created:` value that is not a date.
+invalidDate,
+/// An `in:` folder that does not exist; nothing is in it.
+unknownFolder,
+                    ;
+                    
+                }
+
+class RepairOptions  {
+                /// Also delete orphaned revisions, attachments and chunks.
+final bool removeOrphans;
+final bool removeTempFiles;
+
+                const RepairOptions({required this.removeOrphans ,required this.removeTempFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => removeOrphans.hashCode^removeTempFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepairOptions &&
+                runtimeType == other.runtimeType
+                && removeOrphans == other.removeOrphans&& removeTempFiles == other.removeTempFiles;
+        
+            }
+
+class RepairReport  {
+                /// Keys of the files moved out of the way, now below `quarantine_dir`.
+final List<String> quarantined;
+final String quarantineDir;
+/// Notes whose damaged file was replaced by their newest intact revision.
+final List<String> restoredNotes;
+/// Keys of the orphans and temp files deleted.
+final List<String> removed;
+/// What a check still finds afterwards.
+final List<VaultProblem> remaining;
+
+                const RepairReport({required this.quarantined ,required this.quarantineDir ,required this.restoredNotes ,required this.removed ,required this.remaining ,});
+
+                static Future<RepairReport>  default_()=>RustLib.instance.api.crateApiRepairReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => quarantined.hashCode^quarantineDir.hashCode^restoredNotes.hashCode^removed.hashCode^remaining.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepairReport &&
+                runtimeType == other.runtimeType
+                && quarantined == other.quarantined&& quarantineDir == other.quarantineDir&& restoredNotes == other.restoredNotes&& removed == other.removed&& remaining == other.remaining;
+        
+            }
+
+enum ReplaceField {
+                    title,
+content,
+                    ;
+                    
+                }
+
+/// One match of a `ReplaceQuery`, as shown for review and handed back to
+/// `apply_replace` if it should be replaced.
+class ReplaceMatch  {
+                final String noteId;
+final String noteTitle;
+/// The revision the match was found in.
+final int revision;
+final ReplaceField field;
+/// Where the match is in the title or content, in UTF-16 code units
+/// like `TextMatch`.
+final BigInt start;
+final BigInt end;
+/// Counted from 0.
+final int line;
+/// Up to 40 characters of the line before and after the match.
+final String before;
+final String matched;
+final String after;
+/// `matched` with the replacement applied.
+final String replacement;
+
+                const ReplaceMatch({required this.noteId ,required this.noteTitle ,required this.revision ,required this.field ,required this.start ,required this.end ,required this.line ,required this.before ,required this.matched ,required this.after ,required this.replacement ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noteId.hashCode^noteTitle.hashCode^revision.hashCode^field.hashCode^start.hashCode^end.hashCode^line.hashCode^before.hashCode^matched.hashCode^after.hashCode^replacement.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplaceMatch &&
+                runtimeType == other.runtimeType
+                && noteId == other.noteId&& noteTitle == other.noteTitle&& revision == other.revision&& field == other.field&& start == other.start&& end == other.end&& line == other.line&& before == other.before&& matched == other.matched&& after == other.after&& replacement == other.replacement;
+        
+            }
+
+class ReplacePreview  {
+                final List<ReplaceMatch> matches;
+final int notesSearched;
+/// Notes whose content is encrypted with `encrypt_text`, so only their
+/// titles could be searched.
+final List<String> lockedNotes;
+
+                const ReplacePreview({required this.matches ,required this.notesSearched ,required this.lockedNotes ,});
+
+                static Future<ReplacePreview>  default_()=>RustLib.instance.api.crateApiReplacePreviewDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => matches.hashCode^notesSearched.hashCode^lockedNotes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplacePreview &&
+                runtimeType == other.runtimeType
+                && matches == other.matches&& notesSearched == other.notesSearched&& lockedNotes == other.lockedNotes;
+        
+            }
+
+/// A regex find-and-replace over every note.
+class ReplaceQuery  {
+                /// A regular expression in Rust's `regex` syntax.
+final String pattern;
+/// What each match becomes; `$1` or `${name}` insert a capture group.
+final String replacement;
+final bool caseInsensitive;
+/// Also look at titles, not only content.
+final bool includeTitles;
+
+                const ReplaceQuery({required this.pattern ,required this.replacement ,required this.caseInsensitive ,required this.includeTitles ,});
+
+                static Future<ReplaceQuery>  default_()=>RustLib.instance.api.crateApiReplaceQueryDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => pattern.hashCode^replacement.hashCode^caseInsensitive.hashCode^includeTitles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplaceQuery &&
+                runtimeType == other.runtimeType
+                && pattern == other.pattern&& replacement == other.replacement&& caseInsensitive == other.caseInsensitive&& includeTitles == other.includeTitles;
+        
+            }
+
+/// The outcome of `apply_replace`: either `changed_notes` is filled, or
+/// `stale_notes` and `rejected_notes` say why nothing was.
+class ReplaceReport  {
+                final List<NoteSummary> changedNotes;
+final int replacements;
+/// Notes changed or deleted since the preview. Nothing was written;
+/// preview again and choose afresh.
+final List<String> staleNotes;
+/// Notes the replacement would leave with a title `save_note` refuses,
+/// such as an empty one. Nothing was written.
+final List<String> rejectedNotes;
+
+                const ReplaceReport({required this.changedNotes ,required this.replacements ,required this.staleNotes ,required this.rejectedNotes ,});
+
+                static Future<ReplaceReport>  default_()=>RustLib.instance.api.crateApiReplaceReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => changedNotes.hashCode^replacements.hashCode^staleNotes.hashCode^rejectedNotes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplaceReport &&
+                runtimeType == other.runtimeType
+                && changedNotes == other.changedNotes&& replacements == other.replacements&& staleNotes == other.staleNotes&& rejectedNotes == other.rejectedNotes;
+        
+            }
+
+/// How many old revisions of each note the vault keeps.
+class RetentionPolicy  {
+                /// The newest revisions kept no matter how old they are; at least one.
+final int keepLast;
+/// Past `keep_last`, keep the newest revision of each day for this many days.
+final int keepDailyDays;
+/// After the daily window, keep the newest revision of each week for this
+/// many weeks. Anything older is deleted.
+final int keepWeeklyWeeks;
+
+                const RetentionPolicy({required this.keepLast ,required this.keepDailyDays ,required this.keepWeeklyWeeks ,});
+
+                static Future<RetentionPolicy>  default_()=>RustLib.instance.api.crateApiRetentionPolicyDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => keepLast.hashCode^keepDailyDays.hashCode^keepWeeklyWeeks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetentionPolicy &&
+                runtimeType == other.runtimeType
+                && keepLast == other.keepLast&& keepDailyDays == other.keepDailyDays&& keepWeeklyWeeks == other.keepWeeklyWeeks;
+        
+            }
+
+class RevisionInfo  {
+                final int revision;
+/// Milliseconds since the Unix epoch.
+final PlatformInt64 modifiedAt;
+final String title;
+
+                const RevisionInfo({required this.revision ,required this.modifiedAt ,required this.title ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => revision.hashCode^modifiedAt.hashCode^title.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RevisionInfo &&
+                runtimeType == other.runtimeType
+                && revision == other.revision&& modifiedAt == other.modifiedAt&& title == other.title;
+        
+            }
+
+/// Both sides of a save that lost the race against another writer.
+class SaveConflict  {
+                /// The version that was not written.
+final Note ours;
+/// The version currently on disk.
+final Note theirs;
+
+                const SaveConflict({required this.ours ,required this.theirs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ours.hashCode^theirs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SaveConflict &&
+                runtimeType == other.runtimeType
+                && ours == other.ours&& theirs == other.theirs;
+        
+            }
+
+/// The outcome of `save_note`: exactly one of the two fields is set.
+class SaveResult  {
+                /// The note as stored, with its new revision and timestamps.
+final Note? saved;
+final SaveConflict? conflict;
+
+                const SaveResult({this.saved ,this.conflict ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => saved.hashCode^conflict.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SaveResult &&
+                runtimeType == other.runtimeType
+                && saved == other.saved&& conflict == other.conflict;
+        
+            }
+
+/// Part of the results of `search_stream`.
+class SearchBatch  {
+                /// Best first. Batches are scored separately, so a later batch may hold
+/// better hits; merge them by `score`.
+final List<SearchHit> hits;
+/// Only in the first batch.
+final List<QueryDiagnostic> diagnostics;
+/// Set on the last batch, after which the stream closes.
+final bool done;
+
+                const SearchBatch({required this.hits ,required this.diagnostics ,required this.done ,});
+
+                static Future<SearchBatch>  default_()=>RustLib.instance.api.crateApiSearchBatchDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^diagnostics.hashCode^done.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchBatch &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& diagnostics == other.diagnostics&& done == other.done;
+        
+            }
+
+/// A note found by `search_notes`.
+class SearchHit  {
+                final NoteSummary note;
+/// Higher is a better match; only meaningful relative to other hits of
+/// the same search.
+final double score;
+/// Where the query matched in `note.title`.
+final List<TextMatch> titleMatches;
+/// A stretch of the content around the matches, empty if they were only in
+/// the title.
+final String snippet;
+final List<TextMatch> snippetMatches;
+
+                const SearchHit({required this.note ,required this.score ,required this.titleMatches ,required this.snippet ,required this.snippetMatches ,});
+
+                static Future<SearchHit>  default_()=>RustLib.instance.api.crateApiSearchHitDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => note.hashCode^score.hashCode^titleMatches.hashCode^snippet.hashCode^snippetMatches.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && note == other.note&& score == other.score&& titleMatches == other.titleMatches&& snippet == other.snippet&& snippetMatches == other.snippetMatches;
+        
+            }
+
+/// What `search_notes` found, and what it could not make sense of in the
+/// query.
+class SearchResults  {
+                final List<SearchHit> hits;
+final List<QueryDiagnostic> diagnostics;
+
+                const SearchResults({required this.hits ,required this.diagnostics ,});
+
+                static Future<SearchResults>  default_()=>RustLib.instance.api.crateApiSearchResultsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^diagnostics.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResults &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& diagnostics == other.diagnostics;
+        
+            }
+
+/// How a vault is laid out where it is stored.
+enum StorageKind {
+                    /// A directory with one file per note, revision and chunk.
+directory,
+/// Nothing is persisted; the vault is gone when the app exits.
+memory,
+/// A single SQLite database file.
+sqlite,
+                    ;
+                    
+                }
+
+/// Something to complete what is being typed with.
+class Suggestion  {
+                final SuggestionKind kind;
+/// The note title, the tag, or the folder's path from the top.
+final String text;
+/// The note or folder id, or the tag itself. Passed back to
+/// `record_suggestion_use` when the suggestion is picked.
+final String id;
+
+                const Suggestion({required this.kind ,required this.text ,required this.id ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^text.hashCode^id.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Suggestion &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& text == other.text&& id == other.id;
+        
+            }
+
+enum SuggestionKind {
+                    /// A note, for example as the target of a link.
+title,
+tag,
+folder,
+                    ;
+                    
+                }
+
+class TagCount  {
+                final String tag;
+final int count;
+
+                const TagCount({required this.tag ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => tag.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TagCount &&
+                runtimeType == other.runtimeType
+                && tag == other.tag&& count == other.count;
+        
+            }
+
+/// Matches notes that carry every tag in `all_of`, at least one tag in
+/// `any_of` (when it is not empty) and none of the tags in `none_of`. Tags
+/// match ignoring case, as `tag:` does in a search.
+class TagQuery  {
+                final List<String> allOf;
+final List<String> anyOf;
+final List<String> noneOf;
+
+                const TagQuery({required this.allOf ,required this.anyOf ,required this.noneOf ,});
+
+                static Future<TagQuery>  default_()=>RustLib.instance.api.crateApiTagQueryDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => allOf.hashCode^anyOf.hashCode^noneOf.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TagQuery &&
+                runtimeType == other.runtimeType
+                && allOf == other.allOf&& anyOf == other.anyOf&& noneOf == other.noneOf;
+        
+            }
+
+/// A matched word, in UTF-16 code units so it can be passed straight to
+/// Dart's `String.substring`.
+class TextMatch  {
+                final int start;
+final int end;
+
+                const TextMatch({required this.start ,required this.end ,});
+
+                static Future<TextMatch>  default_()=>RustLib.instance.api.crateApiTextMatchDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextMatch &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+class VaultCheckReport  {
+                final int filesChecked;
+final List<VaultProblem> problems;
+
+                const VaultCheckReport({required this.filesChecked ,required this.problems ,});
+
+                static Future<VaultCheckReport>  default_()=>RustLib.instance.api.crateApiVaultCheckReportDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => filesChecked.hashCode^problems.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultCheckReport &&
+                runtimeType == other.runtimeType
+                && filesChecked == other.filesChecked&& problems == other.problems;
+        
+            }
+
+/// What every vault call fails with. `kind` lets Dart tell a vault held by
+/// another process apart from other failures without parsing `message`.
+class VaultError implements FrbException {
+                final VaultErrorKind kind;
+final String message;
+
+                const VaultError({required this.kind ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& message == other.message;
+        
+            }
+
+enum VaultErrorKind {
+                    /// Another process kept the vault locked; retrying later can succeed.
+busy,
+other,
+                    ;
+                    
+                }
+
+/// A change to a note made by another process or device.
+class VaultEvent  {
+                final VaultEventKind kind;
+final String noteId;
+/// The note as it is now; `None` once it is deleted.
+final NoteSummary? summary;
+/// The storage key of a sync tool's conflict copy, if that is what was
+/// found.
+final String? conflictCopy;
+
+                const VaultEvent({required this.kind ,required this.noteId ,this.summary ,this.conflictCopy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^noteId.hashCode^summary.hashCode^conflictCopy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& noteId == other.noteId&& summary == other.summary&& conflictCopy == other.conflictCopy;
+        
+            }
+
+enum VaultEventKind {
+                    created,
+modified,
+deleted,
+/// The note was changed elsewhere on top of the version this app saved,
+/// or a sync tool left a conflicting copy of it next to it.
+conflict,
+                    ;
+                    
+                }
+
+class VaultProblem  {
+                final VaultProblemKind kind;
+/// The storage key of the file concerned.
+final String key;
+final String? noteId;
+final String detail;
+
+                const VaultProblem({required this.kind ,required this.key ,this.noteId ,required this.detail ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^key.hashCode^noteId.hashCode^detail.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultProblem &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& key == other.key&& noteId == other.noteId&& detail == other.detail;
+        
+            }
+
+enum VaultProblemKind {
+                    /// The file does not decrypt, or holds something other than its name
+/// says.
+corruptFile,
+/// The record decrypts, but chunks of its content are missing or damaged.
+missingContent,
+/// A revision whose note is gone.
+orphanedRevision,
+/// An attachment whose note is gone.
+orphanedAttachment,
+/// A stored chunk nothing refers to.
+orphanedChunk,
+/// The index is unreadable or disagrees with the notes.
+indexMismatch,
+/// The chunk reference counts are unreadable or disagree with the records.
+chunkRefsMismatch,
+/// What is left of a write that never completed.
+tempFile,
+/// A file the vault did not write, such as a sync tool's conflict copy.
+unknownFile,
+                    ;
+                    
+                }
+
+/// Per-vault options, stored encrypted next to the notes.
+class VaultSettings  {
+                final RetentionPolicy retention;
+final CompressionSettings compression;
+
+                const VaultSettings({required this.retention ,required this.compression ,});
+
+                static Future<VaultSettings>  default_()=>RustLib.instance.api.crateApiVaultSettingsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => retention.hashCode^compression.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultSettings &&
+                runtimeType == other.runtimeType
+                && retention == other.retention&& compression == other.compression;
+        
+            }
+
+/// Where a vault's bytes go. Sizes are encrypted sizes as stored, unless
+/// said otherwise.
+class VaultStats  {
+                final int noteCount;
+/// Everything the vault stores.
+final BigInt totalBytes;
+/// Chunks hold the content of notes, revisions and attachments, each
+/// stored once however many of them use it.
+final int chunkCount;
+final BigInt chunkBytes;
+final int revisionCount;
+/// The revision records; their content is counted in the chunks.
+final BigInt revisionBytes;
+final int attachmentCount;
+/// Plaintext size of all attachments.
+final BigInt attachmentBytes;
+/// Unsaved editor drafts.
+final int draftCount;
+final BigInt draftBytes;
+/// What `repair_vault` moved aside, one batch per repair, newest first.
+/// Deleted notes are removed at once, so this is the vault's only trash.
+final List<QuarantineStats> quarantine;
+/// Milliseconds since the Unix epoch; `None` in an empty vault.
+final PlatformInt64? oldestModifiedAt;
+final PlatformInt64? newestModifiedAt;
+/// Largest first.
+final List<NoteStats> notes;
+
+                const VaultStats({required this.noteCount ,required this.totalBytes ,required this.chunkCount ,required this.chunkBytes ,required this.revisionCount ,required this.revisionBytes ,required this.attachmentCount ,required this.attachmentBytes ,required this.draftCount ,required this.draftBytes ,required this.quarantine ,this.oldestModifiedAt ,this.newestModifiedAt ,required this.notes ,});
+
+                static Future<VaultStats>  default_()=>RustLib.instance.api.crateApiVaultStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => noteCount.hashCode^totalBytes.hashCode^chunkCount.hashCode^chunkBytes.hashCode^revisionCount.hashCode^revisionBytes.hashCode^attachmentCount.hashCode^attachmentBytes.hashCode^draftCount.hashCode^draftBytes.hashCode^quarantine.hashCode^oldestModifiedAt.hashCode^newestModifiedAt.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VaultStats &&
+                runtimeType == other.runtimeType
+                && noteCount == other.noteCount&& totalBytes == other.totalBytes&& chunkCount == other.chunkCount&& chunkBytes == other.chunkBytes&& revisionCount == other.revisionCount&& revisionBytes == other.revisionBytes&& attachmentCount == other.attachmentCount&& attachmentBytes == other.attachmentBytes&& draftCount == other.draftCount&& draftBytes == other.draftBytes&& quarantine == other.quarantine&& oldestModifiedAt == other.oldestModifiedAt&& newestModifiedAt == other.newestModifiedAt&& notes == other.notes;
+        
+            }
+            
\ No newline at end of file
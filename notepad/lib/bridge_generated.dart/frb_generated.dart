@@ -7,429 +7,3508 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.10.0';
-
-  @override
-  int get rustContentHash => 801344141;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_notepad',
-        ioDirectory: 'native/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<String> crateApiDecryptText({required String encryptedText});
-
-  Future<void> crateApiDeleteNoteFromDisk({required String title});
-
-  Future<String> crateApiEncryptText({required String text});
-
-  Future<String> crateApiGetNotesDirectory();
-
-  Future<String> crateApiListNoteTitles();
-
-  Future<String> crateApiLoadNoteFromDisk({required String title});
-
-  Future<bool> crateApiSaveNoteToDisk({
-    required String title,
-    required String content,
-  });
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<String> crateApiDecryptText({required String encryptedText}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(encryptedText, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.10.0';
+
+                  @override
+                  int get rustContentHash => 703044054;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_notepad',
+                    ioDirectory: 'native/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<Attachment> crateApiAddAttachment({required String noteId , required String sourcePath , required String fileName , required String mimeType });
+
+Future<ReplaceReport> crateApiApplyReplace({required ReplaceQuery query , required List<ReplaceMatch> chosen });
+
+Future<void> crateApiCancelSearch();
+
+Future<VaultCheckReport> crateApiCheckVault();
+
+Future<GcReport> crateApiCollectGarbage();
+
+Future<CompressionSettings> crateApiCompressionSettingsDefault();
+
+Future<Folder> crateApiCreateFolder({required String name , String? parentId });
+
+Future<String> crateApiDecryptText({required String encryptedText });
+
+Future<void> crateApiDeleteAttachment({required String id });
+
+Future<void> crateApiDeleteFolder({required String id , required bool recursive });
+
+Future<void> crateApiDeleteNote({required String id });
+
+Future<List<DiffHunk>> crateApiDiffRevisions({required String noteId , required int fromRevision , required int toRevision , required DiffGranularity granularity });
+
+Future<void> crateApiDiscardDraft({required String noteId , required String draftId });
+
+Future<String> crateApiEncryptText({required String text });
+
+Future<BigInt> crateApiExportAttachment({required String id , required String destinationPath });
+
+Future<GcReport> crateApiGcReportDefault();
+
+Future<Note> crateApiGetNote({required String id });
+
+Future<String> crateApiGetNotesDirectory();
+
+Future<Note> crateApiGetRevision({required String noteId , required int revision });
+
+Future<VaultSettings> crateApiGetVaultSettings();
+
+Future<List<Attachment>> crateApiListAttachments({required String noteId });
+
+Future<List<FolderInfo>> crateApiListFolders();
+
+Future<List<NoteSummary>> crateApiListNotes();
+
+Future<List<NoteSummary>> crateApiListNotesByTags({required TagQuery query });
+
+Future<List<NoteSummary>> crateApiListNotesInFolder({String? folderId , required bool includeSubfolders });
+
+Future<List<RevisionInfo>> crateApiListRevisions({required String noteId });
+
+Future<List<TagCount>> crateApiListTags();
+
+Future<int> crateApiMergeTags({required List<String> sources , required String target });
+
+Future<Folder> crateApiMoveFolder({required String id , String? newParentId });
+
+Future<Note> crateApiMoveNote({required String id , String? folderId });
+
+Future<String> crateApiNewDraftId();
+
+Future<Note> crateApiNoteDefault();
+
+Future<NoteStats> crateApiNoteStatsDefault();
+
+Future<NoteSummary> crateApiNoteSummaryDefault();
+
+Future<String> crateApiOpenVault({required String rootPath , required StorageKind storage });
+
+Future<List<Draft>> crateApiPendingDrafts();
+
+Future<ReplacePreview> crateApiPreviewReplace({required ReplaceQuery query });
+
+Future<QuarantineStats> crateApiQuarantineStatsDefault();
+
+Future<Uint8List> crateApiReadAttachmentRange({required String id , required BigInt offset , required BigInt length });
+
+Future<NoteWindow> crateApiReadNoteLines({required String id , required int firstLine , required int lineCount });
+
+Future<NoteWindow> crateApiReadNoteRange({required String id , required BigInt offset , required BigInt length });
+
+Future<void> crateApiRecordSuggestionUse({required SuggestionKind kind , required String id });
+
+Future<Folder> crateApiRenameFolder({required String id , required String newName });
+
+Future<Note> crateApiRenameNote({required String id , required String newTitle });
+
+Future<int> crateApiRenameTag({required String oldTag , required String newTag });
+
+Future<RepairReport> crateApiRepairReportDefault();
+
+Future<RepairReport> crateApiRepairVault({required RepairOptions options });
+
+Future<ReplacePreview> crateApiReplacePreviewDefault();
+
+Future<ReplaceQuery> crateApiReplaceQueryDefault();
+
+Future<ReplaceReport> crateApiReplaceReportDefault();
+
+Future<Note> crateApiRestoreRevision({required String noteId , required int revision });
+
+Future<RetentionPolicy> crateApiRetentionPolicyDefault();
+
+Future<void> crateApiSaveDraft({required String noteId , required String draftId , required String title , required String content });
+
+Future<SaveResult> crateApiSaveNote({required Note note , required String draftId });
+
+Future<SearchBatch> crateApiSearchBatchDefault();
+
+Future<SearchHit> crateApiSearchHitDefault();
+
+Future<SearchResults> crateApiSearchNotes({required String query , required int limit });
+
+Future<SearchResults> crateApiSearchResultsDefault();
+
+Stream<SearchBatch> crateApiSearchStream({required String query , required int limit });
+
+Future<void> crateApiSetVaultSettings({required VaultSettings settings });
+
+Future<List<Suggestion>> crateApiSuggest({required String prefix , SuggestionKind? kind , required int limit });
+
+Future<TagQuery> crateApiTagQueryDefault();
+
+Future<TextMatch> crateApiTextMatchDefault();
+
+Future<VaultCheckReport> crateApiVaultCheckReportDefault();
+
+Future<VaultSettings> crateApiVaultSettingsDefault();
+
+Future<VaultStats> crateApiVaultStats();
+
+Future<VaultStats> crateApiVaultStatsDefault();
+
+Stream<VaultEvent> crateApiWatchVault();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<Attachment> crateApiAddAttachment({required String noteId , required String sourcePath , required String fileName , required String mimeType })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_String(sourcePath, serializer);
+sse_encode_String(fileName, serializer);
+sse_encode_String(mimeType, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_attachment,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiAddAttachmentConstMeta,
+            argValues: [noteId, sourcePath, fileName, mimeType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddAttachmentConstMeta => const TaskConstMeta(
+            debugName: "add_attachment",
+            argNames: ["noteId", "sourcePath", "fileName", "mimeType"],
+        );
+        
+
+@override Future<ReplaceReport> crateApiApplyReplace({required ReplaceQuery query , required List<ReplaceMatch> chosen })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_replace_query(query, serializer);
+sse_encode_list_replace_match(chosen, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replace_report,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiApplyReplaceConstMeta,
+            argValues: [query, chosen],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApplyReplaceConstMeta => const TaskConstMeta(
+            debugName: "apply_replace",
+            argNames: ["query", "chosen"],
+        );
+        
+
+@override Future<void> crateApiCancelSearch()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiCancelSearchConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelSearchConstMeta => const TaskConstMeta(
+            debugName: "cancel_search",
+            argNames: [],
+        );
+        
+
+@override Future<VaultCheckReport> crateApiCheckVault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_check_report,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiCheckVaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckVaultConstMeta => const TaskConstMeta(
+            debugName: "check_vault",
+            argNames: [],
+        );
+        
+
+@override Future<GcReport> crateApiCollectGarbage()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gc_report,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiCollectGarbageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCollectGarbageConstMeta => const TaskConstMeta(
+            debugName: "collect_garbage",
+            argNames: [],
+        );
+        
+
+@override Future<CompressionSettings> crateApiCompressionSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_compression_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompressionSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompressionSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "compression_settings_default",
+            argNames: [],
+        );
+        
+
+@override Future<Folder> crateApiCreateFolder({required String name , String? parentId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+sse_encode_opt_String(parentId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_folder,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiCreateFolderConstMeta,
+            argValues: [name, parentId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateFolderConstMeta => const TaskConstMeta(
+            debugName: "create_folder",
+            argNames: ["name", "parentId"],
+        );
+        
+
+@override Future<String> crateApiDecryptText({required String encryptedText })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(encryptedText, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDecryptTextConstMeta,
-        argValues: [encryptedText],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDecryptTextConstMeta => const TaskConstMeta(
-    debugName: "decrypt_text",
-    argNames: ["encryptedText"],
-  );
-
-  @override
-  Future<void> crateApiDeleteNoteFromDisk({required String title}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDecryptTextConstMeta,
+            argValues: [encryptedText],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDecryptTextConstMeta => const TaskConstMeta(
+            debugName: "decrypt_text",
+            argNames: ["encryptedText"],
+        );
+        
+
+@override Future<void> crateApiDeleteAttachment({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDeleteNoteFromDiskConstMeta,
-        argValues: [title],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDeleteNoteFromDiskConstMeta => const TaskConstMeta(
-    debugName: "delete_note_from_disk",
-    argNames: ["title"],
-  );
-
-  @override
-  Future<String> crateApiEncryptText({required String text}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiDeleteAttachmentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteAttachmentConstMeta => const TaskConstMeta(
+            debugName: "delete_attachment",
+            argNames: ["id"],
+        );
+        
+
+@override Future<void> crateApiDeleteFolder({required String id , required bool recursive })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_bool(recursive, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiDeleteFolderConstMeta,
+            argValues: [id, recursive],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteFolderConstMeta => const TaskConstMeta(
+            debugName: "delete_folder",
+            argNames: ["id", "recursive"],
+        );
+        
+
+@override Future<void> crateApiDeleteNote({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiDeleteNoteConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteNoteConstMeta => const TaskConstMeta(
+            debugName: "delete_note",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<DiffHunk>> crateApiDiffRevisions({required String noteId , required int fromRevision , required int toRevision , required DiffGranularity granularity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_u_32(fromRevision, serializer);
+sse_encode_u_32(toRevision, serializer);
+sse_encode_diff_granularity(granularity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diff_hunk,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiDiffRevisionsConstMeta,
+            argValues: [noteId, fromRevision, toRevision, granularity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiffRevisionsConstMeta => const TaskConstMeta(
+            debugName: "diff_revisions",
+            argNames: ["noteId", "fromRevision", "toRevision", "granularity"],
+        );
+        
+
+@override Future<void> crateApiDiscardDraft({required String noteId , required String draftId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_String(draftId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiDiscardDraftConstMeta,
+            argValues: [noteId, draftId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDiscardDraftConstMeta => const TaskConstMeta(
+            debugName: "discard_draft",
+            argNames: ["noteId", "draftId"],
+        );
+        
+
+@override Future<String> crateApiEncryptText({required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEncryptTextConstMeta,
-        argValues: [text],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEncryptTextConstMeta =>
-      const TaskConstMeta(debugName: "encrypt_text", argNames: ["text"]);
-
-  @override
-  Future<String> crateApiGetNotesDirectory() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiEncryptTextConstMeta,
+            argValues: [text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEncryptTextConstMeta => const TaskConstMeta(
+            debugName: "encrypt_text",
+            argNames: ["text"],
+        );
+        
+
+@override Future<BigInt> crateApiExportAttachment({required String id , required String destinationPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(destinationPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiExportAttachmentConstMeta,
+            argValues: [id, destinationPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportAttachmentConstMeta => const TaskConstMeta(
+            debugName: "export_attachment",
+            argNames: ["id", "destinationPath"],
+        );
+        
+
+@override Future<GcReport> crateApiGcReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gc_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGcReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGcReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "gc_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<Note> crateApiGetNote({required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiGetNoteConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNoteConstMeta => const TaskConstMeta(
+            debugName: "get_note",
+            argNames: ["id"],
+        );
+        
+
+@override Future<String> crateApiGetNotesDirectory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGetNotesDirectoryConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetNotesDirectoryConstMeta =>
-      const TaskConstMeta(debugName: "get_notes_directory", argNames: []);
-
-  @override
-  Future<String> crateApiListNoteTitles() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiGetNotesDirectoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNotesDirectoryConstMeta => const TaskConstMeta(
+            debugName: "get_notes_directory",
+            argNames: [],
+        );
+        
+
+@override Future<Note> crateApiGetRevision({required String noteId , required int revision })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_u_32(revision, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiGetRevisionConstMeta,
+            argValues: [noteId, revision],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRevisionConstMeta => const TaskConstMeta(
+            debugName: "get_revision",
+            argNames: ["noteId", "revision"],
+        );
+        
+
+@override Future<VaultSettings> crateApiGetVaultSettings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_settings,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiGetVaultSettingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetVaultSettingsConstMeta => const TaskConstMeta(
+            debugName: "get_vault_settings",
+            argNames: [],
+        );
+        
+
+@override Future<List<Attachment>> crateApiListAttachments({required String noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_attachment,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListAttachmentsConstMeta,
+            argValues: [noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListAttachmentsConstMeta => const TaskConstMeta(
+            debugName: "list_attachments",
+            argNames: ["noteId"],
+        );
+        
+
+@override Future<List<FolderInfo>> crateApiListFolders()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_folder_info,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListFoldersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListFoldersConstMeta => const TaskConstMeta(
+            debugName: "list_folders",
+            argNames: [],
+        );
+        
+
+@override Future<List<NoteSummary>> crateApiListNotes()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_note_summary,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListNotesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListNotesConstMeta => const TaskConstMeta(
+            debugName: "list_notes",
+            argNames: [],
+        );
+        
+
+@override Future<List<NoteSummary>> crateApiListNotesByTags({required TagQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_tag_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_note_summary,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListNotesByTagsConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListNotesByTagsConstMeta => const TaskConstMeta(
+            debugName: "list_notes_by_tags",
+            argNames: ["query"],
+        );
+        
+
+@override Future<List<NoteSummary>> crateApiListNotesInFolder({String? folderId , required bool includeSubfolders })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(folderId, serializer);
+sse_encode_bool(includeSubfolders, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_note_summary,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListNotesInFolderConstMeta,
+            argValues: [folderId, includeSubfolders],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListNotesInFolderConstMeta => const TaskConstMeta(
+            debugName: "list_notes_in_folder",
+            argNames: ["folderId", "includeSubfolders"],
+        );
+        
+
+@override Future<List<RevisionInfo>> crateApiListRevisions({required String noteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_revision_info,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListRevisionsConstMeta,
+            argValues: [noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListRevisionsConstMeta => const TaskConstMeta(
+            debugName: "list_revisions",
+            argNames: ["noteId"],
+        );
+        
+
+@override Future<List<TagCount>> crateApiListTags()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_tag_count,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiListTagsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiListTagsConstMeta => const TaskConstMeta(
+            debugName: "list_tags",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiMergeTags({required List<String> sources , required String target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(sources, serializer);
+sse_encode_String(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiMergeTagsConstMeta,
+            argValues: [sources, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMergeTagsConstMeta => const TaskConstMeta(
+            debugName: "merge_tags",
+            argNames: ["sources", "target"],
+        );
+        
+
+@override Future<Folder> crateApiMoveFolder({required String id , String? newParentId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_opt_String(newParentId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_folder,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiMoveFolderConstMeta,
+            argValues: [id, newParentId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoveFolderConstMeta => const TaskConstMeta(
+            debugName: "move_folder",
+            argNames: ["id", "newParentId"],
+        );
+        
+
+@override Future<Note> crateApiMoveNote({required String id , String? folderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_opt_String(folderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiMoveNoteConstMeta,
+            argValues: [id, folderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoveNoteConstMeta => const TaskConstMeta(
+            debugName: "move_note",
+            argNames: ["id", "folderId"],
+        );
+        
+
+@override Future<String> crateApiNewDraftId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiListNoteTitlesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiListNoteTitlesConstMeta =>
-      const TaskConstMeta(debugName: "list_note_titles", argNames: []);
-
-  @override
-  Future<String> crateApiLoadNoteFromDisk({required String title}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiNewDraftIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNewDraftIdConstMeta => const TaskConstMeta(
+            debugName: "new_draft_id",
+            argNames: [],
+        );
+        
+
+@override Future<Note> crateApiNoteDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNoteDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteDefaultConstMeta => const TaskConstMeta(
+            debugName: "note_default",
+            argNames: [],
+        );
+        
+
+@override Future<NoteStats> crateApiNoteStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNoteStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "note_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<NoteSummary> crateApiNoteSummaryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_summary,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiNoteSummaryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiNoteSummaryDefaultConstMeta => const TaskConstMeta(
+            debugName: "note_summary_default",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiOpenVault({required String rootPath , required StorageKind storage })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(rootPath, serializer);
+sse_encode_storage_kind(storage, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiOpenVaultConstMeta,
+            argValues: [rootPath, storage],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenVaultConstMeta => const TaskConstMeta(
+            debugName: "open_vault",
+            argNames: ["rootPath", "storage"],
+        );
+        
+
+@override Future<List<Draft>> crateApiPendingDrafts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_draft,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiPendingDraftsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPendingDraftsConstMeta => const TaskConstMeta(
+            debugName: "pending_drafts",
+            argNames: [],
+        );
+        
+
+@override Future<ReplacePreview> crateApiPreviewReplace({required ReplaceQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_replace_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replace_preview,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiPreviewReplaceConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPreviewReplaceConstMeta => const TaskConstMeta(
+            debugName: "preview_replace",
+            argNames: ["query"],
+        );
+        
+
+@override Future<QuarantineStats> crateApiQuarantineStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_quarantine_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiQuarantineStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiQuarantineStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "quarantine_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<Uint8List> crateApiReadAttachmentRange({required String id , required BigInt offset , required BigInt length })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_64(offset, serializer);
+sse_encode_u_64(length, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiReadAttachmentRangeConstMeta,
+            argValues: [id, offset, length],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadAttachmentRangeConstMeta => const TaskConstMeta(
+            debugName: "read_attachment_range",
+            argNames: ["id", "offset", "length"],
+        );
+        
+
+@override Future<NoteWindow> crateApiReadNoteLines({required String id , required int firstLine , required int lineCount })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_32(firstLine, serializer);
+sse_encode_u_32(lineCount, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_window,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiReadNoteLinesConstMeta,
+            argValues: [id, firstLine, lineCount],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadNoteLinesConstMeta => const TaskConstMeta(
+            debugName: "read_note_lines",
+            argNames: ["id", "firstLine", "lineCount"],
+        );
+        
+
+@override Future<NoteWindow> crateApiReadNoteRange({required String id , required BigInt offset , required BigInt length })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_u_64(offset, serializer);
+sse_encode_u_64(length, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note_window,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiReadNoteRangeConstMeta,
+            argValues: [id, offset, length],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReadNoteRangeConstMeta => const TaskConstMeta(
+            debugName: "read_note_range",
+            argNames: ["id", "offset", "length"],
+        );
+        
+
+@override Future<void> crateApiRecordSuggestionUse({required SuggestionKind kind , required String id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_suggestion_kind(kind, serializer);
+sse_encode_String(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRecordSuggestionUseConstMeta,
+            argValues: [kind, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordSuggestionUseConstMeta => const TaskConstMeta(
+            debugName: "record_suggestion_use",
+            argNames: ["kind", "id"],
+        );
+        
+
+@override Future<Folder> crateApiRenameFolder({required String id , required String newName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(newName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_folder,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRenameFolderConstMeta,
+            argValues: [id, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenameFolderConstMeta => const TaskConstMeta(
+            debugName: "rename_folder",
+            argNames: ["id", "newName"],
+        );
+        
+
+@override Future<Note> crateApiRenameNote({required String id , required String newTitle })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(newTitle, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRenameNoteConstMeta,
+            argValues: [id, newTitle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenameNoteConstMeta => const TaskConstMeta(
+            debugName: "rename_note",
+            argNames: ["id", "newTitle"],
+        );
+        
+
+@override Future<int> crateApiRenameTag({required String oldTag , required String newTag })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(oldTag, serializer);
+sse_encode_String(newTag, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRenameTagConstMeta,
+            argValues: [oldTag, newTag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenameTagConstMeta => const TaskConstMeta(
+            debugName: "rename_tag",
+            argNames: ["oldTag", "newTag"],
+        );
+        
+
+@override Future<RepairReport> crateApiRepairReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_repair_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRepairReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRepairReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "repair_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<RepairReport> crateApiRepairVault({required RepairOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_repair_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_repair_report,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRepairVaultConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRepairVaultConstMeta => const TaskConstMeta(
+            debugName: "repair_vault",
+            argNames: ["options"],
+        );
+        
+
+@override Future<ReplacePreview> crateApiReplacePreviewDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replace_preview,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiReplacePreviewDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReplacePreviewDefaultConstMeta => const TaskConstMeta(
+            debugName: "replace_preview_default",
+            argNames: [],
+        );
+        
+
+@override Future<ReplaceQuery> crateApiReplaceQueryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replace_query,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiReplaceQueryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReplaceQueryDefaultConstMeta => const TaskConstMeta(
+            debugName: "replace_query_default",
+            argNames: [],
+        );
+        
+
+@override Future<ReplaceReport> crateApiReplaceReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replace_report,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLoadNoteFromDiskConstMeta,
-        argValues: [title],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLoadNoteFromDiskConstMeta => const TaskConstMeta(
-    debugName: "load_note_from_disk",
-    argNames: ["title"],
-  );
-
-  @override
-  Future<bool> crateApiSaveNoteToDisk({
-    required String title,
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+        )
+        ,
+            constMeta: kCrateApiReplaceReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiReplaceReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "replace_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<Note> crateApiRestoreRevision({required String noteId , required int revision })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_u_32(revision, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_note,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiRestoreRevisionConstMeta,
+            argValues: [noteId, revision],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRestoreRevisionConstMeta => const TaskConstMeta(
+            debugName: "restore_revision",
+            argNames: ["noteId", "revision"],
+        );
+        
+
+@override Future<RetentionPolicy> crateApiRetentionPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_retention_policy,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSaveNoteToDiskConstMeta,
-        argValues: [title, content],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSaveNoteToDiskConstMeta => const TaskConstMeta(
-    debugName: "save_note_to_disk",
-    argNames: ["title", "content"],
-  );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiRetentionPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetentionPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "retention_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSaveDraft({required String noteId , required String draftId , required String title , required String content })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(noteId, serializer);
+sse_encode_String(draftId, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(content, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSaveDraftConstMeta,
+            argValues: [noteId, draftId, title, content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSaveDraftConstMeta => const TaskConstMeta(
+            debugName: "save_draft",
+            argNames: ["noteId", "draftId", "title", "content"],
+        );
+        
+
+@override Future<SaveResult> crateApiSaveNote({required Note note , required String draftId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_note(note, serializer);
+sse_encode_String(draftId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_save_result,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSaveNoteConstMeta,
+            argValues: [note, draftId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSaveNoteConstMeta => const TaskConstMeta(
+            debugName: "save_note",
+            argNames: ["note", "draftId"],
+        );
+        
+
+@override Future<SearchBatch> crateApiSearchBatchDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_batch,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchBatchDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchBatchDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_batch_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchHit> crateApiSearchHitDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_hit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchHitDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchHitDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_hit_default",
+            argNames: [],
+        );
+        
+
+@override Future<SearchResults> crateApiSearchNotes({required String query , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_results,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSearchNotesConstMeta,
+            argValues: [query, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchNotesConstMeta => const TaskConstMeta(
+            debugName: "search_notes",
+            argNames: ["query", "limit"],
+        );
+        
+
+@override Future<SearchResults> crateApiSearchResultsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_results,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchResultsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchResultsDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_results_default",
+            argNames: [],
+        );
+        
+
+@override Stream<SearchBatch> crateApiSearchStream({required String query , required int limit })  { 
+            final sink = RustStreamSink<SearchBatch>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_u_32(limit, serializer);
+sse_encode_StreamSink_search_batch_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSearchStreamConstMeta,
+            argValues: [query, limit, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSearchStreamConstMeta => const TaskConstMeta(
+            debugName: "search_stream",
+            argNames: ["query", "limit", "sink"],
+        );
+        
+
+@override Future<void> crateApiSetVaultSettings({required VaultSettings settings })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_vault_settings(settings, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSetVaultSettingsConstMeta,
+            argValues: [settings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetVaultSettingsConstMeta => const TaskConstMeta(
+            debugName: "set_vault_settings",
+            argNames: ["settings"],
+        );
+        
+
+@override Future<List<Suggestion>> crateApiSuggest({required String prefix , SuggestionKind? kind , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+sse_encode_opt_box_autoadd_suggestion_kind(kind, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_suggestion,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiSuggestConstMeta,
+            argValues: [prefix, kind, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuggestConstMeta => const TaskConstMeta(
+            debugName: "suggest",
+            argNames: ["prefix", "kind", "limit"],
+        );
+        
+
+@override Future<TagQuery> crateApiTagQueryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_tag_query,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTagQueryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTagQueryDefaultConstMeta => const TaskConstMeta(
+            debugName: "tag_query_default",
+            argNames: [],
+        );
+        
+
+@override Future<TextMatch> crateApiTextMatchDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_text_match,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTextMatchDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTextMatchDefaultConstMeta => const TaskConstMeta(
+            debugName: "text_match_default",
+            argNames: [],
+        );
+        
+
+@override Future<VaultCheckReport> crateApiVaultCheckReportDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_check_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVaultCheckReportDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultCheckReportDefaultConstMeta => const TaskConstMeta(
+            debugName: "vault_check_report_default",
+            argNames: [],
+        );
+        
+
+@override Future<VaultSettings> crateApiVaultSettingsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_settings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVaultSettingsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultSettingsDefaultConstMeta => const TaskConstMeta(
+            debugName: "vault_settings_default",
+            argNames: [],
+        );
+        
+
+@override Future<VaultStats> crateApiVaultStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_stats,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiVaultStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultStatsConstMeta => const TaskConstMeta(
+            debugName: "vault_stats",
+            argNames: [],
+        );
+        
+
+@override Future<VaultStats> crateApiVaultStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vault_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiVaultStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiVaultStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "vault_stats_default",
+            argNames: [],
+        );
+        
+
+@override Stream<VaultEvent> crateApiWatchVault()  { 
+            final sink = RustStreamSink<VaultEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_vault_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_vault_error,
+        )
+        ,
+            constMeta: kCrateApiWatchVaultConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiWatchVaultConstMeta => const TaskConstMeta(
+            debugName: "watch_vault",
+            argNames: ["sink"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<SearchBatch> dco_decode_StreamSink_search_batch_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<VaultEvent> dco_decode_StreamSink_vault_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected Attachment dco_decode_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Attachment(id: dco_decode_String(arr[0]),
+noteId: dco_decode_String(arr[1]),
+fileName: dco_decode_String(arr[2]),
+mimeType: dco_decode_String(arr[3]),
+size: dco_decode_u_64(arr[4]),
+createdAt: dco_decode_i_64(arr[5]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected Note dco_decode_box_autoadd_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_note(raw); }
+
+@protected NoteSummary dco_decode_box_autoadd_note_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_note_summary(raw); }
+
+@protected RepairOptions dco_decode_box_autoadd_repair_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_repair_options(raw); }
+
+@protected ReplaceQuery dco_decode_box_autoadd_replace_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_replace_query(raw); }
+
+@protected SaveConflict dco_decode_box_autoadd_save_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_save_conflict(raw); }
+
+@protected SuggestionKind dco_decode_box_autoadd_suggestion_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_suggestion_kind(raw); }
+
+@protected TagQuery dco_decode_box_autoadd_tag_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_tag_query(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected VaultSettings dco_decode_box_autoadd_vault_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_vault_settings(raw); }
+
+@protected CompressionSettings dco_decode_compression_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CompressionSettings(enabled: dco_decode_bool(arr[0]),
+minNoteSize: dco_decode_u_32(arr[1]),); }
+
+@protected DiffChange dco_decode_diff_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DiffChange(op: dco_decode_diff_op(arr[0]),
+text: dco_decode_String(arr[1]),); }
+
+@protected DiffGranularity dco_decode_diff_granularity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiffGranularity.values[raw as int]; }
+
+@protected DiffHunk dco_decode_diff_hunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DiffHunk(oldStart: dco_decode_u_32(arr[0]),
+oldLen: dco_decode_u_32(arr[1]),
+newStart: dco_decode_u_32(arr[2]),
+newLen: dco_decode_u_32(arr[3]),
+changes: dco_decode_list_diff_change(arr[4]),); }
+
+@protected DiffOp dco_decode_diff_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DiffOp.values[raw as int]; }
+
+@protected Draft dco_decode_draft(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return Draft(noteId: dco_decode_String(arr[0]),
+draftId: dco_decode_String(arr[1]),
+title: dco_decode_String(arr[2]),
+content: dco_decode_String(arr[3]),
+savedAt: dco_decode_i_64(arr[4]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected Folder dco_decode_folder(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return Folder(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+parentId: dco_decode_opt_String(arr[2]),
+createdAt: dco_decode_i_64(arr[3]),); }
+
+@protected FolderInfo dco_decode_folder_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FolderInfo(folder: dco_decode_folder(arr[0]),
+noteCount: dco_decode_u_32(arr[1]),
+totalNoteCount: dco_decode_u_32(arr[2]),); }
+
+@protected GcReport dco_decode_gc_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return GcReport(liveChunks: dco_decode_u_32(arr[0]),
+removedChunks: dco_decode_u_32(arr[1]),
+freedBytes: dco_decode_u_64(arr[2]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Attachment> dco_decode_list_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_attachment).toList(); }
+
+@protected List<DiffChange> dco_decode_list_diff_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_diff_change).toList(); }
+
+@protected List<DiffHunk> dco_decode_list_diff_hunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_diff_hunk).toList(); }
+
+@protected List<Draft> dco_decode_list_draft(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_draft).toList(); }
+
+@protected List<FolderInfo> dco_decode_list_folder_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_folder_info).toList(); }
+
+@protected List<NoteStats> dco_decode_list_note_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_note_stats).toList(); }
+
+@protected List<NoteSummary> dco_decode_list_note_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_note_summary).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QuarantineStats> dco_decode_list_quarantine_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_quarantine_stats).toList(); }
+
+@protected List<QueryDiagnostic> dco_decode_list_query_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_diagnostic).toList(); }
+
+@protected List<ReplaceMatch> dco_decode_list_replace_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_replace_match).toList(); }
+
+@protected List<RevisionInfo> dco_decode_list_revision_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_revision_info).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_suggestion).toList(); }
+
+@protected List<TagCount> dco_decode_list_tag_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_tag_count).toList(); }
+
+@protected List<TextMatch> dco_decode_list_text_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_text_match).toList(); }
+
+@protected List<VaultProblem> dco_decode_list_vault_problem(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_vault_problem).toList(); }
+
+@protected Note dco_decode_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return Note(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+content: dco_decode_String(arr[2]),
+createdAt: dco_decode_i_64(arr[3]),
+modifiedAt: dco_decode_i_64(arr[4]),
+tags: dco_decode_list_String(arr[5]),
+pinned: dco_decode_bool(arr[6]),
+color: dco_decode_opt_box_autoadd_u_32(arr[7]),
+contentEncrypted: dco_decode_bool(arr[8]),
+revision: dco_decode_u_32(arr[9]),
+folderId: dco_decode_opt_String(arr[10]),); }
+
+@protected NoteStats dco_decode_note_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return NoteStats(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+contentBytes: dco_decode_u_64(arr[2]),
+storedBytes: dco_decode_u_64(arr[3]),
+revisionCount: dco_decode_u_32(arr[4]),
+revisionBytes: dco_decode_u_64(arr[5]),
+attachmentCount: dco_decode_u_32(arr[6]),
+attachmentBytes: dco_decode_u_64(arr[7]),); }
+
+@protected NoteSummary dco_decode_note_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return NoteSummary(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+createdAt: dco_decode_i_64(arr[2]),
+modifiedAt: dco_decode_i_64(arr[3]),
+tags: dco_decode_list_String(arr[4]),
+pinned: dco_decode_bool(arr[5]),
+color: dco_decode_opt_box_autoadd_u_32(arr[6]),
+folderId: dco_decode_opt_String(arr[7]),); }
+
+@protected NoteWindow dco_decode_note_window(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NoteWindow(text: dco_decode_String(arr[0]),
+offset: dco_decode_u_64(arr[1]),
+firstLine: dco_decode_u_32(arr[2]),
+totalSize: dco_decode_u_64(arr[3]),
+totalLines: dco_decode_u_32(arr[4]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected Note? dco_decode_opt_box_autoadd_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_note(raw); }
+
+@protected NoteSummary? dco_decode_opt_box_autoadd_note_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_note_summary(raw); }
+
+@protected SaveConflict? dco_decode_opt_box_autoadd_save_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_save_conflict(raw); }
+
+@protected SuggestionKind? dco_decode_opt_box_autoadd_suggestion_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_suggestion_kind(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected QuarantineStats dco_decode_quarantine_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QuarantineStats(quarantinedAt: dco_decode_i_64(arr[0]),
+fileCount: dco_decode_u_32(arr[1]),
+bytes: dco_decode_u_64(arr[2]),); }
+
+@protected QueryDiagnostic dco_decode_query_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return QueryDiagnostic(kind: dco_decode_query_diagnostic_kind(arr[0]),
+message: dco_decode_String(arr[1]),
+start: dco_decode_u_32(arr[2]),
+end: dco_decode_u_32(arr[3]),); }
+
+@protected QueryDiagnosticKind dco_decode_query_diagnostic_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return QueryDiagnosticKind.values[raw as int]; }
+
+@protected RepairOptions dco_decode_repair_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RepairOptions(removeOrphans: dco_decode_bool(arr[0]),
+removeTempFiles: dco_decode_bool(arr[1]),); }
+
+@protected RepairReport dco_decode_repair_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RepairReport(quarantined: dco_decode_list_String(arr[0]),
+quarantineDir: dco_decode_String(arr[1]),
+restoredNotes: dco_decode_list_String(arr[2]),
+removed: dco_decode_list_String(arr[3]),
+remaining: dco_decode_list_vault_problem(arr[4]),); }
+
+@protected ReplaceField dco_decode_replace_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ReplaceField.values[raw as int]; }
+
+@protected ReplaceMatch dco_decode_replace_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return ReplaceMatch(noteId: dco_decode_String(arr[0]),
+noteTitle: dco_decode_String(arr[1]),
+revision: dco_decode_u_32(arr[2]),
+field: dco_decode_replace_field(arr[3]),
+start: dco_decode_u_64(arr[4]),
+end: dco_decode_u_64(arr[5]),
+line: dco_decode_u_32(arr[6]),
+before: dco_decode_String(arr[7]),
+matched: dco_decode_String(arr[8]),
+after: dco_decode_String(arr[9]),
+replacement: dco_decode_String(arr[10]),); }
+
+@protected ReplacePreview dco_decode_replace_preview(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReplacePreview(matches: dco_decode_list_replace_match(arr[0]),
+notesSearched: dco_decode_u_32(arr[1]),
+lockedNotes: dco_decode_list_String(arr[2]),); }
+
+@protected ReplaceQuery dco_decode_replace_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReplaceQuery(pattern: dco_decode_String(arr[0]),
+replacement: dco_decode_String(arr[1]),
+caseInsensitive: dco_decode_bool(arr[2]),
+includeTitles: dco_decode_bool(arr[3]),); }
+
+@protected ReplaceReport dco_decode_replace_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReplaceReport(changedNotes: dco_decode_list_note_summary(arr[0]),
+replacements: dco_decode_u_32(arr[1]),
+staleNotes: dco_decode_list_String(arr[2]),
+rejectedNotes: dco_decode_list_String(arr[3]),); }
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RetentionPolicy(keepLast: dco_decode_u_32(arr[0]),
+keepDailyDays: dco_decode_u_32(arr[1]),
+keepWeeklyWeeks: dco_decode_u_32(arr[2]),); }
+
+@protected RevisionInfo dco_decode_revision_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RevisionInfo(revision: dco_decode_u_32(arr[0]),
+modifiedAt: dco_decode_i_64(arr[1]),
+title: dco_decode_String(arr[2]),); }
+
+@protected SaveConflict dco_decode_save_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SaveConflict(ours: dco_decode_note(arr[0]),
+theirs: dco_decode_note(arr[1]),); }
+
+@protected SaveResult dco_decode_save_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SaveResult(saved: dco_decode_opt_box_autoadd_note(arr[0]),
+conflict: dco_decode_opt_box_autoadd_save_conflict(arr[1]),); }
+
+@protected SearchBatch dco_decode_search_batch(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SearchBatch(hits: dco_decode_list_search_hit(arr[0]),
+diagnostics: dco_decode_list_query_diagnostic(arr[1]),
+done: dco_decode_bool(arr[2]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchHit(note: dco_decode_note_summary(arr[0]),
+score: dco_decode_f_64(arr[1]),
+titleMatches: dco_decode_list_text_match(arr[2]),
+snippet: dco_decode_String(arr[3]),
+snippetMatches: dco_decode_list_text_match(arr[4]),); }
+
+@protected SearchResults dco_decode_search_results(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SearchResults(hits: dco_decode_list_search_hit(arr[0]),
+diagnostics: dco_decode_list_query_diagnostic(arr[1]),); }
+
+@protected StorageKind dco_decode_storage_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StorageKind.values[raw as int]; }
+
+@protected Suggestion dco_decode_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Suggestion(kind: dco_decode_suggestion_kind(arr[0]),
+text: dco_decode_String(arr[1]),
+id: dco_decode_String(arr[2]),); }
+
+@protected SuggestionKind dco_decode_suggestion_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SuggestionKind.values[raw as int]; }
+
+@protected TagCount dco_decode_tag_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TagCount(tag: dco_decode_String(arr[0]),
+count: dco_decode_u_32(arr[1]),); }
+
+@protected TagQuery dco_decode_tag_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TagQuery(allOf: dco_decode_list_String(arr[0]),
+anyOf: dco_decode_list_String(arr[1]),
+noneOf: dco_decode_list_String(arr[2]),); }
+
+@protected TextMatch dco_decode_text_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TextMatch(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected VaultCheckReport dco_decode_vault_check_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VaultCheckReport(filesChecked: dco_decode_u_32(arr[0]),
+problems: dco_decode_list_vault_problem(arr[1]),); }
+
+@protected VaultError dco_decode_vault_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VaultError(kind: dco_decode_vault_error_kind(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected VaultErrorKind dco_decode_vault_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VaultErrorKind.values[raw as int]; }
+
+@protected VaultEvent dco_decode_vault_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VaultEvent(kind: dco_decode_vault_event_kind(arr[0]),
+noteId: dco_decode_String(arr[1]),
+summary: dco_decode_opt_box_autoadd_note_summary(arr[2]),
+conflictCopy: dco_decode_opt_String(arr[3]),); }
+
+@protected VaultEventKind dco_decode_vault_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VaultEventKind.values[raw as int]; }
+
+@protected VaultProblem dco_decode_vault_problem(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VaultProblem(kind: dco_decode_vault_problem_kind(arr[0]),
+key: dco_decode_String(arr[1]),
+noteId: dco_decode_opt_String(arr[2]),
+detail: dco_decode_String(arr[3]),); }
+
+@protected VaultProblemKind dco_decode_vault_problem_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VaultProblemKind.values[raw as int]; }
+
+@protected VaultSettings dco_decode_vault_settings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VaultSettings(retention: dco_decode_retention_policy(arr[0]),
+compression: dco_decode_compression_settings(arr[1]),); }
+
+@protected VaultStats dco_decode_vault_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return VaultStats(noteCount: dco_decode_u_32(arr[0]),
+totalBytes: dco_decode_u_64(arr[1]),
+chunkCount: dco_decode_u_32(arr[2]),
+chunkBytes: dco_decode_u_64(arr[3]),
+revisionCount: dco_decode_u_32(arr[4]),
+revisionBytes: dco_decode_u_64(arr[5]),
+attachmentCount: dco_decode_u_32(arr[6]),
+attachmentBytes: dco_decode_u_64(arr[7]),
+draftCount: dco_decode_u_32(arr[8]),
+draftBytes: dco_decode_u_64(arr[9]),
+quarantine: dco_decode_list_quarantine_stats(arr[10]),
+oldestModifiedAt: dco_decode_opt_box_autoadd_i_64(arr[11]),
+newestModifiedAt: dco_decode_opt_box_autoadd_i_64(arr[12]),
+notes: dco_decode_list_note_stats(arr[13]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<SearchBatch> sse_decode_StreamSink_search_batch_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<VaultEvent> sse_decode_StreamSink_vault_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected Attachment sse_decode_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_noteId = sse_decode_String(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_size = sse_decode_u_64(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return Attachment(id: var_id, noteId: var_noteId, fileName: var_fileName, mimeType: var_mimeType, size: var_size, createdAt: var_createdAt); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected Note sse_decode_box_autoadd_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_note(deserializer)); }
+
+@protected NoteSummary sse_decode_box_autoadd_note_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_note_summary(deserializer)); }
+
+@protected RepairOptions sse_decode_box_autoadd_repair_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_repair_options(deserializer)); }
+
+@protected ReplaceQuery sse_decode_box_autoadd_replace_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_replace_query(deserializer)); }
+
+@protected SaveConflict sse_decode_box_autoadd_save_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_save_conflict(deserializer)); }
+
+@protected SuggestionKind sse_decode_box_autoadd_suggestion_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_suggestion_kind(deserializer)); }
+
+@protected TagQuery sse_decode_box_autoadd_tag_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_tag_query(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected VaultSettings sse_decode_box_autoadd_vault_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_vault_settings(deserializer)); }
+
+@protected CompressionSettings sse_decode_compression_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_minNoteSize = sse_decode_u_32(deserializer);
+return CompressionSettings(enabled: var_enabled, minNoteSize: var_minNoteSize); }
+
+@protected DiffChange sse_decode_diff_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_op = sse_decode_diff_op(deserializer);
+var var_text = sse_decode_String(deserializer);
+return DiffChange(op: var_op, text: var_text); }
+
+@protected DiffGranularity sse_decode_diff_granularity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DiffGranularity.values[inner]; }
+
+@protected DiffHunk sse_decode_diff_hunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_oldStart = sse_decode_u_32(deserializer);
+var var_oldLen = sse_decode_u_32(deserializer);
+var var_newStart = sse_decode_u_32(deserializer);
+var var_newLen = sse_decode_u_32(deserializer);
+var var_changes = sse_decode_list_diff_change(deserializer);
+return DiffHunk(oldStart: var_oldStart, oldLen: var_oldLen, newStart: var_newStart, newLen: var_newLen, changes: var_changes); }
+
+@protected DiffOp sse_decode_diff_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DiffOp.values[inner]; }
+
+@protected Draft sse_decode_draft(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteId = sse_decode_String(deserializer);
+var var_draftId = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_savedAt = sse_decode_i_64(deserializer);
+return Draft(noteId: var_noteId, draftId: var_draftId, title: var_title, content: var_content, savedAt: var_savedAt); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected Folder sse_decode_folder(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_parentId = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return Folder(id: var_id, name: var_name, parentId: var_parentId, createdAt: var_createdAt); }
+
+@protected FolderInfo sse_decode_folder_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_folder = sse_decode_folder(deserializer);
+var var_noteCount = sse_decode_u_32(deserializer);
+var var_totalNoteCount = sse_decode_u_32(deserializer);
+return FolderInfo(folder: var_folder, noteCount: var_noteCount, totalNoteCount: var_totalNoteCount); }
+
+@protected GcReport sse_decode_gc_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_liveChunks = sse_decode_u_32(deserializer);
+var var_removedChunks = sse_decode_u_32(deserializer);
+var var_freedBytes = sse_decode_u_64(deserializer);
+return GcReport(liveChunks: var_liveChunks, removedChunks: var_removedChunks, freedBytes: var_freedBytes); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Attachment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_attachment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiffChange> sse_decode_list_diff_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiffChange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_diff_change(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiffHunk> sse_decode_list_diff_hunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiffHunk>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_diff_hunk(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Draft> sse_decode_list_draft(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Draft>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_draft(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FolderInfo> sse_decode_list_folder_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FolderInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_folder_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NoteStats> sse_decode_list_note_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NoteStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_note_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NoteSummary> sse_decode_list_note_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NoteSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_note_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QuarantineStats> sse_decode_list_quarantine_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QuarantineStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_quarantine_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<QueryDiagnostic> sse_decode_list_query_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryDiagnostic>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_diagnostic(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReplaceMatch> sse_decode_list_replace_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReplaceMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_replace_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RevisionInfo> sse_decode_list_revision_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RevisionInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_revision_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Suggestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_suggestion(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TagCount> sse_decode_list_tag_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TagCount>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_tag_count(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TextMatch> sse_decode_list_text_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TextMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_text_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VaultProblem> sse_decode_list_vault_problem(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VaultProblem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_vault_problem(deserializer)); }
+        return ans_;
+         }
+
+@protected Note sse_decode_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_modifiedAt = sse_decode_i_64(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_pinned = sse_decode_bool(deserializer);
+var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_contentEncrypted = sse_decode_bool(deserializer);
+var var_revision = sse_decode_u_32(deserializer);
+var var_folderId = sse_decode_opt_String(deserializer);
+return Note(id: var_id, title: var_title, content: var_content, createdAt: var_createdAt, modifiedAt: var_modifiedAt, tags: var_tags, pinned: var_pinned, color: var_color, contentEncrypted: var_contentEncrypted, revision: var_revision, folderId: var_folderId); }
+
+@protected NoteStats sse_decode_note_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_contentBytes = sse_decode_u_64(deserializer);
+var var_storedBytes = sse_decode_u_64(deserializer);
+var var_revisionCount = sse_decode_u_32(deserializer);
+var var_revisionBytes = sse_decode_u_64(deserializer);
+var var_attachmentCount = sse_decode_u_32(deserializer);
+var var_attachmentBytes = sse_decode_u_64(deserializer);
+return NoteStats(id: var_id, title: var_title, contentBytes: var_contentBytes, storedBytes: var_storedBytes, revisionCount: var_revisionCount, revisionBytes: var_revisionBytes, attachmentCount: var_attachmentCount, attachmentBytes: var_attachmentBytes); }
+
+@protected NoteSummary sse_decode_note_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_modifiedAt = sse_decode_i_64(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_pinned = sse_decode_bool(deserializer);
+var var_color = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_folderId = sse_decode_opt_String(deserializer);
+return NoteSummary(id: var_id, title: var_title, createdAt: var_createdAt, modifiedAt: var_modifiedAt, tags: var_tags, pinned: var_pinned, color: var_color, folderId: var_folderId); }
+
+@protected NoteWindow sse_decode_note_window(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_offset = sse_decode_u_64(deserializer);
+var var_firstLine = sse_decode_u_32(deserializer);
+var var_totalSize = sse_decode_u_64(deserializer);
+var var_totalLines = sse_decode_u_32(deserializer);
+return NoteWindow(text: var_text, offset: var_offset, firstLine: var_firstLine, totalSize: var_totalSize, totalLines: var_totalLines); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Note? sse_decode_opt_box_autoadd_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_note(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NoteSummary? sse_decode_opt_box_autoadd_note_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_note_summary(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SaveConflict? sse_decode_opt_box_autoadd_save_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_save_conflict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SuggestionKind? sse_decode_opt_box_autoadd_suggestion_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_suggestion_kind(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QuarantineStats sse_decode_quarantine_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_quarantinedAt = sse_decode_i_64(deserializer);
+var var_fileCount = sse_decode_u_32(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+return QuarantineStats(quarantinedAt: var_quarantinedAt, fileCount: var_fileCount, bytes: var_bytes); }
+
+@protected QueryDiagnostic sse_decode_query_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_query_diagnostic_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return QueryDiagnostic(kind: var_kind, message: var_message, start: var_start, end: var_end); }
+
+@protected QueryDiagnosticKind sse_decode_query_diagnostic_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return QueryDiagnosticKind.values[inner]; }
+
+@protected RepairOptions sse_decode_repair_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_removeOrphans = sse_decode_bool(deserializer);
+var var_removeTempFiles = sse_decode_bool(deserializer);
+return RepairOptions(removeOrphans: var_removeOrphans, removeTempFiles: var_removeTempFiles); }
+
+@protected RepairReport sse_decode_repair_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_quarantined = sse_decode_list_String(deserializer);
+var var_quarantineDir = sse_decode_String(deserializer);
+var var_restoredNotes = sse_decode_list_String(deserializer);
+var var_removed = sse_decode_list_String(deserializer);
+var var_remaining = sse_decode_list_vault_problem(deserializer);
+return RepairReport(quarantined: var_quarantined, quarantineDir: var_quarantineDir, restoredNotes: var_restoredNotes, removed: var_removed, remaining: var_remaining); }
+
+@protected ReplaceField sse_decode_replace_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ReplaceField.values[inner]; }
+
+@protected ReplaceMatch sse_decode_replace_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteId = sse_decode_String(deserializer);
+var var_noteTitle = sse_decode_String(deserializer);
+var var_revision = sse_decode_u_32(deserializer);
+var var_field = sse_decode_replace_field(deserializer);
+var var_start = sse_decode_u_64(deserializer);
+var var_end = sse_decode_u_64(deserializer);
+var var_line = sse_decode_u_32(deserializer);
+var var_before = sse_decode_String(deserializer);
+var var_matched = sse_decode_String(deserializer);
+var var_after = sse_decode_String(deserializer);
+var var_replacement = sse_decode_String(deserializer);
+return ReplaceMatch(noteId: var_noteId, noteTitle: var_noteTitle, revision: var_revision, field: var_field, start: var_start, end: var_end, line: var_line, before: var_before, matched: var_matched, after: var_after, replacement: var_replacement); }
+
+@protected ReplacePreview sse_decode_replace_preview(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_matches = sse_decode_list_replace_match(deserializer);
+var var_notesSearched = sse_decode_u_32(deserializer);
+var var_lockedNotes = sse_decode_list_String(deserializer);
+return ReplacePreview(matches: var_matches, notesSearched: var_notesSearched, lockedNotes: var_lockedNotes); }
+
+@protected ReplaceQuery sse_decode_replace_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pattern = sse_decode_String(deserializer);
+var var_replacement = sse_decode_String(deserializer);
+var var_caseInsensitive = sse_decode_bool(deserializer);
+var var_includeTitles = sse_decode_bool(deserializer);
+return ReplaceQuery(pattern: var_pattern, replacement: var_replacement, caseInsensitive: var_caseInsensitive, includeTitles: var_includeTitles); }
+
+@protected ReplaceReport sse_decode_replace_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_changedNotes = sse_decode_list_note_summary(deserializer);
+var var_replacements = sse_decode_u_32(deserializer);
+var var_staleNotes = sse_decode_list_String(deserializer);
+var var_rejectedNotes = sse_decode_list_String(deserializer);
+return ReplaceReport(changedNotes: var_changedNotes, replacements: var_replacements, staleNotes: var_staleNotes, rejectedNotes: var_rejectedNotes); }
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keepLast = sse_decode_u_32(deserializer);
+var var_keepDailyDays = sse_decode_u_32(deserializer);
+var var_keepWeeklyWeeks = sse_decode_u_32(deserializer);
+return RetentionPolicy(keepLast: var_keepLast, keepDailyDays: var_keepDailyDays, keepWeeklyWeeks: var_keepWeeklyWeeks); }
+
+@protected RevisionInfo sse_decode_revision_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_revision = sse_decode_u_32(deserializer);
+var var_modifiedAt = sse_decode_i_64(deserializer);
+var var_title = sse_decode_String(deserializer);
+return RevisionInfo(revision: var_revision, modifiedAt: var_modifiedAt, title: var_title); }
+
+@protected SaveConflict sse_decode_save_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ours = sse_decode_note(deserializer);
+var var_theirs = sse_decode_note(deserializer);
+return SaveConflict(ours: var_ours, theirs: var_theirs); }
+
+@protected SaveResult sse_decode_save_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_saved = sse_decode_opt_box_autoadd_note(deserializer);
+var var_conflict = sse_decode_opt_box_autoadd_save_conflict(deserializer);
+return SaveResult(saved: var_saved, conflict: var_conflict); }
+
+@protected SearchBatch sse_decode_search_batch(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_diagnostics = sse_decode_list_query_diagnostic(deserializer);
+var var_done = sse_decode_bool(deserializer);
+return SearchBatch(hits: var_hits, diagnostics: var_diagnostics, done: var_done); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_note = sse_decode_note_summary(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+var var_titleMatches = sse_decode_list_text_match(deserializer);
+var var_snippet = sse_decode_String(deserializer);
+var var_snippetMatches = sse_decode_list_text_match(deserializer);
+return SearchHit(note: var_note, score: var_score, titleMatches: var_titleMatches, snippet: var_snippet, snippetMatches: var_snippetMatches); }
+
+@protected SearchResults sse_decode_search_results(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_diagnostics = sse_decode_list_query_diagnostic(deserializer);
+return SearchResults(hits: var_hits, diagnostics: var_diagnostics); }
+
+@protected StorageKind sse_decode_storage_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StorageKind.values[inner]; }
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_suggestion_kind(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_id = sse_decode_String(deserializer);
+return Suggestion(kind: var_kind, text: var_text, id: var_id); }
+
+@protected SuggestionKind sse_decode_suggestion_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SuggestionKind.values[inner]; }
+
+@protected TagCount sse_decode_tag_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tag = sse_decode_String(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+return TagCount(tag: var_tag, count: var_count); }
+
+@protected TagQuery sse_decode_tag_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_allOf = sse_decode_list_String(deserializer);
+var var_anyOf = sse_decode_list_String(deserializer);
+var var_noneOf = sse_decode_list_String(deserializer);
+return TagQuery(allOf: var_allOf, anyOf: var_anyOf, noneOf: var_noneOf); }
+
+@protected TextMatch sse_decode_text_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return TextMatch(start: var_start, end: var_end); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected VaultCheckReport sse_decode_vault_check_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filesChecked = sse_decode_u_32(deserializer);
+var var_problems = sse_decode_list_vault_problem(deserializer);
+return VaultCheckReport(filesChecked: var_filesChecked, problems: var_problems); }
+
+@protected VaultError sse_decode_vault_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_vault_error_kind(deserializer);
+var var_message = sse_decode_String(deserializer);
+return VaultError(kind: var_kind, message: var_message); }
+
+@protected VaultErrorKind sse_decode_vault_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VaultErrorKind.values[inner]; }
+
+@protected VaultEvent sse_decode_vault_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_vault_event_kind(deserializer);
+var var_noteId = sse_decode_String(deserializer);
+var var_summary = sse_decode_opt_box_autoadd_note_summary(deserializer);
+var var_conflictCopy = sse_decode_opt_String(deserializer);
+return VaultEvent(kind: var_kind, noteId: var_noteId, summary: var_summary, conflictCopy: var_conflictCopy); }
+
+@protected VaultEventKind sse_decode_vault_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VaultEventKind.values[inner]; }
+
+@protected VaultProblem sse_decode_vault_problem(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_vault_problem_kind(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_noteId = sse_decode_opt_String(deserializer);
+var var_detail = sse_decode_String(deserializer);
+return VaultProblem(kind: var_kind, key: var_key, noteId: var_noteId, detail: var_detail); }
+
+@protected VaultProblemKind sse_decode_vault_problem_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VaultProblemKind.values[inner]; }
+
+@protected VaultSettings sse_decode_vault_settings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_retention = sse_decode_retention_policy(deserializer);
+var var_compression = sse_decode_compression_settings(deserializer);
+return VaultSettings(retention: var_retention, compression: var_compression); }
+
+@protected VaultStats sse_decode_vault_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteCount = sse_decode_u_32(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+var var_chunkCount = sse_decode_u_32(deserializer);
+var var_chunkBytes = sse_decode_u_64(deserializer);
+var var_revisionCount = sse_decode_u_32(deserializer);
+var var_revisionBytes = sse_decode_u_64(deserializer);
+var var_attachmentCount = sse_decode_u_32(deserializer);
+var var_attachmentBytes = sse_decode_u_64(deserializer);
+var var_draftCount = sse_decode_u_32(deserializer);
+var var_draftBytes = sse_decode_u_64(deserializer);
+var var_quarantine = sse_decode_list_quarantine_stats(deserializer);
+var var_oldestModifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_newestModifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_notes = sse_decode_list_note_stats(deserializer);
+return VaultStats(noteCount: var_noteCount, totalBytes: var_totalBytes, chunkCount: var_chunkCount, chunkBytes: var_chunkBytes, revisionCount: var_revisionCount, revisionBytes: var_revisionBytes, attachmentCount: var_attachmentCount, attachmentBytes: var_attachmentBytes, draftCount: var_draftCount, draftBytes: var_draftBytes, quarantine: var_quarantine, oldestModifiedAt: var_oldestModifiedAt, newestModifiedAt: var_newestModifiedAt, notes: var_notes); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_search_batch_Sse(RustStreamSink<SearchBatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_search_batch,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_vault_event_Sse(RustStreamSink<VaultEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_vault_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_attachment(Attachment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.noteId, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_u_64(self.size, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_note(Note self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_note(self, serializer); }
+
+@protected void sse_encode_box_autoadd_note_summary(NoteSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_note_summary(self, serializer); }
+
+@protected void sse_encode_box_autoadd_repair_options(RepairOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_repair_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_replace_query(ReplaceQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_replace_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_save_conflict(SaveConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_save_conflict(self, serializer); }
+
+@protected void sse_encode_box_autoadd_suggestion_kind(SuggestionKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_suggestion_kind(self, serializer); }
+
+@protected void sse_encode_box_autoadd_tag_query(TagQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_tag_query(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_vault_settings(VaultSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vault_settings(self, serializer); }
+
+@protected void sse_encode_compression_settings(CompressionSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_u_32(self.minNoteSize, serializer);
+ }
+
+@protected void sse_encode_diff_change(DiffChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_diff_op(self.op, serializer);
+sse_encode_String(self.text, serializer);
+ }
+
+@protected void sse_encode_diff_granularity(DiffGranularity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_diff_hunk(DiffHunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.oldStart, serializer);
+sse_encode_u_32(self.oldLen, serializer);
+sse_encode_u_32(self.newStart, serializer);
+sse_encode_u_32(self.newLen, serializer);
+sse_encode_list_diff_change(self.changes, serializer);
+ }
+
+@protected void sse_encode_diff_op(DiffOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_draft(Draft self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.noteId, serializer);
+sse_encode_String(self.draftId, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_i_64(self.savedAt, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_folder(Folder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.parentId, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_folder_info(FolderInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_folder(self.folder, serializer);
+sse_encode_u_32(self.noteCount, serializer);
+sse_encode_u_32(self.totalNoteCount, serializer);
+ }
+
+@protected void sse_encode_gc_report(GcReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.liveChunks, serializer);
+sse_encode_u_32(self.removedChunks, serializer);
+sse_encode_u_64(self.freedBytes, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_attachment(List<Attachment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_attachment(item, serializer); } }
+
+@protected void sse_encode_list_diff_change(List<DiffChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_diff_change(item, serializer); } }
+
+@protected void sse_encode_list_diff_hunk(List<DiffHunk> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_diff_hunk(item, serializer); } }
+
+@protected void sse_encode_list_draft(List<Draft> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_draft(item, serializer); } }
+
+@protected void sse_encode_list_folder_info(List<FolderInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_folder_info(item, serializer); } }
+
+@protected void sse_encode_list_note_stats(List<NoteStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_note_stats(item, serializer); } }
+
+@protected void sse_encode_list_note_summary(List<NoteSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_note_summary(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_quarantine_stats(List<QuarantineStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_quarantine_stats(item, serializer); } }
+
+@protected void sse_encode_list_query_diagnostic(List<QueryDiagnostic> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_diagnostic(item, serializer); } }
+
+@protected void sse_encode_list_replace_match(List<ReplaceMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_replace_match(item, serializer); } }
+
+@protected void sse_encode_list_revision_info(List<RevisionInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_revision_info(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_suggestion(item, serializer); } }
+
+@protected void sse_encode_list_tag_count(List<TagCount> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_tag_count(item, serializer); } }
+
+@protected void sse_encode_list_text_match(List<TextMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_text_match(item, serializer); } }
+
+@protected void sse_encode_list_vault_problem(List<VaultProblem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_vault_problem(item, serializer); } }
+
+@protected void sse_encode_note(Note self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.modifiedAt, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_bool(self.pinned, serializer);
+sse_encode_opt_box_autoadd_u_32(self.color, serializer);
+sse_encode_bool(self.contentEncrypted, serializer);
+sse_encode_u_32(self.revision, serializer);
+sse_encode_opt_String(self.folderId, serializer);
+ }
+
+@protected void sse_encode_note_stats(NoteStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_u_64(self.contentBytes, serializer);
+sse_encode_u_64(self.storedBytes, serializer);
+sse_encode_u_32(self.revisionCount, serializer);
+sse_encode_u_64(self.revisionBytes, serializer);
+sse_encode_u_32(self.attachmentCount, serializer);
+sse_encode_u_64(self.attachmentBytes, serializer);
+ }
+
+@protected void sse_encode_note_summary(NoteSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.modifiedAt, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_bool(self.pinned, serializer);
+sse_encode_opt_box_autoadd_u_32(self.color, serializer);
+sse_encode_opt_String(self.folderId, serializer);
+ }
+
+@protected void sse_encode_note_window(NoteWindow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_u_64(self.offset, serializer);
+sse_encode_u_32(self.firstLine, serializer);
+sse_encode_u_64(self.totalSize, serializer);
+sse_encode_u_32(self.totalLines, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_note(Note? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_note(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_note_summary(NoteSummary? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_note_summary(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_save_conflict(SaveConflict? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_save_conflict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_suggestion_kind(SuggestionKind? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_suggestion_kind(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_quarantine_stats(QuarantineStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.quarantinedAt, serializer);
+sse_encode_u_32(self.fileCount, serializer);
+sse_encode_u_64(self.bytes, serializer);
+ }
+
+@protected void sse_encode_query_diagnostic(QueryDiagnostic self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_diagnostic_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_query_diagnostic_kind(QueryDiagnosticKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_repair_options(RepairOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.removeOrphans, serializer);
+sse_encode_bool(self.removeTempFiles, serializer);
+ }
+
+@protected void sse_encode_repair_report(RepairReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.quarantined, serializer);
+sse_encode_String(self.quarantineDir, serializer);
+sse_encode_list_String(self.restoredNotes, serializer);
+sse_encode_list_String(self.removed, serializer);
+sse_encode_list_vault_problem(self.remaining, serializer);
+ }
+
+@protected void sse_encode_replace_field(ReplaceField self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_replace_match(ReplaceMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.noteId, serializer);
+sse_encode_String(self.noteTitle, serializer);
+sse_encode_u_32(self.revision, serializer);
+sse_encode_replace_field(self.field, serializer);
+sse_encode_u_64(self.start, serializer);
+sse_encode_u_64(self.end, serializer);
+sse_encode_u_32(self.line, serializer);
+sse_encode_String(self.before, serializer);
+sse_encode_String(self.matched, serializer);
+sse_encode_String(self.after, serializer);
+sse_encode_String(self.replacement, serializer);
+ }
+
+@protected void sse_encode_replace_preview(ReplacePreview self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_replace_match(self.matches, serializer);
+sse_encode_u_32(self.notesSearched, serializer);
+sse_encode_list_String(self.lockedNotes, serializer);
+ }
+
+@protected void sse_encode_replace_query(ReplaceQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.pattern, serializer);
+sse_encode_String(self.replacement, serializer);
+sse_encode_bool(self.caseInsensitive, serializer);
+sse_encode_bool(self.includeTitles, serializer);
+ }
+
+@protected void sse_encode_replace_report(ReplaceReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_note_summary(self.changedNotes, serializer);
+sse_encode_u_32(self.replacements, serializer);
+sse_encode_list_String(self.staleNotes, serializer);
+sse_encode_list_String(self.rejectedNotes, serializer);
+ }
+
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.keepLast, serializer);
+sse_encode_u_32(self.keepDailyDays, serializer);
+sse_encode_u_32(self.keepWeeklyWeeks, serializer);
+ }
+
+@protected void sse_encode_revision_info(RevisionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.revision, serializer);
+sse_encode_i_64(self.modifiedAt, serializer);
+sse_encode_String(self.title, serializer);
+ }
+
+@protected void sse_encode_save_conflict(SaveConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_note(self.ours, serializer);
+sse_encode_note(self.theirs, serializer);
+ }
+
+@protected void sse_encode_save_result(SaveResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_note(self.saved, serializer);
+sse_encode_opt_box_autoadd_save_conflict(self.conflict, serializer);
+ }
+
+@protected void sse_encode_search_batch(SearchBatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_list_query_diagnostic(self.diagnostics, serializer);
+sse_encode_bool(self.done, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_note_summary(self.note, serializer);
+sse_encode_f_64(self.score, serializer);
+sse_encode_list_text_match(self.titleMatches, serializer);
+sse_encode_String(self.snippet, serializer);
+sse_encode_list_text_match(self.snippetMatches, serializer);
+ }
+
+@protected void sse_encode_search_results(SearchResults self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_list_query_diagnostic(self.diagnostics, serializer);
+ }
+
+@protected void sse_encode_storage_kind(StorageKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_suggestion_kind(self.kind, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_String(self.id, serializer);
+ }
+
+@protected void sse_encode_suggestion_kind(SuggestionKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_tag_count(TagCount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.tag, serializer);
+sse_encode_u_32(self.count, serializer);
+ }
+
+@protected void sse_encode_tag_query(TagQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.allOf, serializer);
+sse_encode_list_String(self.anyOf, serializer);
+sse_encode_list_String(self.noneOf, serializer);
+ }
+
+@protected void sse_encode_text_match(TextMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_vault_check_report(VaultCheckReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.filesChecked, serializer);
+sse_encode_list_vault_problem(self.problems, serializer);
+ }
+
+@protected void sse_encode_vault_error(VaultError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vault_error_kind(self.kind, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_vault_error_kind(VaultErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_vault_event(VaultEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vault_event_kind(self.kind, serializer);
+sse_encode_String(self.noteId, serializer);
+sse_encode_opt_box_autoadd_note_summary(self.summary, serializer);
+sse_encode_opt_String(self.conflictCopy, serializer);
+ }
+
+@protected void sse_encode_vault_event_kind(VaultEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_vault_problem(VaultProblem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_vault_problem_kind(self.kind, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_opt_String(self.noteId, serializer);
+sse_encode_String(self.detail, serializer);
+ }
+
+@protected void sse_encode_vault_problem_kind(VaultProblemKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_vault_settings(VaultSettings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retention_policy(self.retention, serializer);
+sse_encode_compression_settings(self.compression, serializer);
+ }
+
+@protected void sse_encode_vault_stats(VaultStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.noteCount, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+sse_encode_u_32(self.chunkCount, serializer);
+sse_encode_u_64(self.chunkBytes, serializer);
+sse_encode_u_32(self.revisionCount, serializer);
+sse_encode_u_64(self.revisionBytes, serializer);
+sse_encode_u_32(self.attachmentCount, serializer);
+sse_encode_u_64(self.attachmentBytes, serializer);
+sse_encode_u_32(self.draftCount, serializer);
+sse_encode_u_64(self.draftBytes, serializer);
+sse_encode_list_quarantine_stats(self.quarantine, serializer);
+sse_encode_opt_box_autoadd_i_64(self.oldestModifiedAt, serializer);
+sse_encode_opt_box_autoadd_i_64(self.newestModifiedAt, serializer);
+sse_encode_list_note_stats(self.notes, serializer);
+ }
+                }
+                
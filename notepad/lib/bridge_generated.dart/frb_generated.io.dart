@@ -10,92 +10,596 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+                  
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected RustStreamSink<SearchBatch> dco_decode_StreamSink_search_batch_Sse(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected RustStreamSink<VaultEvent> dco_decode_StreamSink_vault_event_Sse(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected Attachment dco_decode_attachment(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected Note dco_decode_box_autoadd_note(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected NoteSummary dco_decode_box_autoadd_note_summary(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+@protected RepairOptions dco_decode_box_autoadd_repair_options(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected ReplaceQuery dco_decode_box_autoadd_replace_query(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected SaveConflict dco_decode_box_autoadd_save_conflict(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected SuggestionKind dco_decode_box_autoadd_suggestion_kind(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected TagQuery dco_decode_box_autoadd_tag_query(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected VaultSettings dco_decode_box_autoadd_vault_settings(dynamic raw);
+
+@protected CompressionSettings dco_decode_compression_settings(dynamic raw);
+
+@protected DiffChange dco_decode_diff_change(dynamic raw);
+
+@protected DiffGranularity dco_decode_diff_granularity(dynamic raw);
+
+@protected DiffHunk dco_decode_diff_hunk(dynamic raw);
+
+@protected DiffOp dco_decode_diff_op(dynamic raw);
+
+@protected Draft dco_decode_draft(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected Folder dco_decode_folder(dynamic raw);
+
+@protected FolderInfo dco_decode_folder_info(dynamic raw);
+
+@protected GcReport dco_decode_gc_report(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<Attachment> dco_decode_list_attachment(dynamic raw);
+
+@protected List<DiffChange> dco_decode_list_diff_change(dynamic raw);
+
+@protected List<DiffHunk> dco_decode_list_diff_hunk(dynamic raw);
+
+@protected List<Draft> dco_decode_list_draft(dynamic raw);
+
+@protected List<FolderInfo> dco_decode_list_folder_info(dynamic raw);
+
+@protected List<NoteStats> dco_decode_list_note_stats(dynamic raw);
+
+@protected List<NoteSummary> dco_decode_list_note_summary(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QuarantineStats> dco_decode_list_quarantine_stats(dynamic raw);
+
+@protected List<QueryDiagnostic> dco_decode_list_query_diagnostic(dynamic raw);
+
+@protected List<ReplaceMatch> dco_decode_list_replace_match(dynamic raw);
+
+@protected List<RevisionInfo> dco_decode_list_revision_info(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw);
+
+@protected List<TagCount> dco_decode_list_tag_count(dynamic raw);
+
+@protected List<TextMatch> dco_decode_list_text_match(dynamic raw);
+
+@protected List<VaultProblem> dco_decode_list_vault_problem(dynamic raw);
+
+@protected Note dco_decode_note(dynamic raw);
+
+@protected NoteStats dco_decode_note_stats(dynamic raw);
+
+@protected NoteSummary dco_decode_note_summary(dynamic raw);
+
+@protected NoteWindow dco_decode_note_window(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected Note? dco_decode_opt_box_autoadd_note(dynamic raw);
+
+@protected NoteSummary? dco_decode_opt_box_autoadd_note_summary(dynamic raw);
+
+@protected SaveConflict? dco_decode_opt_box_autoadd_save_conflict(dynamic raw);
+
+@protected SuggestionKind? dco_decode_opt_box_autoadd_suggestion_kind(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected QuarantineStats dco_decode_quarantine_stats(dynamic raw);
+
+@protected QueryDiagnostic dco_decode_query_diagnostic(dynamic raw);
+
+@protected QueryDiagnosticKind dco_decode_query_diagnostic_kind(dynamic raw);
+
+@protected RepairOptions dco_decode_repair_options(dynamic raw);
+
+@protected RepairReport dco_decode_repair_report(dynamic raw);
+
+@protected ReplaceField dco_decode_replace_field(dynamic raw);
+
+@protected ReplaceMatch dco_decode_replace_match(dynamic raw);
+
+@protected ReplacePreview dco_decode_replace_preview(dynamic raw);
+
+@protected ReplaceQuery dco_decode_replace_query(dynamic raw);
+
+@protected ReplaceReport dco_decode_replace_report(dynamic raw);
+
+@protected RetentionPolicy dco_decode_retention_policy(dynamic raw);
+
+@protected RevisionInfo dco_decode_revision_info(dynamic raw);
+
+@protected SaveConflict dco_decode_save_conflict(dynamic raw);
+
+@protected SaveResult dco_decode_save_result(dynamic raw);
+
+@protected SearchBatch dco_decode_search_batch(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchResults dco_decode_search_results(dynamic raw);
+
+@protected StorageKind dco_decode_storage_kind(dynamic raw);
+
+@protected Suggestion dco_decode_suggestion(dynamic raw);
+
+@protected SuggestionKind dco_decode_suggestion_kind(dynamic raw);
+
+@protected TagCount dco_decode_tag_count(dynamic raw);
+
+@protected TagQuery dco_decode_tag_query(dynamic raw);
+
+@protected TextMatch dco_decode_text_match(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected VaultCheckReport dco_decode_vault_check_report(dynamic raw);
+
+@protected VaultError dco_decode_vault_error(dynamic raw);
+
+@protected VaultErrorKind dco_decode_vault_error_kind(dynamic raw);
+
+@protected VaultEvent dco_decode_vault_event(dynamic raw);
+
+@protected VaultEventKind dco_decode_vault_event_kind(dynamic raw);
+
+@protected VaultProblem dco_decode_vault_problem(dynamic raw);
+
+@protected VaultProblemKind dco_decode_vault_problem_kind(dynamic raw);
+
+@protected VaultSettings dco_decode_vault_settings(dynamic raw);
+
+@protected VaultStats dco_decode_vault_stats(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<SearchBatch> sse_decode_StreamSink_search_batch_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<VaultEvent> sse_decode_StreamSink_vault_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected Attachment sse_decode_attachment(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected Note sse_decode_box_autoadd_note(SseDeserializer deserializer);
+
+@protected NoteSummary sse_decode_box_autoadd_note_summary(SseDeserializer deserializer);
+
+@protected RepairOptions sse_decode_box_autoadd_repair_options(SseDeserializer deserializer);
+
+@protected ReplaceQuery sse_decode_box_autoadd_replace_query(SseDeserializer deserializer);
+
+@protected SaveConflict sse_decode_box_autoadd_save_conflict(SseDeserializer deserializer);
+
+@protected SuggestionKind sse_decode_box_autoadd_suggestion_kind(SseDeserializer deserializer);
+
+@protected TagQuery sse_decode_box_autoadd_tag_query(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected VaultSettings sse_decode_box_autoadd_vault_settings(SseDeserializer deserializer);
+
+@protected CompressionSettings sse_decode_compression_settings(SseDeserializer deserializer);
+
+@protected DiffChange sse_decode_diff_change(SseDeserializer deserializer);
+
+@protected DiffGranularity sse_decode_diff_granularity(SseDeserializer deserializer);
+
+@protected DiffHunk sse_decode_diff_hunk(SseDeserializer deserializer);
+
+@protected DiffOp sse_decode_diff_op(SseDeserializer deserializer);
+
+@protected Draft sse_decode_draft(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected Folder sse_decode_folder(SseDeserializer deserializer);
+
+@protected FolderInfo sse_decode_folder_info(SseDeserializer deserializer);
+
+@protected GcReport sse_decode_gc_report(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer);
+
+@protected List<DiffChange> sse_decode_list_diff_change(SseDeserializer deserializer);
+
+@protected List<DiffHunk> sse_decode_list_diff_hunk(SseDeserializer deserializer);
+
+@protected List<Draft> sse_decode_list_draft(SseDeserializer deserializer);
+
+@protected List<FolderInfo> sse_decode_list_folder_info(SseDeserializer deserializer);
+
+@protected List<NoteStats> sse_decode_list_note_stats(SseDeserializer deserializer);
+
+@protected List<NoteSummary> sse_decode_list_note_summary(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QuarantineStats> sse_decode_list_quarantine_stats(SseDeserializer deserializer);
+
+@protected List<QueryDiagnostic> sse_decode_list_query_diagnostic(SseDeserializer deserializer);
+
+@protected List<ReplaceMatch> sse_decode_list_replace_match(SseDeserializer deserializer);
+
+@protected List<RevisionInfo> sse_decode_list_revision_info(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer);
+
+@protected List<TagCount> sse_decode_list_tag_count(SseDeserializer deserializer);
+
+@protected List<TextMatch> sse_decode_list_text_match(SseDeserializer deserializer);
+
+@protected List<VaultProblem> sse_decode_list_vault_problem(SseDeserializer deserializer);
+
+@protected Note sse_decode_note(SseDeserializer deserializer);
+
+@protected NoteStats sse_decode_note_stats(SseDeserializer deserializer);
+
+@protected NoteSummary sse_decode_note_summary(SseDeserializer deserializer);
+
+@protected NoteWindow sse_decode_note_window(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected Note? sse_decode_opt_box_autoadd_note(SseDeserializer deserializer);
+
+@protected NoteSummary? sse_decode_opt_box_autoadd_note_summary(SseDeserializer deserializer);
+
+@protected SaveConflict? sse_decode_opt_box_autoadd_save_conflict(SseDeserializer deserializer);
+
+@protected SuggestionKind? sse_decode_opt_box_autoadd_suggestion_kind(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected QuarantineStats sse_decode_quarantine_stats(SseDeserializer deserializer);
+
+@protected QueryDiagnostic sse_decode_query_diagnostic(SseDeserializer deserializer);
+
+@protected QueryDiagnosticKind sse_decode_query_diagnostic_kind(SseDeserializer deserializer);
+
+@protected RepairOptions sse_decode_repair_options(SseDeserializer deserializer);
+
+@protected RepairReport sse_decode_repair_report(SseDeserializer deserializer);
+
+@protected ReplaceField sse_decode_replace_field(SseDeserializer deserializer);
+
+@protected ReplaceMatch sse_decode_replace_match(SseDeserializer deserializer);
+
+@protected ReplacePreview sse_decode_replace_preview(SseDeserializer deserializer);
+
+@protected ReplaceQuery sse_decode_replace_query(SseDeserializer deserializer);
+
+@protected ReplaceReport sse_decode_replace_report(SseDeserializer deserializer);
+
+@protected RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer);
+
+@protected RevisionInfo sse_decode_revision_info(SseDeserializer deserializer);
+
+@protected SaveConflict sse_decode_save_conflict(SseDeserializer deserializer);
+
+@protected SaveResult sse_decode_save_result(SseDeserializer deserializer);
+
+@protected SearchBatch sse_decode_search_batch(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchResults sse_decode_search_results(SseDeserializer deserializer);
+
+@protected StorageKind sse_decode_storage_kind(SseDeserializer deserializer);
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer);
+
+@protected SuggestionKind sse_decode_suggestion_kind(SseDeserializer deserializer);
+
+@protected TagCount sse_decode_tag_count(SseDeserializer deserializer);
+
+@protected TagQuery sse_decode_tag_query(SseDeserializer deserializer);
+
+@protected TextMatch sse_decode_text_match(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected VaultCheckReport sse_decode_vault_check_report(SseDeserializer deserializer);
+
+@protected VaultError sse_decode_vault_error(SseDeserializer deserializer);
+
+@protected VaultErrorKind sse_decode_vault_error_kind(SseDeserializer deserializer);
+
+@protected VaultEvent sse_decode_vault_event(SseDeserializer deserializer);
+
+@protected VaultEventKind sse_decode_vault_event_kind(SseDeserializer deserializer);
+
+@protected VaultProblem sse_decode_vault_problem(SseDeserializer deserializer);
+
+@protected VaultProblemKind sse_decode_vault_problem_kind(SseDeserializer deserializer);
+
+@protected VaultSettings sse_decode_vault_settings(SseDeserializer deserializer);
+
+@protected VaultStats sse_decode_vault_stats(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_search_batch_Sse(RustStreamSink<SearchBatch> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_vault_event_Sse(RustStreamSink<VaultEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_attachment(Attachment self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_note(Note self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_note_summary(NoteSummary self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_repair_options(RepairOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_replace_query(ReplaceQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_save_conflict(SaveConflict self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_suggestion_kind(SuggestionKind self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_tag_query(TagQuery self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_vault_settings(VaultSettings self, SseSerializer serializer);
+
+@protected void sse_encode_compression_settings(CompressionSettings self, SseSerializer serializer);
+
+@protected void sse_encode_diff_change(DiffChange self, SseSerializer serializer);
+
+@protected void sse_encode_diff_granularity(DiffGranularity self, SseSerializer serializer);
+
+@protected void sse_encode_diff_hunk(DiffHunk self, SseSerializer serializer);
+
+@protected void sse_encode_diff_op(DiffOp self, SseSerializer serializer);
+
+@protected void sse_encode_draft(Draft self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_folder(Folder self, SseSerializer serializer);
+
+@protected void sse_encode_folder_info(FolderInfo self, SseSerializer serializer);
+
+@protected void sse_encode_gc_report(GcReport self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_attachment(List<Attachment> self, SseSerializer serializer);
+
+@protected void sse_encode_list_diff_change(List<DiffChange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_diff_hunk(List<DiffHunk> self, SseSerializer serializer);
+
+@protected void sse_encode_list_draft(List<Draft> self, SseSerializer serializer);
+
+@protected void sse_encode_list_folder_info(List<FolderInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_note_stats(List<NoteStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_note_summary(List<NoteSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_quarantine_stats(List<QuarantineStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_diagnostic(List<QueryDiagnostic> self, SseSerializer serializer);
+
+@protected void sse_encode_list_replace_match(List<ReplaceMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_revision_info(List<RevisionInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_tag_count(List<TagCount> self, SseSerializer serializer);
+
+@protected void sse_encode_list_text_match(List<TextMatch> self, SseSerializer serializer);
+
+@protected void sse_encode_list_vault_problem(List<VaultProblem> self, SseSerializer serializer);
+
+@protected void sse_encode_note(Note self, SseSerializer serializer);
+
+@protected void sse_encode_note_stats(NoteStats self, SseSerializer serializer);
+
+@protected void sse_encode_note_summary(NoteSummary self, SseSerializer serializer);
+
+@protected void sse_encode_note_window(NoteWindow self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_note(Note? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_note_summary(NoteSummary? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_save_conflict(SaveConflict? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_suggestion_kind(SuggestionKind? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_quarantine_stats(QuarantineStats self, SseSerializer serializer);
+
+@protected void sse_encode_query_diagnostic(QueryDiagnostic self, SseSerializer serializer);
+
+@protected void sse_encode_query_diagnostic_kind(QueryDiagnosticKind self, SseSerializer serializer);
+
+@protected void sse_encode_repair_options(RepairOptions self, SseSerializer serializer);
+
+@protected void sse_encode_repair_report(RepairReport self, SseSerializer serializer);
+
+@protected void sse_encode_replace_field(ReplaceField self, SseSerializer serializer);
+
+@protected void sse_encode_replace_match(ReplaceMatch self, SseSerializer serializer);
+
+@protected void sse_encode_replace_preview(ReplacePreview self, SseSerializer serializer);
+
+@protected void sse_encode_replace_query(ReplaceQuery self, SseSerializer serializer);
+
+@protected void sse_encode_replace_report(ReplaceReport self, SseSerializer serializer);
+
+@protected void sse_encode_retention_policy(RetentionPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_revision_info(RevisionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_save_conflict(SaveConflict self, SseSerializer serializer);
+
+@protected void sse_encode_save_result(SaveResult self, SseSerializer serializer);
+
+@protected void sse_encode_search_batch(SearchBatch self, SseSerializer serializer);
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_search_results(SearchResults self, SseSerializer serializer);
+
+@protected void sse_encode_storage_kind(StorageKind self, SseSerializer serializer);
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer);
+
+@protected void sse_encode_suggestion_kind(SuggestionKind self, SseSerializer serializer);
+
+@protected void sse_encode_tag_count(TagCount self, SseSerializer serializer);
+
+@protected void sse_encode_tag_query(TagQuery self, SseSerializer serializer);
+
+@protected void sse_encode_text_match(TextMatch self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_vault_check_report(VaultCheckReport self, SseSerializer serializer);
+
+@protected void sse_encode_vault_error(VaultError self, SseSerializer serializer);
+
+@protected void sse_encode_vault_error_kind(VaultErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_vault_event(VaultEvent self, SseSerializer serializer);
+
+@protected void sse_encode_vault_event_kind(VaultEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_vault_problem(VaultProblem self, SseSerializer serializer);
+
+@protected void sse_encode_vault_problem_kind(VaultProblemKind self, SseSerializer serializer);
+
+@protected void sse_encode_vault_settings(VaultSettings self, SseSerializer serializer);
+
+@protected void sse_encode_vault_stats(VaultStats self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
@@ -3,6 +3,8 @@ import 'dart:io';
 import 'package:flutter/material.dart';
 import 'package:flutter_bloc/flutter_bloc.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:path_provider/path_provider.dart';
+import 'package:flutter_ui/bridge_generated.dart/api.dart';
 import 'package:flutter_ui/bridge_generated.dart/frb_generated.dart';
 import 'package:flutter_ui/ui/screens/bloc/notepad_bloc.dart';
 
@@ -27,6 +29,12 @@ Future<void> main() async {
   try {
     await RustLib.init(externalLibrary: ExternalLibrary.open(getRustLibPath()));
     print("Rust lib initialized successfully");
+
+    final documents = await getApplicationDocumentsDirectory();
+    await RustLib.instance.api.crateApiOpenVault(
+      rootPath: '${documents.path}/encrypted_notes',
+      storage: StorageKind.directory,
+    );
   } catch (e) {
     print("Failed to initialize Rust lib: $e");
     return;
@@ -1,7 +1,7 @@
-import 'dart:io';
+import 'dart:async';
+
 import 'package:flutter_bloc/flutter_bloc.dart';
-import 'package:path_provider/path_provider.dart';
-import 'package:path/path.dart' as path;
+import 'package:flutter_ui/bridge_generated.dart/api.dart';
 import 'package:flutter_ui/bridge_generated.dart/frb_generated.dart';
 import 'notepad_event.dart';
 import 'notepad_state.dart';
@@ -12,10 +12,31 @@ class NotepadBloc extends Bloc<NotepadEvent, NotepadState> {
     on<SaveNoteEvent>(_onSaveNote);
     on<DeleteNoteEvent>(_onDeleteNote);
     on<SearchNotesEvent>(_onSearchNotes);
+    _watchVault();
   }
 
-  List<String> _allNotes = [];
+  List<NoteSummary> _allNotes = [];
+  final Map<String, Note> _openNotes = {};
   String _currentSearchQuery = '';
+  StreamSubscription<VaultEvent>? _vaultEvents;
+
+  /// Reloads the list whenever another app or a sync tool changes a note.
+  void _watchVault() {
+    try {
+      _vaultEvents = RustLib.instance.api.crateApiWatchVault().listen(
+        (_) => add(LoadNotesEvent()),
+        onError: (e) => print("Vault watch failed: $e"),
+      );
+    } catch (e) {
+      print("Vault watch failed: $e");
+    }
+  }
+
+  @override
+  Future<void> close() async {
+    await _vaultEvents?.cancel();
+    return super.close();
+  }
 
   Future<void> _onLoadNotes(
     LoadNotesEvent event,
@@ -24,47 +45,17 @@ class NotepadBloc extends Bloc<NotepadEvent, NotepadState> {
     emit(NotepadLoading());
 
     try {
-      List<String> allTitles = [];
+      List<NoteSummary> allNotes = [];
 
       try {
-        final titlesString =
-            await RustLib.instance.api.crateApiListNoteTitles();
-        if (titlesString.isNotEmpty) {
-          allTitles.addAll(titlesString.split(';'));
-        }
+        allNotes = await RustLib.instance.api.crateApiListNotes();
       } catch (e) {
         print("Error fetching Rust notes: $e");
       }
 
-      if (Platform.isAndroid && allTitles.isEmpty) {
-        try {
-          final directory = await getApplicationDocumentsDirectory();
-          final files =
-              directory
-                  .listSync()
-                  .where((file) => file.path.endsWith('.txt'))
-                  .map((file) => path.basenameWithoutExtension(file.path))
-                  .toList();
-          allTitles.addAll(files);
-        } catch (e) {
-          print("Error fetching Flutter notes: $e");
-        }
-      }
-
-      _allNotes = allTitles;
+      _allNotes = allNotes;
 
-      final filteredNotes =
-          _currentSearchQuery.isEmpty
-              ? _allNotes
-              : _allNotes
-                  .where(
-                    (note) => note.toLowerCase().contains(
-                      _currentSearchQuery.toLowerCase(),
-                    ),
-                  )
-                  .toList();
-
-      emit(NotepadLoaded(notes: filteredNotes));
+      await _search(_currentSearchQuery, emit);
     } catch (e) {
       emit(NotepadError(message: "Failed to load notes: ${e.toString()}"));
     }
@@ -92,35 +83,60 @@ class NotepadBloc extends Bloc<NotepadEvent, NotepadState> {
       }
 
       bool saved = false;
+      String? failureMessage;
 
-      if (Platform.isAndroid) {
-        try {
-          final directory = await getApplicationDocumentsDirectory();
-          final fileName = '${title.replaceAll(RegExp(r'[^\w\s]+'), '')}.txt';
-          final filePath = '${directory.path}/$fileName';
-          final file = File(filePath);
-          await file.writeAsString(content);
-          saved = true;
-        } catch (e) {
-          print("Flutter save failed: $e");
-        }
-      }
-
-      if (!saved) {
-        try {
-          saved = await RustLib.instance.api.crateApiSaveNoteToDisk(
+      try {
+        final note =
+            event.id.isEmpty
+                ? Note(
+                  id: '',
+                  title: title,
+                  content: content,
+                  createdAt: 0,
+                  modifiedAt: 0,
+                  tags: const [],
+                  pinned: false,
+                  contentEncrypted: false,
+                  revision: 0,
+                )
+                : _openNotes[event.id] ??
+                    await RustLib.instance.api.crateApiGetNote(id: event.id);
+        final result = await RustLib.instance.api.crateApiSaveNote(
+          note: Note(
+            id: note.id,
             title: title,
             content: content,
-          );
-        } catch (e) {
-          print("Rust save failed: $e");
+            createdAt: note.createdAt,
+            modifiedAt: note.modifiedAt,
+            tags: note.tags,
+            pinned: note.pinned,
+            color: note.color,
+            contentEncrypted: content.startsWith('🔒 '),
+            revision: note.revision,
+            folderId: note.folderId,
+          ),
+          draftId: event.draftId,
+        );
+        final savedNote = result.saved;
+        if (savedNote != null) {
+          _openNotes[savedNote.id] = savedNote;
+          saved = true;
+        } else if (result.conflict != null) {
+          failureMessage =
+              "\"${result.conflict!.theirs.title}\" was changed elsewhere. "
+              "Reopen it to see the latest version.";
         }
+      } on VaultError catch (e) {
+        print("Rust save failed: ${e.message}");
+        failureMessage = _describeVaultError(e);
+      } catch (e) {
+        print("Rust save failed: $e");
       }
 
       if (saved) {
         add(LoadNotesEvent());
       } else {
-        emit(NotepadError(message: "Failed to save note"));
+        emit(NotepadError(message: failureMessage ?? "Failed to save note"));
         if (currentState is NotepadLoaded) {
           emit(currentState);
         }
@@ -142,37 +158,27 @@ class NotepadBloc extends Bloc<NotepadEvent, NotepadState> {
 
     try {
       bool deleted = false;
+      String? failureMessage;
 
       try {
-        await RustLib.instance.api.crateApiDeleteNoteFromDisk(
-          title: event.title,
-        );
+        await RustLib.instance.api.crateApiDeleteNote(id: event.id);
         deleted = true;
+      } on VaultError catch (e) {
+        print("Rust delete failed: ${e.message}");
+        failureMessage = _describeVaultError(e);
       } catch (e) {
         print("Rust delete failed: $e");
       }
 
-      if (!deleted && Platform.isAndroid) {
-        try {
-          final directory = await getApplicationDocumentsDirectory();
-          final fileName =
-              '${event.title.replaceAll(RegExp(r'[^\w\s]+'), '')}.txt';
-          final filePath = '${directory.path}/$fileName';
-          final file = File(filePath);
-          if (await file.exists()) {
-            await file.delete();
-            deleted = true;
-          }
-        } catch (e) {
-          print("Flutter delete failed: $e");
-        }
-      }
-
       if (deleted) {
-        _allNotes.remove(event.title);
+        _allNotes.removeWhere((note) => note.id == event.id);
         add(LoadNotesEvent());
       } else {
-        emit(NotepadError(message: "Failed to delete note: ${event.title}"));
+        emit(
+          NotepadError(
+            message: failureMessage ?? "Failed to delete note: ${event.title}",
+          ),
+        );
         if (currentState is NotepadLoaded) {
           emit(currentState);
         }
@@ -196,45 +202,78 @@ class NotepadBloc extends Bloc<NotepadEvent, NotepadState> {
       return;
     }
 
-    final filteredNotes =
-        event.query.isEmpty
-            ? _allNotes
-            : _allNotes
-                .where(
-                  (note) =>
-                      note.toLowerCase().contains(event.query.toLowerCase()),
-                )
-                .toList();
-
-    emit(NotepadLoaded(notes: filteredNotes));
+    await _search(event.query, emit);
   }
 
-  Future<String> loadNoteContent(String title) async {
+  /// Runs the query in Rust, which understands filters such as `tag:` and
+  /// `in:`, showing hits as they stream in. A newer search stops this one.
+  /// Falls back to matching titles if the search fails.
+  Future<void> _search(String query, Emitter<NotepadState> emit) async {
+    if (query.trim().isEmpty) {
+      emit(NotepadLoaded(notes: _allNotes));
+      return;
+    }
+    final hits = <SearchHit>[];
+    var diagnostics = <QueryDiagnostic>[];
     try {
-      try {
-        return await RustLib.instance.api.crateApiLoadNoteFromDisk(
-          title: title,
+      await for (final batch in RustLib.instance.api.crateApiSearchStream(
+        query: query,
+        limit: _allNotes.length,
+      )) {
+        if (query != _currentSearchQuery) break;
+        // Batches are scored separately, so a later one may rank higher.
+        hits
+          ..addAll(batch.hits)
+          ..sort((a, b) => b.score.compareTo(a.score));
+        if (batch.diagnostics.isNotEmpty) {
+          diagnostics = batch.diagnostics;
+        }
+        emit(
+          NotepadLoaded(
+            notes: hits.map((hit) => hit.note).toList(),
+            hits: {for (final hit in hits) hit.note.id: hit},
+            diagnostics: diagnostics,
+          ),
         );
-      } catch (e) {
-        print("Rust load failed: $e");
       }
+    } catch (e) {
+      print("Rust search failed: $e");
+      emit(
+        NotepadLoaded(
+          notes:
+              _allNotes
+                  .where(
+                    (note) =>
+                        note.title.toLowerCase().contains(query.toLowerCase()),
+                  )
+                  .toList(),
+        ),
+      );
+    }
+  }
 
-      if (Platform.isAndroid) {
-        final directory = await getApplicationDocumentsDirectory();
-        final fileName = '${title.replaceAll(RegExp(r'[^\w\s]+'), '')}.txt';
-        final filePath = '${directory.path}/$fileName';
-        final file = File(filePath);
-        if (await file.exists()) {
-          return await file.readAsString();
-        }
-      }
+  String _describeVaultError(VaultError error) {
+    if (error.kind == VaultErrorKind.busy) {
+      return "The notes are in use by another app. Try again in a moment.";
+    }
+    return error.message;
+  }
 
-      throw Exception("Note not found: $title");
+  Future<String> loadNoteContent(String id) async {
+    try {
+      return (await loadNote(id)).content;
     } catch (e) {
       throw Exception("Error loading note: ${e.toString()}");
     }
   }
 
+  /// Loads a note for editing; saves are checked against this version.
+  Future<Note> loadNote(String id) async {
+    final note = await RustLib.instance.api.crateApiGetNote(id: id);
+    _openNotes[id] = note;
+    return note;
+  }
+
   Future<String> encryptText(String text) async {
     try {
       return await RustLib.instance.api.crateApiEncryptText(text: text);
@@ -3,16 +3,26 @@ abstract class NotepadEvent {}
 class LoadNotesEvent extends NotepadEvent {}
 
 class SaveNoteEvent extends NotepadEvent {
+  final String id;
   final String title;
   final String content;
+
+  /// The draft of a new note, discarded once it is saved.
+  final String draftId;
   
-  SaveNoteEvent({required this.title, required this.content});
+  SaveNoteEvent({
+    this.id = '',
+    required this.title,
+    required this.content,
+    this.draftId = '',
+  });
 }
 
 class DeleteNoteEvent extends NotepadEvent {
+  final String id;
   final String title;
   
-  DeleteNoteEvent({required this.title});
+  DeleteNoteEvent({required this.id, required this.title});
 }
 
 class SearchNotesEvent extends NotepadEvent {
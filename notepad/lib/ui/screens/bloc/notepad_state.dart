@@ -1,3 +1,5 @@
+import 'package:flutter_ui/bridge_generated.dart/api.dart';
+
 abstract class NotepadState {}
 
 class NotepadInitial extends NotepadState {}
@@ -5,9 +7,19 @@ class NotepadInitial extends NotepadState {}
 class NotepadLoading extends NotepadState {}
 
 class NotepadLoaded extends NotepadState {
-  final List<String> notes;
-  
-  NotepadLoaded({required this.notes});
+  final List<NoteSummary> notes;
+
+  /// Where each listed note matched the search, by note id.
+  final Map<String, SearchHit> hits;
+
+  /// Parts of the search query that were left out of the search.
+  final List<QueryDiagnostic> diagnostics;
+
+  NotepadLoaded({
+    required this.notes,
+    this.hits = const {},
+    this.diagnostics = const [],
+  });
 }
 
 class NotepadError extends NotepadState {
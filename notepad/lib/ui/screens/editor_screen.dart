@@ -2,6 +2,7 @@ import 'dart:async';
 import 'dart:io';
 import 'package:flutter/material.dart';
 import 'package:flutter_bloc/flutter_bloc.dart';
+import 'package:flutter_ui/bridge_generated.dart/api.dart' show Draft;
 import 'package:flutter_ui/bridge_generated.dart/frb_generated.dart' as bridge;
 import 'package:flutter_ui/ui/screens/bloc/notepad_bloc.dart';
 import 'package:flutter_ui/ui/screens/bloc/notepad_event.dart';
@@ -9,13 +10,20 @@ import 'package:flutter_ui/ui/screens/bloc/notepad_state.dart';
 import 'package:permission_handler/permission_handler.dart';
 
 class EditorScreen extends StatefulWidget {
+  final String noteId;
   final String initialTitle;
   final String initialContent;
 
+  /// Unsaved changes recovered from the draft journal, shown in place of
+  /// the initial title and content.
+  final Draft? draft;
+
   const EditorScreen({
     super.key,
+    this.noteId = '',
     this.initialTitle = '',
     this.initialContent = '',
+    this.draft,
   });
 
   @override
@@ -33,8 +41,13 @@ class _EditorScreenState extends State<EditorScreen> {
   late FocusNode _titleFocus;
   late FocusNode _contentFocus;
   Timer? _autoSaveTimer;
+  Timer? _draftTimer;
   DateTime? _lastSaved;
 
+  /// What the drafts of a new note are saved under; empty until one is
+  /// picked, and unused for a note that has been saved.
+  String _draftId = '';
+
   int get wordCount =>
       _contentController.text
           .trim()
@@ -50,11 +63,25 @@ class _EditorScreenState extends State<EditorScreen> {
   @override
   void initState() {
     super.initState();
-    _titleController = TextEditingController(text: widget.initialTitle);
-    _contentController = TextEditingController(text: widget.initialContent);
+    _titleController = TextEditingController(
+      text: widget.draft?.title ?? widget.initialTitle,
+    );
+    _contentController = TextEditingController(
+      text: widget.draft?.content ?? widget.initialContent,
+    );
+    _hasChanges =
+        _titleController.text != widget.initialTitle ||
+        _contentController.text != widget.initialContent;
     _titleFocus = FocusNode();
     _contentFocus = FocusNode();
 
+    _draftId = widget.draft?.draftId ?? '';
+    if (widget.noteId.isEmpty && _draftId.isEmpty) {
+      bridge.RustLib.instance.api
+          .crateApiNewDraftId()
+          .then((id) => _draftId = id);
+    }
+
     _titleController.addListener(_onTextChanged);
     _contentController.addListener(_onTextChanged);
 
@@ -73,6 +100,39 @@ class _EditorScreenState extends State<EditorScreen> {
         _hasChanges = titleChanged || contentChanged;
       });
     }
+    _scheduleDraftSave();
+  }
+
+  /// Journals the unsaved text shortly after typing stops, so it can be
+  /// recovered if the app is killed before the note is saved.
+  void _scheduleDraftSave() {
+    _draftTimer?.cancel();
+    _draftTimer = Timer(const Duration(seconds: 2), () async {
+      if (!_hasChanges) return;
+      if (widget.noteId.isEmpty && _draftId.isEmpty) return;
+      try {
+        await bridge.RustLib.instance.api.crateApiSaveDraft(
+          noteId: widget.noteId,
+          draftId: _draftId,
+          title: _titleController.text,
+          content: _contentController.text,
+        );
+      } catch (e) {
+        debugPrint('Draft save failed: $e');
+      }
+    });
+  }
+
+  Future<void> _discardDraft() async {
+    _draftTimer?.cancel();
+    try {
+      await bridge.RustLib.instance.api.crateApiDiscardDraft(
+        noteId: widget.noteId,
+        draftId: _draftId,
+      );
+    } catch (e) {
+      debugPrint('Draft discard failed: $e');
+    }
   }
 
   void _startAutoSave() {
@@ -100,8 +160,10 @@ class _EditorScreenState extends State<EditorScreen> {
       final bloc = context.read<NotepadBloc>();
       bloc.add(
         SaveNoteEvent(
+          id: widget.noteId,
           title: _titleController.text.trim(),
           content: _contentController.text.trim(),
+          draftId: _draftId,
         ),
       );
 
@@ -204,8 +266,17 @@ class _EditorScreenState extends State<EditorScreen> {
       await requestStoragePermission();
     }
 
+    // Saving the note discards its draft; don't write a new one behind it.
+    _draftTimer?.cancel();
     final bloc = context.read<NotepadBloc>();
-    bloc.add(SaveNoteEvent(title: title, content: content));
+    bloc.add(
+      SaveNoteEvent(
+        id: widget.noteId,
+        title: title,
+        content: content,
+        draftId: _draftId,
+      ),
+    );
   }
 
   Future<bool> _onWillPop() async {
@@ -233,6 +304,9 @@ class _EditorScreenState extends State<EditorScreen> {
             ],
           ),
     );
+    if (result == true) {
+      await _discardDraft();
+    }
     return result ?? false;
   }
 
@@ -281,6 +355,7 @@ class _EditorScreenState extends State<EditorScreen> {
   @override
   void dispose() {
     _autoSaveTimer?.cancel();
+    _draftTimer?.cancel();
     _titleController.removeListener(_onTextChanged);
     _contentController.removeListener(_onTextChanged);
     _titleController.dispose();
@@ -1,5 +1,7 @@
 import 'package:flutter/material.dart';
 import 'package:flutter_bloc/flutter_bloc.dart';
+import 'package:flutter_ui/bridge_generated.dart/api.dart';
+import 'package:flutter_ui/bridge_generated.dart/frb_generated.dart';
 import 'package:flutter_ui/ui/screens/bloc/notepad_bloc.dart';
 import 'package:flutter_ui/ui/screens/bloc/notepad_event.dart';
 import 'package:flutter_ui/ui/screens/bloc/notepad_state.dart';
@@ -19,6 +21,7 @@ class _HomeScreenState extends State<HomeScreen>
   late Animation<double> _animation;
   final TextEditingController _searchController = TextEditingController();
   bool _isSearching = false;
+  List<Suggestion> _suggestions = const [];
 
   @override
   void initState() {
@@ -33,6 +36,111 @@ class _HomeScreenState extends State<HomeScreen>
     context.read<NotepadBloc>().add(LoadNotesEvent());
 
     _searchController.addListener(_onSearchChanged);
+    WidgetsBinding.instance.addPostFrameCallback((_) => _offerDraftRecovery());
+  }
+
+  /// Offers to reopen whatever was being typed when the app last stopped
+  /// without saving.
+  Future<void> _offerDraftRecovery() async {
+    List<Draft> drafts;
+    try {
+      drafts = await RustLib.instance.api.crateApiPendingDrafts();
+    } catch (e) {
+      debugPrint('Could not read drafts: $e');
+      return;
+    }
+
+    for (final draft in drafts) {
+      if (!mounted) return;
+      final title = draft.title.trim().isEmpty ? 'Untitled' : draft.title;
+      final recover = await showDialog<bool>(
+        context: context,
+        barrierDismissible: false,
+        builder:
+            (context) => AlertDialog(
+              title: const Text('Recover unsaved changes?'),
+              content: Text('"$title" has changes that were never saved.'),
+              actions: [
+                TextButton(
+                  onPressed: () => Navigator.of(context).pop(false),
+                  child: const Text('Discard'),
+                ),
+                ElevatedButton(
+                  onPressed: () => Navigator.of(context).pop(true),
+                  child: const Text('Recover'),
+                ),
+              ],
+            ),
+      );
+      if (recover == true) {
+        await _openDraft(draft);
+      } else {
+        try {
+          await RustLib.instance.api.crateApiDiscardDraft(
+            noteId: draft.noteId,
+            draftId: draft.draftId,
+          );
+        } catch (e) {
+          debugPrint('Draft discard failed: $e');
+        }
+      }
+    }
+  }
+
+  Future<void> _openDraft(Draft draft) async {
+    var noteId = draft.noteId;
+    var recovered = draft;
+    var title = '';
+    var content = '';
+    if (noteId.isNotEmpty) {
+      try {
+        final note = await context.read<NotepadBloc>().loadNote(noteId);
+        title = note.title;
+        content = note.content;
+      } catch (e) {
+        // The note was deleted meanwhile: keep the draft as a new note.
+        noteId = '';
+        try {
+          final draftId = await RustLib.instance.api.crateApiNewDraftId();
+          await RustLib.instance.api.crateApiSaveDraft(
+            noteId: '',
+            draftId: draftId,
+            title: draft.title,
+            content: draft.content,
+          );
+          await RustLib.instance.api.crateApiDiscardDraft(
+            noteId: draft.noteId,
+            draftId: '',
+          );
+          recovered = Draft(
+            noteId: '',
+            draftId: draftId,
+            title: draft.title,
+            content: draft.content,
+            savedAt: draft.savedAt,
+          );
+        } catch (e) {
+          debugPrint('Draft move failed: $e');
+        }
+      }
+    }
+
+    if (!mounted) return;
+    final result = await Navigator.push(
+      context,
+      MaterialPageRoute(
+        builder:
+            (context) => EditorScreen(
+              noteId: noteId,
+              initialTitle: title,
+              initialContent: content,
+              draft: recovered,
+            ),
+      ),
+    );
+    if (result == true) {
+      _refreshNotes();
+    }
   }
 
   @override
@@ -46,12 +154,72 @@ class _HomeScreenState extends State<HomeScreen>
     context.read<NotepadBloc>().add(
       SearchNotesEvent(query: _searchController.text),
     );
+    _updateSuggestions();
+  }
+
+  /// The word being typed: everything after the last space, without a
+  /// `tag:` or `in:` in front, and the kind of suggestion that fits it.
+  (String, SuggestionKind?) _typedWord() {
+    final text = _searchController.text;
+    var word = text.substring(text.lastIndexOf(' ') + 1);
+    if (word.startsWith('-')) word = word.substring(1);
+    if (word.startsWith('tag:')) return (word.substring(4), SuggestionKind.tag);
+    if (word.startsWith('in:')) return (word.substring(3), SuggestionKind.folder);
+    return (word, null);
+  }
+
+  Future<void> _updateSuggestions() async {
+    final text = _searchController.text;
+    final (word, kind) = _typedWord();
+    var suggestions = const <Suggestion>[];
+    if (word.isNotEmpty && !word.contains(':') && !word.contains('"')) {
+      try {
+        suggestions = await RustLib.instance.api.crateApiSuggest(
+          prefix: word,
+          kind: kind,
+          limit: 6,
+        );
+      } catch (e) {
+        debugPrint('Suggestions failed: $e');
+      }
+    }
+    // A newer keystroke has its own suggestions on the way.
+    if (!mounted || _searchController.text != text) return;
+    setState(() => _suggestions = suggestions);
+  }
+
+  /// Opens a suggested note, or puts a suggested tag or folder in the query
+  /// as a filter in place of the word being typed.
+  Future<void> _pickSuggestion(Suggestion suggestion) async {
+    _recordUse(suggestion.kind, suggestion.id);
+    if (suggestion.kind == SuggestionKind.title) {
+      await _openNote(suggestion.id, suggestion.text);
+      return;
+    }
+    final text = _searchController.text;
+    final start = text.lastIndexOf(' ') + 1;
+    final negated = text.startsWith('-', start) ? '-' : '';
+    final field = suggestion.kind == SuggestionKind.tag ? 'tag' : 'in';
+    final value =
+        suggestion.text.contains(' ') ? '"${suggestion.text}"' : suggestion.text;
+    final query = '${text.substring(0, start)}$negated$field:$value ';
+    _searchController.value = TextEditingValue(
+      text: query,
+      selection: TextSelection.collapsed(offset: query.length),
+    );
+  }
+
+  void _recordUse(SuggestionKind kind, String id) {
+    RustLib.instance.api
+        .crateApiRecordSuggestionUse(kind: kind, id: id)
+        .catchError((e) => debugPrint('Could not record use: $e'));
   }
 
   void _toggleSearch() {
     setState(() {
       _isSearching = !_isSearching;
       if (!_isSearching) {
+        _suggestions = const [];
         _searchController.clear();
         context.read<NotepadBloc>().add(SearchNotesEvent(query: ''));
       }
@@ -76,10 +244,11 @@ class _HomeScreenState extends State<HomeScreen>
     }
   }
 
-  Future<void> _openNote(String noteTitle) async {
+  Future<void> _openNote(String noteId, String noteTitle) async {
+    _recordUse(SuggestionKind.title, noteId);
     try {
       final notepadBloc = context.read<NotepadBloc>();
-      final content = await notepadBloc.loadNoteContent(noteTitle);
+      final content = await notepadBloc.loadNoteContent(noteId);
 
       if (mounted) {
         final result = await Navigator.push(
@@ -87,6 +256,7 @@ class _HomeScreenState extends State<HomeScreen>
           MaterialPageRoute(
             builder:
                 (context) => EditorScreen(
+                  noteId: noteId,
                   initialTitle: noteTitle,
                   initialContent: content,
                 ),
@@ -109,8 +279,97 @@ class _HomeScreenState extends State<HomeScreen>
     }
   }
 
-  void _deleteNote(String noteTitle) {
-    context.read<NotepadBloc>().add(DeleteNoteEvent(title: noteTitle));
+  void _deleteNote(String noteId, String noteTitle) {
+    context.read<NotepadBloc>().add(
+      DeleteNoteEvent(id: noteId, title: noteTitle),
+    );
+  }
+
+  /// Titles, tags and folders completing the word being typed.
+  Widget _suggestionChips() {
+    return SizedBox(
+      height: 48,
+      child: ListView(
+        scrollDirection: Axis.horizontal,
+        padding: const EdgeInsets.fromLTRB(12, 8, 12, 0),
+        children: [
+          for (final suggestion in _suggestions)
+            Padding(
+              padding: const EdgeInsets.symmetric(horizontal: 4),
+              child: ActionChip(
+                avatar: Icon(switch (suggestion.kind) {
+                  SuggestionKind.title => Icons.description_outlined,
+                  SuggestionKind.tag => Icons.label_outline,
+                  SuggestionKind.folder => Icons.folder_outlined,
+                }, size: 18),
+                label: Text(suggestion.text, overflow: TextOverflow.ellipsis),
+                onPressed: () => _pickSuggestion(suggestion),
+              ),
+            ),
+        ],
+      ),
+    );
+  }
+
+  /// What the search left out of the typed query, such as a misspelt
+  /// filter or a date it could not read.
+  Widget _queryProblems(List<QueryDiagnostic> diagnostics) {
+    final color = Theme.of(context).colorScheme.error;
+    return Padding(
+      padding: const EdgeInsets.fromLTRB(16, 12, 16, 0),
+      child: Column(
+        crossAxisAlignment: CrossAxisAlignment.start,
+        children: [
+          for (final diagnostic in diagnostics)
+            Row(
+              children: [
+                Icon(Icons.warning_amber_rounded, size: 18, color: color),
+                const SizedBox(width: 8),
+                Expanded(
+                  child: Text(
+                    diagnostic.message,
+                    style: TextStyle(fontSize: 13, color: color),
+                  ),
+                ),
+              ],
+            ),
+        ],
+      ),
+    );
+  }
+
+  /// [text] with the search matches in bold and tinted.
+  Widget _highlighted(
+    String text,
+    List<TextMatch> matches,
+    TextStyle style, {
+    int? maxLines,
+  }) {
+    final highlight = style.copyWith(
+      fontWeight: FontWeight.bold,
+      backgroundColor: Theme.of(
+        context,
+      ).colorScheme.primary.withOpacity(0.15),
+    );
+    final spans = <TextSpan>[];
+    var position = 0;
+    for (final match in matches) {
+      if (match.start < position || match.end > text.length) continue;
+      spans.add(TextSpan(text: text.substring(position, match.start)));
+      spans.add(
+        TextSpan(
+          text: text.substring(match.start, match.end),
+          style: highlight,
+        ),
+      );
+      position = match.end;
+    }
+    spans.add(TextSpan(text: text.substring(position)));
+    return Text.rich(
+      TextSpan(style: style, children: spans),
+      maxLines: maxLines,
+      overflow: maxLines == null ? null : TextOverflow.ellipsis,
+    );
   }
 
   @override
@@ -180,6 +439,17 @@ class _HomeScreenState extends State<HomeScreen>
                   ),
                 ),
               ),
+            if (_isSearching && _suggestions.isNotEmpty) _suggestionChips(),
+            if (_isSearching)
+              BlocBuilder<NotepadBloc, NotepadState>(
+                buildWhen: (previous, current) => current is NotepadLoaded,
+                builder: (context, state) {
+                  if (state is! NotepadLoaded || state.diagnostics.isEmpty) {
+                    return const SizedBox.shrink();
+                  }
+                  return _queryProblems(state.diagnostics);
+                },
+              ),
             Expanded(
               child: BlocConsumer<NotepadBloc, NotepadState>(
                 listener: (context, state) {
@@ -266,9 +536,11 @@ class _HomeScreenState extends State<HomeScreen>
                       ),
                       itemCount: notes.length,
                       itemBuilder: (context, index) {
-                        final noteTitle = notes[index];
+                        final noteId = notes[index].id;
+                        final noteTitle = notes[index].title;
+                        final hit = state.hits[noteId];
                         return Dismissible(
-                          key: Key(noteTitle),
+                          key: Key(noteId),
                           direction: DismissDirection.endToStart,
                           background: Container(
                             alignment: Alignment.centerRight,
@@ -326,7 +598,7 @@ class _HomeScreenState extends State<HomeScreen>
                             );
                           },
                           onDismissed: (direction) {
-                            _deleteNote(noteTitle);
+                            _deleteNote(noteId, noteTitle);
                             ScaffoldMessenger.of(context).showSnackBar(
                               SnackBar(
                                 content: Text('$noteTitle deleted'),
@@ -345,7 +617,7 @@ class _HomeScreenState extends State<HomeScreen>
                             clipBehavior: Clip.antiAlias,
                             elevation: 2,
                             child: InkWell(
-                              onTap: () => _openNote(noteTitle),
+                              onTap: () => _openNote(noteId, noteTitle),
                               child: Padding(
                                 padding: const EdgeInsets.all(16.0),
                                 child: Row(
@@ -353,12 +625,35 @@ class _HomeScreenState extends State<HomeScreen>
                                     const Icon(Icons.note_rounded),
                                     const SizedBox(width: 12),
                                     Expanded(
-                                      child: Text(
-                                        noteTitle,
-                                        style: const TextStyle(
-                                          fontSize: 18,
-                                          fontWeight: FontWeight.w500,
-                                        ),
+                                      child: Column(
+                                        crossAxisAlignment:
+                                            CrossAxisAlignment.start,
+                                        children: [
+                                          _highlighted(
+                                            noteTitle,
+                                            hit?.titleMatches ?? const [],
+                                            const TextStyle(
+                                              fontSize: 18,
+                                              fontWeight: FontWeight.w500,
+                                            ),
+                                          ),
+                                          if (hit != null &&
+                                              hit.snippet.isNotEmpty) ...[
+                                            const SizedBox(height: 4),
+                                            _highlighted(
+                                              hit.snippet,
+                                              hit.snippetMatches,
+                                              TextStyle(
+                                                fontSize: 14,
+                                                color: Theme.of(context)
+                                                    .colorScheme
+                                                    .onSurface
+                                                    .withOpacity(0.7),
+                                              ),
+                                              maxLines: 2,
+                                            ),
+                                          ],
+                                        ],
                                       ),
                                     ),
                                     const Icon(Icons.lock, size: 20),
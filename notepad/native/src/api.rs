@@ -1,66 +1,69 @@
+use crate::crypto::xor_encrypt_decrypt;
+use crate::frb_generated::StreamSink;
+use crate::history;
+use crate::note;
+use crate::vault::{self, Vault, VaultBusy};
 use flutter_rust_bridge::frb;
-use std::fs::{self, File};
-use std::io::{self, Write};
-use std::path::PathBuf;
-use std::sync::Once;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
-use std::sync::OnceLock;
-use sha2::{Sha256, Digest};
-
-static DERIVED_KEY: OnceLock<[u8; 32]> = OnceLock::new();
-
-fn get_encryption_key() -> &'static [u8; 32] {
-    DERIVED_KEY.get_or_init(|| {
-        let mut hasher = Sha256::new();
-        hasher.update(b"NOTEPAD_SECRET_KEY_2025");
-        hasher.update(std::env::var("USER").unwrap_or_default().as_bytes());
-        let result = hasher.finalize();
-        result.into()
-    })
+/// A note with its metadata. The whole struct, not just `content`, is
+/// serialized into the encrypted envelope on disk.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Note {
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    /// Milliseconds since the Unix epoch.
+    pub created_at: i64,
+    /// Milliseconds since the Unix epoch.
+    pub modified_at: i64,
+    pub tags: Vec<String>,
+    pub pinned: bool,
+    /// ARGB value, as used by Flutter's `Color`.
+    pub color: Option<u32>,
+    /// Set when `content` holds ciphertext from `encrypt_text`.
+    pub content_encrypted: bool,
+    /// Bumped on every save. A save is based on the revision it carries and
+    /// is refused if the stored note has moved on since.
+    pub revision: u32,
+    /// `None` for notes at the top level of the vault.
+    pub folder_id: Option<String>,
 }
 
-fn calculate_checksum(data: &[u8]) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(data);
-    format!("{:x}", hasher.finalize())
-}
-
-static ENCRYPTION_KEY: &[u8] = b"NOTEPAD_SECRET_KEY_2025";
-static INIT: Once = Once::new();
-
-fn initialize() {
-    let dir = notes_dir();
-    if !dir.exists() {
-        if let Err(e) = fs::create_dir_all(&dir) {
-            eprintln!("Failed to create notes directory: {}", e);
-        } else {
-            println!("Notes directory created at: {:?}", dir);
-        }
-    }
+/// The outcome of `save_note`: exactly one of the two fields is set.
+#[derive(Clone, Debug)]
+pub struct SaveResult {
+    /// The note as stored, with its new revision and timestamps.
+    pub saved: Option<Note>,
+    pub conflict: Option<SaveConflict>,
 }
 
-fn notes_dir() -> PathBuf {
-    let mut dir = std::env::current_dir().unwrap_or_default();
-    dir.push("notes");
-    dir
+/// Both sides of a save that lost the race against another writer.
+#[derive(Clone, Debug)]
+pub struct SaveConflict {
+    /// The version that was not written.
+    pub ours: Note,
+    /// The version currently on disk.
+    pub theirs: Note,
 }
 
-fn ensure_directory_exists() -> io::Result<PathBuf> {
-    INIT.call_once(|| {
-        initialize();
-    });
-    let dir = notes_dir();
-    if !dir.exists() {
-        fs::create_dir_all(&dir)?;
-    }
-    Ok(dir)
-}
-
-fn xor_encrypt_decrypt(data: &[u8]) -> Vec<u8> {
-    data.iter()
-        .zip(ENCRYPTION_KEY.iter().cycle())
-        .map(|(&b, &k)| b ^ k)
-        .collect()
+/// The metadata of a note without its content, as listed from the index.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoteSummary {
+    pub id: String,
+    pub title: String,
+    /// Milliseconds since the Unix epoch.
+    pub created_at: i64,
+    /// Milliseconds since the Unix epoch.
+    pub modified_at: i64,
+    pub tags: Vec<String>,
+    pub pinned: bool,
+    /// ARGB value, as used by Flutter's `Color`.
+    pub color: Option<u32>,
+    pub folder_id: Option<String>,
 }
 
 fn base64_decode(data: &str) -> Result<Vec<u8>, String> {
@@ -68,7 +71,7 @@ fn base64_decode(data: &str) -> Result<Vec<u8>, String> {
     let mut result = Vec::new();
     let data = data.trim();
     
-    if data.len() % 4 != 0 {
+    if !data.len().is_multiple_of(4) {
         return Err("Invalid base64 length".to_string());
     }
     
@@ -99,141 +102,80 @@ fn base64_decode(data: &str) -> Result<Vec<u8>, String> {
     Ok(result)
 }
 
+/// How a vault is laid out where it is stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageKind {
+    /// A directory with one file per note, revision and chunk.
+    Directory,
+    /// Nothing is persisted; the vault is gone when the app exits.
+    Memory,
+    /// A single SQLite database file.
+    Sqlite,
+}
+
+/// Opens the vault at `root_path`, a directory or database file depending
+/// on `storage`, and returns where it lives.
 #[frb]
-pub fn save_note_to_disk(title: String, content: String) -> bool {
-    let sanitized = title.chars()
-        .map(|c| if c.is_alphanumeric() || c == ' ' { c } else { '_' })
-        .collect::<String>()
-        .trim()
-        .to_string();
-    
-    if sanitized.is_empty() {
-        eprintln!("[Rust] Invalid title after sanitization");
-        return false;
-    }
-    
-    let base_path = if cfg!(target_os = "android") {
-        std::env::var("ANDROID_DATA")
-            .unwrap_or_else(|_| "/data/data".to_string())
-    } else {
-        std::env::var("HOME")
-            .unwrap_or_else(|_| "/tmp".to_string())
-    };
-    
-    let notes_dir = if cfg!(target_os = "android") {
-        format!("{}/files/notes", base_path)
-    } else {
-        format!("{}/Documents/encrypted_notes", base_path)
-    };
-    
-    let path = PathBuf::from(format!("{}/{}.txt", notes_dir, sanitized));
-    
-    if let Some(parent) = path.parent() {
-        if let Err(e) = fs::create_dir_all(parent) {
-            eprintln!("[Rust] Failed to create folder {:?}: {}", parent, e);
-            return false;
-        }
-    }
-    
-    let encrypted = xor_encrypt_decrypt(content.as_bytes());
-    match File::create(&path).and_then(|mut f| f.write_all(&encrypted)) {
-        Ok(_) => {
-            println!("[Rust] Saved note to {:?}", path);
-            true
-        }
-        Err(e) => {
-            eprintln!("[Rust] File write failed: {}", e);
-            false
-        }
-    }
+pub fn open_vault(root_path: String, storage: StorageKind) -> Result<String, VaultError> {
+    let vault = Vault::open(storage, Path::new(&root_path))?;
+    let location = vault.location();
+    println!("[Rust] Opened vault at {}", location);
+    vault::set_current(vault);
+    Ok(location)
 }
 
+/// Creates the note when `note.id` is empty, otherwise updates the note
+/// with that id if it is still at `note.revision`. Timestamps and the next
+/// revision are filled in here, not by the caller. For a new note,
+/// `draft_id` is the id its drafts were saved under, and that draft is
+/// discarded; it may be empty.
 #[frb]
-pub fn load_note_from_disk(title: String) -> String {
-    let base_path = if cfg!(target_os = "android") {
-        std::env::var("ANDROID_DATA")
-            .unwrap_or_else(|_| "/data/data".to_string())
-    } else {
-        std::env::var("HOME")
-            .unwrap_or_else(|_| "/tmp".to_string())
-    };
-    
-    let notes_dir = if cfg!(target_os = "android") {
-        format!("{}/files/notes", base_path)
-    } else {
-        format!("{}/Documents/encrypted_notes", base_path)
-    };
-    
-    let path = PathBuf::from(format!("{}/{}.txt", notes_dir, title));
-    
-    match fs::read(&path) {
-        Ok(encrypted_bytes) => {
-            let decrypted_bytes = xor_encrypt_decrypt(&encrypted_bytes);
-            match String::from_utf8(decrypted_bytes) {
-                Ok(content) => {
-                    println!("[Rust] Loaded note from {:?}", path);
-                    content
-                }
-                Err(e) => {
-                    eprintln!("[Rust] Failed to decode UTF-8: {}", e);
-                    String::new()
-                }
-            }
-        }
-        Err(e) => {
-            eprintln!("[Rust] Failed to read file {:?}: {}", path, e);
-            String::new()
-        }
+pub fn save_note(note: Note, draft_id: String) -> Result<SaveResult, VaultError> {
+    note::validate_title(&note.title)?;
+    let vault = vault::current()?;
+    let result = vault.save_note(note, &draft_id)?;
+    match (&result.saved, &result.conflict) {
+        (Some(note), _) => println!("[Rust] Saved note {} at revision {}", note.id, note.revision),
+        (_, Some(conflict)) => eprintln!(
+            "[Rust] Conflict saving note {}: based on revision {}, stored is {}",
+            conflict.ours.id, conflict.ours.revision, conflict.theirs.revision
+        ),
+        _ => {}
     }
+    Ok(result)
 }
 
 #[frb]
-pub async fn list_note_titles() -> String {
-    let dir = match ensure_directory_exists() {
-        Ok(dir) => dir,
-        Err(_) => return String::new(),
-    };
-    
-    let mut titles = Vec::new();
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            if let Some(name) = entry.file_name().to_str() {
-                if let Some(title) = name.strip_suffix(".txt") {
-                    titles.push(title.to_string());
-                }
-            }
-        }
-    }
-    
-    titles.sort();
-    titles.join(";")
+pub fn get_note(id: String) -> Result<Note, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.read_note(&id)?)
 }
 
+/// Changes only the title; the id, and with it the file, stays the same.
 #[frb]
-pub fn delete_note_from_disk(title: String) {
-    let title_str = title;
-    
-    let dir = match ensure_directory_exists() {
-        Ok(dir) => dir,
-        Err(e) => {
-            eprintln!("Failed to access notes directory for deletion: {}", e);
-            return;
-        }
-    };
-    
-    let mut path = dir;
-    path.push(format!("{}.txt", title_str));
-    
-    if let Err(e) = fs::remove_file(&path) {
-        eprintln!("Failed to delete note {}: {}", title_str, e);
-    } else {
-        println!("Note deleted successfully: {}", title_str);
-    }
+pub fn rename_note(id: String, new_title: String) -> Result<Note, VaultError> {
+    note::validate_title(&new_title)?;
+    let vault = vault::current()?;
+    Ok(vault.update_note(&id, |note| note.title = new_title)?)
+}
+
+#[frb]
+pub fn list_notes() -> Result<Vec<NoteSummary>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_notes()?)
+}
+
+#[frb]
+pub fn delete_note(id: String) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    vault.delete_note(&id)?;
+    println!("Note deleted successfully: {}", id);
+    Ok(())
 }
 
 fn base64_encode(data: &[u8]) -> String {
     const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
-    let mut result = String::with_capacity((data.len() + 2) / 3 * 4);
+    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
     
     for chunk in data.chunks(3) {
         let b0 = chunk[0] as u32;
@@ -295,22 +237,843 @@ pub fn decrypt_text(encrypted_text: String) -> String {
 }
 
 #[frb]
-pub fn get_notes_directory() -> Result<String, anyhow::Error> {
-    let base_path = if cfg!(target_os = "android") {
-        std::env::var("ANDROID_DATA")
-            .map(|data| format!("{}/data/com.example.notepad/files", data))
-            .unwrap_or_else(|_| "/data/data/com.example.notepad/files".to_string())
-    } else {
-        std::env::var("HOME")
-            .map(|home| format!("{}/Documents/encrypted_notes", home))
-            .unwrap_or_else(|_| "/tmp/encrypted_notes".to_string())
-    };
-    
-    let notes_dir = PathBuf::from(format!("{}/notes", base_path));
-    
-    if !notes_dir.exists() {
-        std::fs::create_dir_all(&notes_dir)?;
+pub fn get_notes_directory() -> Result<String, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.location())
+}
+
+/// How many old revisions of each note the vault keeps.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// The newest revisions kept no matter how old they are; at least one.
+    pub keep_last: u32,
+    /// Past `keep_last`, keep the newest revision of each day for this many days.
+    pub keep_daily_days: u32,
+    /// After the daily window, keep the newest revision of each week for this
+    /// many weeks. Anything older is deleted.
+    pub keep_weekly_weeks: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        RetentionPolicy {
+            keep_last: 50,
+            keep_daily_days: 30,
+            keep_weekly_weeks: 26,
+        }
     }
-    
-    Ok(notes_dir.to_string_lossy().to_string())
-}
\ No newline at end of file
+}
+
+/// Whether note content is compressed before it is encrypted. Compression
+/// makes the stored size depend on what a note says, so vaults where that
+/// side channel matters can turn it off.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompressionSettings {
+    pub enabled: bool,
+    /// Notes smaller than this many bytes are stored uncompressed.
+    pub min_note_size: u32,
+}
+
+impl Default for CompressionSettings {
+    fn default() -> Self {
+        CompressionSettings {
+            enabled: true,
+            min_note_size: 16 * 1024,
+        }
+    }
+}
+
+/// Per-vault options, stored encrypted next to the notes.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VaultSettings {
+    pub retention: RetentionPolicy,
+    pub compression: CompressionSettings,
+}
+
+#[derive(Clone, Debug)]
+pub struct RevisionInfo {
+    pub revision: u32,
+    /// Milliseconds since the Unix epoch.
+    pub modified_at: i64,
+    pub title: String,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum DiffGranularity {
+    Line,
+    Word,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Clone, Debug)]
+pub struct DiffChange {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// A run of changes with some unchanged context around it. Starts and
+/// lengths count lines or words, depending on the requested granularity.
+#[derive(Clone, Debug)]
+pub struct DiffHunk {
+    pub old_start: u32,
+    pub old_len: u32,
+    pub new_start: u32,
+    pub new_len: u32,
+    pub changes: Vec<DiffChange>,
+}
+
+#[frb]
+pub fn get_vault_settings() -> Result<VaultSettings, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.settings()?)
+}
+
+#[frb]
+pub fn set_vault_settings(settings: VaultSettings) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.save_settings(&settings)?)
+}
+
+#[frb]
+pub fn list_revisions(note_id: String) -> Result<Vec<RevisionInfo>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_revisions(&note_id)?)
+}
+
+#[frb]
+pub fn get_revision(note_id: String, revision: u32) -> Result<Note, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.read_revision(&note_id, revision)?)
+}
+
+/// Diffs the content of two revisions of the same note, `from` being the
+/// older side.
+#[frb]
+pub fn diff_revisions(
+    note_id: String,
+    from_revision: u32,
+    to_revision: u32,
+    granularity: DiffGranularity,
+) -> Result<Vec<DiffHunk>, VaultError> {
+    let vault = vault::current()?;
+    let from = vault.read_revision(&note_id, from_revision)?;
+    let to = vault.read_revision(&note_id, to_revision)?;
+    Ok(history::diff(&from.content, &to.content, granularity))
+}
+
+/// Brings back an old revision as a new one on top of the history, so the
+/// versions in between stay available.
+#[frb]
+pub fn restore_revision(note_id: String, revision: u32) -> Result<Note, VaultError> {
+    let vault = vault::current()?;
+    let old = vault.read_revision(&note_id, revision)?;
+    let note = vault.update_note(&note_id, |note| {
+        note.title = old.title;
+        note.content = old.content;
+        note.tags = old.tags;
+        note.pinned = old.pinned;
+        note.color = old.color;
+        note.content_encrypted = old.content_encrypted;
+    })?;
+    Ok(note)
+}
+
+/// A notebook or nested folder. Folders exist only in the vault metadata;
+/// notes point at theirs through `Note::folder_id`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Folder {
+    pub id: String,
+    pub name: String,
+    /// `None` for top-level folders.
+    pub parent_id: Option<String>,
+    /// Milliseconds since the Unix epoch.
+    pub created_at: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct FolderInfo {
+    pub folder: Folder,
+    /// Notes directly in this folder.
+    pub note_count: u32,
+    /// Notes in this folder and all folders below it.
+    pub total_note_count: u32,
+}
+
+#[frb]
+pub fn create_folder(name: String, parent_id: Option<String>) -> Result<Folder, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.create_folder(name, parent_id)?)
+}
+
+#[frb]
+pub fn rename_folder(id: String, new_name: String) -> Result<Folder, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.rename_folder(&id, new_name)?)
+}
+
+#[frb]
+pub fn move_folder(id: String, new_parent_id: Option<String>) -> Result<Folder, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.move_folder(&id, new_parent_id)?)
+}
+
+/// Refuses to delete a folder that still has notes or subfolders unless
+/// `recursive` is set, in which case all of them are deleted with it.
+#[frb]
+pub fn delete_folder(id: String, recursive: bool) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.delete_folder(&id, recursive)?)
+}
+
+#[frb]
+pub fn list_folders() -> Result<Vec<FolderInfo>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_folders()?)
+}
+
+/// Lists the notes in a folder, or at the top level when `folder_id` is
+/// `None`. With `include_subfolders`, notes in nested folders are included
+/// too, which for `None` means every note in the vault.
+#[frb]
+pub fn list_notes_in_folder(
+    folder_id: Option<String>,
+    include_subfolders: bool,
+) -> Result<Vec<NoteSummary>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_notes_in_folder(folder_id.as_deref(), include_subfolders)?)
+}
+
+#[frb]
+pub fn move_note(id: String, folder_id: Option<String>) -> Result<Note, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.move_note(&id, folder_id)?)
+}
+
+#[derive(Clone, Debug)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: u32,
+}
+
+/// Matches notes that carry every tag in `all_of`, at least one tag in
+/// `any_of` (when it is not empty) and none of the tags in `none_of`. Tags
+/// match ignoring case, as `tag:` does in a search.
+#[derive(Clone, Debug, Default)]
+pub struct TagQuery {
+    pub all_of: Vec<String>,
+    pub any_of: Vec<String>,
+    pub none_of: Vec<String>,
+}
+
+#[frb]
+pub fn list_tags() -> Result<Vec<TagCount>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_tags()?)
+}
+
+/// Returns the number of notes that were retagged.
+#[frb]
+pub fn rename_tag(old_tag: String, new_tag: String) -> Result<u32, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.rename_tag(&old_tag, &new_tag)?)
+}
+
+/// Folds all of `sources` into `target`. Returns the number of notes that
+/// were retagged.
+#[frb]
+pub fn merge_tags(sources: Vec<String>, target: String) -> Result<u32, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.merge_tags(&sources, &target)?)
+}
+
+#[frb]
+pub fn list_notes_by_tags(query: TagQuery) -> Result<Vec<NoteSummary>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_notes_by_tags(&query)?)
+}
+
+/// A binary file linked to a note. The contents are encrypted in the vault
+/// and read back in ranges or exported, never handed over whole.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub note_id: String,
+    pub file_name: String,
+    pub mime_type: String,
+    /// Plaintext size in bytes.
+    pub size: u64,
+    /// Milliseconds since the Unix epoch.
+    pub created_at: i64,
+}
+
+/// Encrypts the file at `source_path` into the vault and attaches it to the
+/// note. The source file is left untouched.
+#[frb]
+pub fn add_attachment(
+    note_id: String,
+    source_path: String,
+    file_name: String,
+    mime_type: String,
+) -> Result<Attachment, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.add_attachment(&note_id, Path::new(&source_path), file_name, mime_type)?)
+}
+
+#[frb]
+pub fn list_attachments(note_id: String) -> Result<Vec<Attachment>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.list_attachments(&note_id)?)
+}
+
+/// Returns up to `length` bytes of the attachment starting at `offset`;
+/// fewer when the range runs past the end.
+#[frb]
+pub fn read_attachment_range(id: String, offset: u64, length: u64) -> Result<Vec<u8>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.read_attachment_range(&id, offset, length)?)
+}
+
+/// Writes the decrypted attachment to `destination_path`, replacing any file
+/// there. Returns the number of bytes written.
+#[frb]
+pub fn export_attachment(id: String, destination_path: String) -> Result<u64, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.export_attachment(&id, Path::new(&destination_path))?)
+}
+
+#[frb]
+pub fn delete_attachment(id: String) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.delete_attachment(&id)?)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct GcReport {
+    /// Chunks still used by a note, revision or attachment.
+    pub live_chunks: u32,
+    pub removed_chunks: u32,
+    pub freed_bytes: u64,
+}
+
+/// Deletes stored chunks that no note, revision or attachment refers to any
+/// more. Chunks are normally freed as soon as their last user goes; this
+/// also catches those left behind by an interrupted write.
+#[frb]
+pub fn collect_garbage() -> Result<GcReport, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.collect_garbage()?)
+}
+
+/// What every vault call fails with. `kind` lets Dart tell a vault held by
+/// another process apart from other failures without parsing `message`.
+#[derive(Clone, Debug)]
+pub struct VaultError {
+    pub kind: VaultErrorKind,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultErrorKind {
+    /// Another process kept the vault locked; retrying later can succeed.
+    Busy,
+    Other,
+}
+
+impl From<anyhow::Error> for VaultError {
+    fn from(error: anyhow::Error) -> Self {
+        let kind = if error.downcast_ref::<VaultBusy>().is_some() {
+            VaultErrorKind::Busy
+        } else {
+            VaultErrorKind::Other
+        };
+        VaultError {
+            kind,
+            message: format!("{:#}", error),
+        }
+    }
+}
+
+impl std::fmt::Display for VaultError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultEventKind {
+    Created,
+    Modified,
+    Deleted,
+    /// The note was changed elsewhere on top of the version this app saved,
+    /// or a sync tool left a conflicting copy of it next to it.
+    Conflict,
+}
+
+/// A change to a note made by another process or device.
+#[derive(Clone, Debug)]
+pub struct VaultEvent {
+    pub kind: VaultEventKind,
+    pub note_id: String,
+    /// The note as it is now; `None` once it is deleted.
+    pub summary: Option<NoteSummary>,
+    /// The storage key of a sync tool's conflict copy, if that is what was
+    /// found.
+    pub conflict_copy: Option<String>,
+}
+
+/// Streams changes other processes make to the open vault. Changes made
+/// through this API are not reported. A new call ends the previous stream.
+#[frb]
+pub fn watch_vault(sink: StreamSink<VaultEvent>) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    vault.watch(move |event| sink.add(event).is_ok())?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VaultProblemKind {
+    /// The file does not decrypt, or holds something other than its name
+    /// says.
+    CorruptFile,
+    /// The record decrypts, but chunks of its content are missing or damaged.
+    MissingContent,
+    /// A revision whose note is gone.
+    OrphanedRevision,
+    /// An attachment whose note is gone.
+    OrphanedAttachment,
+    /// A stored chunk nothing refers to.
+    OrphanedChunk,
+    /// The index is unreadable or disagrees with the notes.
+    IndexMismatch,
+    /// The chunk reference counts are unreadable or disagree with the records.
+    ChunkRefsMismatch,
+    /// What is left of a write that never completed.
+    TempFile,
+    /// A file the vault did not write, such as a sync tool's conflict copy.
+    UnknownFile,
+}
+
+#[derive(Clone, Debug)]
+pub struct VaultProblem {
+    pub kind: VaultProblemKind,
+    /// The storage key of the file concerned.
+    pub key: String,
+    pub note_id: Option<String>,
+    pub detail: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct VaultCheckReport {
+    pub files_checked: u32,
+    pub problems: Vec<VaultProblem>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RepairOptions {
+    /// Also delete orphaned revisions, attachments and chunks.
+    pub remove_orphans: bool,
+    pub remove_temp_files: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RepairReport {
+    /// Keys of the files moved out of the way, now below `quarantine_dir`.
+    pub quarantined: Vec<String>,
+    pub quarantine_dir: String,
+    /// Notes whose damaged file was replaced by their newest intact revision.
+    pub restored_notes: Vec<String>,
+    /// Keys of the orphans and temp files deleted.
+    pub removed: Vec<String>,
+    /// What a check still finds afterwards.
+    pub remaining: Vec<VaultProblem>,
+}
+
+/// Reads every file in the vault and reports what is damaged, orphaned or
+/// out of sync, without changing anything.
+#[frb]
+pub fn check_vault() -> Result<VaultCheckReport, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.check()?)
+}
+
+/// Moves damaged files into a quarantine directory inside the vault,
+/// restores damaged notes from their history where possible, and rebuilds
+/// the index and chunk counts from what survives.
+#[frb]
+pub fn repair_vault(options: RepairOptions) -> Result<RepairReport, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.repair(&options)?)
+}
+
+/// A slice of a note's content, for editors that load large notes lazily.
+#[derive(Clone, Debug)]
+pub struct NoteWindow {
+    pub text: String,
+    /// Byte offset of `text` in the content.
+    pub offset: u64,
+    /// The line `text` starts on, counted from 0.
+    pub first_line: u32,
+    /// Size of the whole content in bytes.
+    pub total_size: u64,
+    pub total_lines: u32,
+}
+
+/// Returns `line_count` lines of the note starting at `first_line`, counted
+/// from 0. A window past the last line comes back empty.
+#[frb]
+pub fn read_note_lines(id: String, first_line: u32, line_count: u32) -> Result<NoteWindow, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.read_note_lines(&id, first_line, line_count)?)
+}
+
+/// Returns up to `length` bytes of the note's content starting at `offset`,
+/// without splitting a character: the window's `offset` says where it
+/// actually starts.
+#[frb]
+pub fn read_note_range(id: String, offset: u64, length: u64) -> Result<NoteWindow, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.read_note_range(&id, offset, length)?)
+}
+
+/// Editor content that has not been saved yet, kept so that it survives the
+/// app being killed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Draft {
+    /// The note being edited, or empty for a note that was never saved.
+    pub note_id: String,
+    /// For a note that was never saved, the id from `new_draft_id` that
+    /// its editor saves the draft under; empty otherwise.
+    pub draft_id: String,
+    pub title: String,
+    pub content: String,
+    /// Milliseconds since the Unix epoch.
+    pub saved_at: i64,
+}
+
+/// An id for the drafts of a note that has not been saved yet, so that
+/// two such notes being edited keep separate drafts.
+#[frb]
+pub fn new_draft_id() -> String {
+    note::new_id()
+}
+
+/// Stores the editor's current content for `note_id`, or for a new note
+/// under `draft_id` when `note_id` is empty, replacing the previous draft.
+/// Cheap enough to call every few seconds. Saving the note discards its
+/// draft.
+#[frb]
+pub fn save_draft(
+    note_id: String,
+    draft_id: String,
+    title: String,
+    content: String,
+) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.save_draft(&note_id, &draft_id, title, content)?)
+}
+
+/// Drafts left behind by editing sessions that ended without a save, for
+/// offering recovery on launch. Newest first.
+#[frb]
+pub fn pending_drafts() -> Result<Vec<Draft>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.pending_drafts()?)
+}
+
+/// Takes the same ids as `save_draft`.
+#[frb]
+pub fn discard_draft(note_id: String, draft_id: String) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.discard_draft(&note_id, &draft_id)?)
+}
+
+/// Where a vault's bytes go. Sizes are encrypted sizes as stored, unless
+/// said otherwise.
+#[derive(Clone, Debug, Default)]
+pub struct VaultStats {
+    pub note_count: u32,
+    /// Everything the vault stores.
+    pub total_bytes: u64,
+    /// Chunks hold the content of notes, revisions and attachments, each
+    /// stored once however many of them use it.
+    pub chunk_count: u32,
+    pub chunk_bytes: u64,
+    pub revision_count: u32,
+    /// The revision records; their content is counted in the chunks.
+    pub revision_bytes: u64,
+    pub attachment_count: u32,
+    /// Plaintext size of all attachments.
+    pub attachment_bytes: u64,
+    /// Unsaved editor drafts.
+    pub draft_count: u32,
+    pub draft_bytes: u64,
+    /// What `repair_vault` moved aside, one batch per repair, newest first.
+    /// Deleted notes are removed at once, so this is the vault's only trash.
+    pub quarantine: Vec<QuarantineStats>,
+    /// Milliseconds since the Unix epoch; `None` in an empty vault.
+    pub oldest_modified_at: Option<i64>,
+    pub newest_modified_at: Option<i64>,
+    /// Largest first.
+    pub notes: Vec<NoteStats>,
+}
+
+/// One `repair_vault` run's worth of quarantined files.
+#[derive(Clone, Debug, Default)]
+pub struct QuarantineStats {
+    /// When the repair ran, in milliseconds since the Unix epoch.
+    pub quarantined_at: i64,
+    pub file_count: u32,
+    pub bytes: u64,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NoteStats {
+    pub id: String,
+    pub title: String,
+    /// Plaintext size of the content.
+    pub content_bytes: u64,
+    /// The note file plus the chunks its content is in. A chunk shared with
+    /// other notes counts in full for each of them.
+    pub stored_bytes: u64,
+    pub revision_count: u32,
+    pub revision_bytes: u64,
+    pub attachment_count: u32,
+    /// Plaintext size of the note's attachments.
+    pub attachment_bytes: u64,
+}
+
+#[frb]
+pub fn vault_stats() -> Result<VaultStats, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.stats()?)
+}
+
+/// A note found by `search_notes`.
+#[derive(Clone, Debug, Default)]
+pub struct SearchHit {
+    pub note: NoteSummary,
+    /// Higher is a better match; only meaningful relative to other hits of
+    /// the same search.
+    pub score: f64,
+    /// Where the query matched in `note.title`.
+    pub title_matches: Vec<TextMatch>,
+    /// A stretch of the content around the matches, empty if they were only in
+    /// the title.
+    pub snippet: String,
+    pub snippet_matches: Vec<TextMatch>,
+}
+
+/// A matched word, in UTF-16 code units so it can be passed straight to
+/// Dart's `String.substring`.
+#[derive(Clone, Debug, Default)]
+pub struct TextMatch {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// What `search_notes` found, and what it could not make sense of in the
+/// query.
+#[derive(Clone, Debug, Default)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    pub diagnostics: Vec<QueryDiagnostic>,
+}
+
+/// A part of a search query that was left out of the search.
+#[derive(Clone, Debug)]
+pub struct QueryDiagnostic {
+    pub kind: QueryDiagnosticKind,
+    pub message: String,
+    /// Where in the query, in UTF-16 code units.
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryDiagnosticKind {
+    /// A `"` without a closing one; the rest of the query is the phrase.
+    UnterminatedQuote,
+    /// `name:` is not a filter; the clause is searched for as text.
+    UnknownField,
+    /// A filter such as `tag:` with nothing after it.
+    MissingValue,
+    /// An `is:` or `has:` value that is not supported.
+    InvalidValue,
+    /// A `modified:` or `created:` value that is not a date.
+    InvalidDate,
+    /// An `in:` folder that does not exist; nothing is in it.
+    UnknownFolder,
+}
+
+/// Notes matching a query, best match first.
+///
+/// Plain words match the title or content exactly, as the start of a
+/// longer word, or with a typo or two. Quoted phrases must appear as
+/// written. `tag:`, `in:` (a folder name or path, with its subfolders),
+/// `modified:` and `created:` (`2026-01-31`, optionally after `>`, `>=`,
+/// `<` or `<=`, in UTC), `is:pinned`, `is:locked` and `has:attachment`
+/// filter on metadata, and a leading `-` excludes what a clause matches.
+/// Content hidden with `encrypt_text` is not searched.
+#[frb]
+pub fn search_notes(query: String, limit: u32) -> Result<SearchResults, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.search(&query, limit as usize)?)
+}
+
+/// A regex find-and-replace over every note.
+#[derive(Clone, Debug, Default)]
+pub struct ReplaceQuery {
+    /// A regular expression in Rust's `regex` syntax.
+    pub pattern: String,
+    /// What each match becomes; `$1` or `${name}` insert a capture group.
+    pub replacement: String,
+    pub case_insensitive: bool,
+    /// Also look at titles, not only content.
+    pub include_titles: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplaceField {
+    Title,
+    Content,
+}
+
+/// One match of a `ReplaceQuery`, as shown for review and handed back to
+/// `apply_replace` if it should be replaced.
+#[derive(Clone, Debug)]
+pub struct ReplaceMatch {
+    pub note_id: String,
+    pub note_title: String,
+    /// The revision the match was found in.
+    pub revision: u32,
+    pub field: ReplaceField,
+    /// Where the match is in the title or content, in UTF-16 code units
+    /// like `TextMatch`.
+    pub start: u64,
+    pub end: u64,
+    /// Counted from 0.
+    pub line: u32,
+    /// Up to 40 characters of the line before and after the match.
+    pub before: String,
+    pub matched: String,
+    pub after: String,
+    /// `matched` with the replacement applied.
+    pub replacement: String,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ReplacePreview {
+    pub matches: Vec<ReplaceMatch>,
+    pub notes_searched: u32,
+    /// Notes whose content is encrypted with `encrypt_text`, so only their
+    /// titles could be searched.
+    pub locked_notes: Vec<String>,
+}
+
+/// The outcome of `apply_replace`: either `changed_notes` is filled, or
+/// `stale_notes` and `rejected_notes` say why nothing was.
+#[derive(Clone, Debug, Default)]
+pub struct ReplaceReport {
+    pub changed_notes: Vec<NoteSummary>,
+    pub replacements: u32,
+    /// Notes changed or deleted since the preview. Nothing was written;
+    /// preview again and choose afresh.
+    pub stale_notes: Vec<String>,
+    /// Notes the replacement would leave with a title `save_note` refuses,
+    /// such as an empty one. Nothing was written.
+    pub rejected_notes: Vec<String>,
+}
+
+/// Lists every match of `query` across the vault without changing anything.
+#[frb]
+pub fn preview_replace(query: ReplaceQuery) -> Result<ReplacePreview, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.preview_replace(&query)?)
+}
+
+/// Replaces the `chosen` matches from `preview_replace`, saving each changed
+/// note as a new revision. All notes are written or none are.
+#[frb]
+pub fn apply_replace(query: ReplaceQuery, chosen: Vec<ReplaceMatch>) -> Result<ReplaceReport, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.apply_replace(&query, &chosen)?)
+}
+
+/// Part of the results of `search_stream`.
+#[derive(Clone, Debug, Default)]
+pub struct SearchBatch {
+    /// Best first. Batches are scored separately, so a later batch may hold
+    /// better hits; merge them by `score`.
+    pub hits: Vec<SearchHit>,
+    /// Only in the first batch.
+    pub diagnostics: Vec<QueryDiagnostic>,
+    /// Set on the last batch, after which the stream closes.
+    pub done: bool,
+}
+
+/// Runs `search_notes`, streaming hits as each batch of candidates is
+/// scored, so the first ones show before the whole vault is searched. With
+/// a `limit`, the search stops at the first `limit` hits found, which are
+/// not necessarily the best. A new call, or `cancel_search`, stops the
+/// previous search; its stream then closes without a `done` batch.
+#[frb]
+pub fn search_stream(query: String, limit: u32, sink: StreamSink<SearchBatch>) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    vault.search_stream(&query, limit as usize, |batch| match batch {
+        Ok(batch) => sink.add(batch).is_ok(),
+        Err(e) => sink.add_error(VaultError::from(e)).is_ok(),
+    });
+    Ok(())
+}
+
+#[frb]
+pub fn cancel_search() -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    vault.cancel_search();
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuggestionKind {
+    /// A note, for example as the target of a link.
+    Title,
+    Tag,
+    Folder,
+}
+
+/// Something to complete what is being typed with.
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    pub kind: SuggestionKind,
+    /// The note title, the tag, or the folder's path from the top.
+    pub text: String,
+    /// The note or folder id, or the tag itself. Passed back to
+    /// `record_suggestion_use` when the suggestion is picked.
+    pub id: String,
+}
+
+/// Up to `limit` titles, tags or folder names with a word starting with
+/// `prefix`, those used most and most recently first. Without a `kind`, all
+/// three are suggested.
+#[frb]
+pub fn suggest(prefix: String, kind: Option<SuggestionKind>, limit: u32) -> Result<Vec<Suggestion>, VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.suggest(&prefix, kind, limit as usize)?)
+}
+
+/// Notes that a suggestion, or the note, tag or folder it stands for, was
+/// used, so it ranks higher next time.
+#[frb]
+pub fn record_suggestion_use(kind: SuggestionKind, id: String) -> Result<(), VaultError> {
+    let vault = vault::current()?;
+    Ok(vault.record_suggestion_use(kind, &id)?)
+}
@@ -0,0 +1,175 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMP_SUFFIX: &str = ".tmp";
+
+/// Replaces `path` with `data` so that a crash leaves either the old file or
+/// the new one, never a truncated mix of both.
+///
+/// The bytes go to a temp file next to `path`, which is fsynced and renamed
+/// over the target; the directory is fsynced afterwards so the rename itself
+/// survives a power loss.
+pub fn write_atomic(path: &Path, data: &[u8]) -> io::Result<()> {
+    let mut file = AtomicFile::create(path)?;
+    file.write_all(data)?;
+    file.commit()
+}
+
+/// The streaming form of `write_atomic`, for content that should not be held
+/// in memory at once. Nothing replaces the target until `commit`; dropping
+/// the file without committing deletes the temp file.
+pub struct AtomicFile {
+    path: PathBuf,
+    temp: PathBuf,
+    file: Option<fs::File>,
+    committed: bool,
+}
+
+impl AtomicFile {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let temp = temp_path(path)?;
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp)?;
+        Ok(AtomicFile {
+            path: path.to_path_buf(),
+            temp,
+            file: Some(file),
+            committed: false,
+        })
+    }
+
+    pub fn commit(mut self) -> io::Result<()> {
+        if let Some(file) = self.file.take() {
+            file.sync_all()?;
+        }
+        fs::rename(&self.temp, &self.path)?;
+        self.committed = true;
+        sync_dir(parent_dir(&self.path))
+    }
+
+    fn file(&mut self) -> io::Result<&mut fs::File> {
+        self.file
+            .as_mut()
+            .ok_or_else(|| io::Error::other("atomic file already committed"))
+    }
+}
+
+impl Write for AtomicFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file()?.flush()
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.file.take();
+            let _ = fs::remove_file(&self.temp);
+        }
+    }
+}
+
+/// A scratch file in `dir` for data that is not kept, deleted on drop.
+pub struct TempFile {
+    path: PathBuf,
+    file: Option<fs::File>,
+}
+
+impl TempFile {
+    pub fn create_in(dir: &Path) -> io::Result<Self> {
+        let path = temp_path(&dir.join("scratch"))?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(TempFile {
+            path,
+            file: Some(file),
+        })
+    }
+
+    pub fn file(&mut self) -> &mut fs::File {
+        self.file.as_mut().expect("only taken on drop")
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // Closed first, since Windows cannot delete an open file.
+        self.file.take();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Deletes temp files left behind by writes that never reached their rename,
+/// in `dir` and everything below it.
+pub fn remove_temp_files(dir: &Path) -> io::Result<usize> {
+    let mut removed = 0;
+    for path in temp_files(dir)? {
+        match fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(e) => eprintln!("[Rust] Failed to remove temp file {:?}: {}", path, e),
+        }
+    }
+    Ok(removed)
+}
+
+/// The temp files in `dir` and everything below it.
+pub fn temp_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+        if file_type.is_dir() {
+            found.extend(temp_files(&path)?);
+        } else if file_type.is_file() && is_temp_file(&path) {
+            found.push(path);
+        }
+    }
+    Ok(found)
+}
+
+pub fn is_temp_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.starts_with('.') && name.ends_with(TEMP_SUFFIX))
+        .unwrap_or(false)
+}
+
+fn temp_path(path: &Path) -> io::Result<PathBuf> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+    let temp_name = format!(".{}.{:016x}{}", name, rand::random::<u64>(), TEMP_SUFFIX);
+    Ok(path.with_file_name(temp_name))
+}
+
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    fs::File::open(dir)?.sync_all()
+}
+
+// Directories cannot be opened as files on Windows; NTFS journals the rename.
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
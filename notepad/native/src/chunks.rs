@@ -0,0 +1,147 @@
+//! Content-defined chunking for the vault's chunk store.
+//!
+//! Data is cut where a rolling gear hash hits a boundary pattern, so an edit
+//! only changes the chunks around it and the rest dedup against earlier
+//! copies. Both the chunk ids and the gear table are keyed with the vault
+//! key: ids reveal nothing about content to someone without it, and neither
+//! do the chunk boundaries.
+
+use crate::crypto::{self, Key};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read};
+
+pub const MIN_CHUNK_LEN: usize = 4 * 1024;
+pub const MAX_CHUNK_LEN: usize = 64 * 1024;
+// 14 bits puts a boundary every 16 KiB on average past the minimum. They are
+// the top bits because those depend on the last 64 bytes rather than the
+// last 14, which matters for repetitive text.
+const BOUNDARY_MASK: u64 = ((1 << 14) - 1) << 50;
+
+/// A chunk of a blob, named by the keyed hash of its plaintext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkRef {
+    pub id: String,
+    pub len: u32,
+    /// Newlines in the chunk, which lets a line be found without decrypting
+    /// the chunks before it.
+    pub lines: u32,
+}
+
+/// The ordered list of chunks that make up one piece of content. It is
+/// stored inside its owner's sealed record, which is what authenticates the
+/// order and number of chunks.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Blob {
+    pub size: u64,
+    pub chunks: Vec<ChunkRef>,
+}
+
+impl Blob {
+    /// The chunks overlapping `offset..offset + len`, each with its starting
+    /// offset in the blob.
+    pub fn chunks_in_range(&self, offset: u64, len: u64) -> Vec<(u64, &ChunkRef)> {
+        let end = offset.saturating_add(len).min(self.size);
+        let mut start = 0;
+        let mut chunks = Vec::new();
+        for chunk in &self.chunks {
+            let chunk_end = start + chunk.len as u64;
+            if chunk_end > offset && start < end {
+                chunks.push((start, chunk));
+            }
+            start = chunk_end;
+        }
+        chunks
+    }
+}
+
+pub fn count_lines(data: &[u8]) -> u32 {
+    data.iter().filter(|&&byte| byte == b'\n').count() as u32
+}
+
+#[derive(Clone)]
+pub struct ChunkKeys {
+    id_key: Key,
+    gear: Box<[u64; 256]>,
+}
+
+impl ChunkKeys {
+    pub fn new(key: &Key) -> Self {
+        let id_key = crypto::subkey(key, "chunk-id");
+        let gear_key = crypto::subkey(key, "chunk-gear");
+        let mut gear = Box::new([0u64; 256]);
+        for (byte, value) in gear.iter_mut().enumerate() {
+            let mac = crypto::mac(&gear_key, &[byte as u8]);
+            *value = u64::from_le_bytes(mac[..8].try_into().expect("8 bytes"));
+        }
+        ChunkKeys { id_key, gear }
+    }
+
+    pub fn chunk_id(&self, data: &[u8]) -> String {
+        crypto::mac(&self.id_key, data)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Length of the chunk at the start of `data`.
+    fn boundary(&self, data: &[u8]) -> usize {
+        if data.len() <= MIN_CHUNK_LEN {
+            return data.len();
+        }
+        let limit = data.len().min(MAX_CHUNK_LEN);
+        let mut hash = 0u64;
+        for (i, byte) in data.iter().enumerate().take(limit).skip(MIN_CHUNK_LEN) {
+            hash = (hash << 1).wrapping_add(self.gear[*byte as usize]);
+            if hash & BOUNDARY_MASK == 0 {
+                return i + 1;
+            }
+        }
+        limit
+    }
+
+    /// Splits everything `reader` yields into chunks, reading at most two
+    /// chunks' worth ahead.
+    pub fn chunker<R: Read>(&self, reader: R) -> Chunker<'_, R> {
+        Chunker {
+            keys: self,
+            reader,
+            buffer: Vec::with_capacity(2 * MAX_CHUNK_LEN),
+            eof: false,
+        }
+    }
+}
+
+pub struct Chunker<'a, R> {
+    keys: &'a ChunkKeys,
+    reader: R,
+    buffer: Vec<u8>,
+    eof: bool,
+}
+
+impl<R: Read> Iterator for Chunker<'_, R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.eof && self.buffer.len() < MAX_CHUNK_LEN {
+            let start = self.buffer.len();
+            self.buffer.resize(2 * MAX_CHUNK_LEN, 0);
+            match self.reader.read(&mut self.buffer[start..]) {
+                Ok(read) => {
+                    self.buffer.truncate(start + read);
+                    self.eof = read == 0;
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => self.buffer.truncate(start),
+                Err(e) => {
+                    self.buffer.truncate(start);
+                    return Some(Err(e));
+                }
+            }
+        }
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let len = self.keys.boundary(&self.buffer);
+        let rest = self.buffer.split_off(len);
+        Some(Ok(std::mem::replace(&mut self.buffer, rest)))
+    }
+}
@@ -0,0 +1,143 @@
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+const MAGIC: &[u8; 4] = b"ENV1";
+const VERSION: u8 = 1;
+const NONCE_LEN: usize = 12;
+const HEADER_LEN: usize = MAGIC.len() + 2 + NONCE_LEN;
+
+/// Set in the flags when the plaintext was zstd-compressed before sealing.
+const FLAG_COMPRESSED: u8 = 1;
+const COMPRESSION_LEVEL: i32 = 3;
+
+pub type Key = [u8; 32];
+
+static ENCRYPTION_KEY: &[u8] = b"NOTEPAD_SECRET_KEY_2025";
+
+/// Derives the key every envelope in a vault is sealed with.
+pub fn derive_key() -> Key {
+    let mut hasher = Sha256::new();
+    hasher.update(ENCRYPTION_KEY);
+    hasher.update(std::env::var("USER").unwrap_or_default().as_bytes());
+    hasher.finalize().into()
+}
+
+/// Derives an independent key for one purpose from the vault key, so that
+/// e.g. a MAC never shares a key with the cipher.
+pub fn subkey(key: &Key, label: &str) -> Key {
+    mac(key, label.as_bytes())
+}
+
+/// HMAC-SHA256 of `data`.
+pub fn mac(key: &Key, data: &[u8]) -> [u8; 32] {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Encrypts `plaintext` into a self-describing envelope:
+/// `magic | version | flags | nonce | ciphertext+tag`.
+///
+/// The header is passed as associated data, so tampering with it fails
+/// authentication just like tampering with the ciphertext.
+pub fn seal(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    seal_with_flags(key, plaintext, 0)
+}
+
+/// Like `seal`, but compresses `plaintext` first when that makes it smaller.
+/// `open` decompresses it again on its own.
+pub fn seal_compressed(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    match zstd::bulk::compress(plaintext, COMPRESSION_LEVEL) {
+        Ok(compressed) if compressed.len() < plaintext.len() => {
+            seal_with_flags(key, &compressed, FLAG_COMPRESSED)
+        }
+        _ => seal(key, plaintext),
+    }
+}
+
+fn seal_with_flags(key: &Key, plaintext: &[u8], flags: u8) -> Vec<u8> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let mut envelope = Vec::with_capacity(HEADER_LEN + plaintext.len() + 16);
+    envelope.extend_from_slice(MAGIC);
+    envelope.push(VERSION);
+    envelope.push(flags);
+    envelope.extend_from_slice(&nonce);
+
+    let cipher = Aes256Gcm::new(key.into());
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: plaintext,
+                aad: &envelope,
+            },
+        )
+        .expect("AES-GCM encryption cannot fail for in-memory buffers");
+    envelope.extend_from_slice(&ciphertext);
+    envelope
+}
+
+pub fn open(key: &Key, envelope: &[u8]) -> anyhow::Result<Vec<u8>> {
+    if !is_envelope(envelope) {
+        bail!("not an encrypted envelope");
+    }
+    if envelope.len() < HEADER_LEN {
+        bail!("envelope is truncated");
+    }
+    if envelope[MAGIC.len()] != VERSION {
+        bail!("unsupported envelope version {}", envelope[MAGIC.len()]);
+    }
+    let flags = envelope[MAGIC.len() + 1];
+    if flags & !FLAG_COMPRESSED != 0 {
+        bail!("unsupported envelope flags {:#04x}", flags);
+    }
+
+    let (header, ciphertext) = envelope.split_at(HEADER_LEN);
+    let nonce = &header[HEADER_LEN - NONCE_LEN..];
+    let cipher = Aes256Gcm::new(key.into());
+    let plaintext = cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| anyhow!("authentication failed, wrong key or corrupted data"))?;
+    if flags & FLAG_COMPRESSED != 0 {
+        // Decompressed only after authentication, so only our own output is
+        // ever fed to the decoder.
+        return zstd::stream::decode_all(plaintext.as_slice())
+            .map_err(|e| anyhow!("failed to decompress envelope: {}", e));
+    }
+    Ok(plaintext)
+}
+
+pub fn seal_json<T: Serialize>(key: &Key, value: &T) -> anyhow::Result<Vec<u8>> {
+    Ok(seal(key, &serde_json::to_vec(value)?))
+}
+
+pub fn open_json<T: DeserializeOwned>(key: &Key, envelope: &[u8]) -> anyhow::Result<T> {
+    Ok(serde_json::from_slice(&open(key, envelope)?)?)
+}
+
+pub fn is_envelope(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+/// The obfuscation notes were stored with before envelopes; still used by
+/// `encrypt_text` and to read notes written by older builds.
+pub fn xor_encrypt_decrypt(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .zip(ENCRYPTION_KEY.iter().cycle())
+        .map(|(&b, &k)| b ^ k)
+        .collect()
+}
@@ -0,0 +1,176 @@
+use crate::api::{DiffChange, DiffGranularity, DiffHunk, DiffOp, RetentionPolicy};
+use similar::{ChangeTag, TextDiff};
+use std::collections::HashSet;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+const WEEK_MS: i64 = 7 * DAY_MS;
+const DIFF_CONTEXT: usize = 3;
+
+pub const REVISION_EXTENSION: &str = "rev";
+
+pub fn revision_file_name(revision: u32) -> String {
+    format!("{:010}.{}", revision, REVISION_EXTENSION)
+}
+
+pub fn parse_revision_file_name(name: &str) -> Option<u32> {
+    name.strip_suffix(REVISION_EXTENSION)?
+        .strip_suffix('.')?
+        .parse()
+        .ok()
+}
+
+/// Picks the revisions `policy` no longer wants, given `(revision, modified_at)`
+/// pairs sorted newest first.
+///
+/// The newest `keep_last` are always kept. Past those, the newest revision of
+/// each day is kept for `keep_daily_days` days and the newest of each week for
+/// `keep_weekly_weeks` weeks after that.
+pub fn revisions_to_prune(
+    revisions: &[(u32, i64)],
+    policy: &RetentionPolicy,
+    now: i64,
+) -> Vec<u32> {
+    let keep_last = policy.keep_last.max(1) as usize;
+    let daily_until = policy.keep_daily_days as i64 * DAY_MS;
+    let weekly_until = daily_until + policy.keep_weekly_weeks as i64 * WEEK_MS;
+
+    let mut buckets = HashSet::new();
+    let mut prune = Vec::new();
+    for (position, &(revision, modified_at)) in revisions.iter().enumerate() {
+        let age = now - modified_at;
+        let bucket = if age < daily_until {
+            Some(('d', modified_at.div_euclid(DAY_MS)))
+        } else if age < weekly_until {
+            Some(('w', modified_at.div_euclid(WEEK_MS)))
+        } else {
+            None
+        };
+
+        let keep = if position < keep_last {
+            true
+        } else {
+            match bucket {
+                Some(bucket) => !buckets.contains(&bucket),
+                None => false,
+            }
+        };
+        if keep {
+            if let Some(bucket) = bucket {
+                buckets.insert(bucket);
+            }
+        } else {
+            prune.push(revision);
+        }
+    }
+    prune
+}
+
+/// Diffs two texts into hunks with a few units of context around each change.
+/// Positions count lines or words, following `granularity`.
+pub fn diff(old: &str, new: &str, granularity: DiffGranularity) -> Vec<DiffHunk> {
+    let diff = match granularity {
+        DiffGranularity::Line => TextDiff::from_lines(old, new),
+        DiffGranularity::Word => TextDiff::configure().diff_words(old, new),
+    };
+
+    let mut hunks = Vec::new();
+    for group in diff.grouped_ops(DIFF_CONTEXT) {
+        let (first, last) = match (group.first(), group.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => continue,
+        };
+        let old_range = first.old_range().start..last.old_range().end;
+        let new_range = first.new_range().start..last.new_range().end;
+
+        let mut changes: Vec<DiffChange> = Vec::new();
+        for op in &group {
+            for change in diff.iter_changes(op) {
+                let op = match change.tag() {
+                    ChangeTag::Equal => DiffOp::Equal,
+                    ChangeTag::Insert => DiffOp::Insert,
+                    ChangeTag::Delete => DiffOp::Delete,
+                };
+                match changes.last_mut() {
+                    Some(previous) if previous.op == op => previous.text.push_str(change.value()),
+                    _ => changes.push(DiffChange {
+                        op,
+                        text: change.value().to_string(),
+                    }),
+                }
+            }
+        }
+
+        hunks.push(DiffHunk {
+            old_start: old_range.start as u32,
+            old_len: old_range.len() as u32,
+            new_start: new_range.start as u32,
+            new_len: new_range.len() as u32,
+            changes,
+        });
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR_MS: i64 = 60 * 60 * 1000;
+    /// Midday, so that a few hours either way stay on the same day.
+    const NOW: i64 = 1000 * DAY_MS + 12 * HOUR_MS;
+
+    fn policy(keep_last: u32, keep_daily_days: u32, keep_weekly_weeks: u32) -> RetentionPolicy {
+        RetentionPolicy {
+            keep_last,
+            keep_daily_days,
+            keep_weekly_weeks,
+        }
+    }
+
+    #[test]
+    fn the_newest_revisions_are_kept_however_old() {
+        let revisions = [(3, NOW - 100 * DAY_MS), (2, NOW - 200 * DAY_MS), (1, 0)];
+        assert_eq!(revisions_to_prune(&revisions, &policy(2, 0, 0), NOW), [1]);
+        // At least one is kept even when the policy asks for none.
+        assert_eq!(
+            revisions_to_prune(&revisions, &policy(0, 0, 0), NOW),
+            [2, 1]
+        );
+    }
+
+    #[test]
+    fn the_newest_revision_of_each_day_is_kept_for_the_daily_window() {
+        let revisions = [
+            (6, NOW - HOUR_MS),
+            (5, NOW - 2 * HOUR_MS),
+            (4, NOW - DAY_MS),
+            (3, NOW - DAY_MS - HOUR_MS),
+            (2, NOW - 2 * DAY_MS),
+            (1, NOW - 5 * DAY_MS),
+        ];
+        assert_eq!(
+            revisions_to_prune(&revisions, &policy(1, 3, 0), NOW),
+            [5, 3, 1]
+        );
+    }
+
+    #[test]
+    fn the_newest_revision_of_each_week_is_kept_after_the_daily_window() {
+        let revisions = [
+            (5, NOW),
+            (4, NOW - 3 * DAY_MS),
+            (3, NOW - 3 * DAY_MS - HOUR_MS),
+            (2, NOW - 10 * DAY_MS),
+            (1, NOW - 30 * DAY_MS),
+        ];
+        assert_eq!(
+            revisions_to_prune(&revisions, &policy(1, 1, 2), NOW),
+            [3, 1]
+        );
+    }
+
+    #[test]
+    fn nothing_is_pruned_from_an_empty_history() {
+        assert!(revisions_to_prune(&[], &RetentionPolicy::default(), NOW).is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use crate::api::{Note, NoteSummary};
+use crate::crypto::{self, Key};
+use crate::note;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Summaries of every note in the vault, kept in one encrypted file so that
+/// listing notes does not have to decrypt each note.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Index {
+    pub notes: BTreeMap<String, NoteSummary>,
+    /// Tag to the ids of the notes carrying it.
+    pub tags: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Index {
+    pub fn decode(key: &Key, data: &[u8]) -> anyhow::Result<Self> {
+        crypto::open_json(key, data)
+    }
+
+    pub fn encode(&self, key: &Key) -> anyhow::Result<Vec<u8>> {
+        crypto::seal_json(key, self)
+    }
+
+    pub fn upsert(&mut self, note: &Note) {
+        self.remove(&note.id);
+        for tag in &note.tags {
+            self.tags.entry(tag.clone()).or_default().insert(note.id.clone());
+        }
+        self.notes.insert(note.id.clone(), NoteSummary::from(note));
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        if let Some(old) = self.notes.remove(id) {
+            for tag in &old.tags {
+                if let Some(ids) = self.tags.get_mut(tag) {
+                    ids.remove(id);
+                    if ids.is_empty() {
+                        self.tags.remove(tag);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Ids of the notes carrying `tag`, in any spelling `tag_matches`
+    /// accepts.
+    pub fn tagged(&self, tag: &str) -> BTreeSet<String> {
+        self.tags
+            .iter()
+            .filter(|(spelling, _)| note::tag_matches(spelling, tag))
+            .flat_map(|(_, ids)| ids.iter().cloned())
+            .collect()
+    }
+}
+
+impl From<&Note> for NoteSummary {
+    fn from(note: &Note) -> Self {
+        NoteSummary {
+            id: note.id.clone(),
+            title: note.title.clone(),
+            created_at: note.created_at,
+            modified_at: note.modified_at,
+            tags: note.tags.clone(),
+            pinned: note.pinned,
+            color: note.color,
+            folder_id: note.folder_id.clone(),
+        }
+    }
+}
@@ -1,4 +1,17 @@
 mod frb_generated;
 pub mod api;
+mod atomic;
+mod chunks;
+mod crypto;
+mod history;
+mod index;
+mod note;
+mod query;
+mod search;
+mod stem;
+mod storage;
+mod suggest;
+mod text;
+mod vault;
 
 pub use api::*;
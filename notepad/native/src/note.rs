@@ -0,0 +1,105 @@
+use crate::api::Note;
+use crate::chunks::Blob;
+use crate::crypto::{self, Key};
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// Milliseconds since the Unix epoch, the unit every note timestamp uses.
+pub fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+pub fn new_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Checks that `id` is a note id before it is used to build a path, and
+/// returns it in canonical form.
+pub fn parse_id(id: &str) -> anyhow::Result<String> {
+    Uuid::parse_str(id)
+        .map(|uuid| uuid.to_string())
+        .map_err(|_| anyhow!("invalid note id {:?}", id))
+}
+
+pub fn validate_title(title: &str) -> anyhow::Result<()> {
+    if title.trim().is_empty() {
+        return Err(anyhow!("note title cannot be empty"));
+    }
+    Ok(())
+}
+
+/// Whether `a` and `b` name the same tag. Tags are matched ignoring case
+/// and surrounding whitespace wherever they are compared.
+pub fn tag_matches(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+/// Trims tags and drops empty ones and ones that match an earlier tag,
+/// keeping the first spelling.
+pub fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !normalized.iter().any(|existing| tag_matches(existing, tag)) {
+            normalized.push(tag.to_string());
+        }
+    }
+    normalized
+}
+
+/// What a note or revision file holds. With a `body` the content lives in
+/// the chunk store and `note.content` is empty; without one, as in files
+/// from before the chunk store, the content is inline.
+#[derive(Serialize, Deserialize)]
+pub struct NoteRecord {
+    #[serde(flatten)]
+    pub note: Note,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Blob>,
+}
+
+pub fn encode(key: &Key, note: &Note, body: Option<&Blob>) -> anyhow::Result<Vec<u8>> {
+    let note = match body {
+        Some(_) => Note {
+            content: String::new(),
+            ..note.clone()
+        },
+        None => note.clone(),
+    };
+    crypto::seal_json(
+        key,
+        &NoteRecord {
+            note,
+            body: body.cloned(),
+        },
+    )
+}
+
+pub fn decode(key: &Key, data: &[u8]) -> anyhow::Result<NoteRecord> {
+    crypto::open_json(key, data).context("note metadata is malformed")
+}
+
+/// Decodes a `<title>.txt` file written by older builds, which is either an
+/// envelope without an id or bare XOR-obfuscated content.
+pub fn decode_legacy(key: &Key, title: &str, data: &[u8], modified_at: i64) -> anyhow::Result<Note> {
+    let mut note = if crypto::is_envelope(data) {
+        decode(key, data)?.note
+    } else {
+        let content = String::from_utf8(crypto::xor_encrypt_decrypt(data))
+            .context("legacy note is not valid UTF-8")?;
+        Note {
+            title: title.to_string(),
+            content,
+            created_at: modified_at,
+            modified_at,
+            ..Note::default()
+        }
+    };
+    note.id = new_id();
+    Ok(note)
+}
@@ -0,0 +1,473 @@
+//! The search box's query language.
+//!
+//! A query is a list of clauses that must all hold. A clause is a word, a
+//! quoted phrase or a `field:value` filter, and a leading `-` negates it:
+//!
+//! ```text
+//! tag:ops modified:>2026-01-01 "deploy key" -draft is:locked in:Work
+//! ```
+//!
+//! Parsing never fails. A clause that cannot be understood is reported as a
+//! diagnostic and left out, so results keep following what is typed.
+
+use crate::api::QueryDiagnosticKind;
+use crate::text::words;
+use std::ops::Range;
+
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Default)]
+pub struct Query {
+    pub clauses: Vec<Clause>,
+}
+
+#[derive(Debug)]
+pub struct Clause {
+    pub negated: bool,
+    pub filter: Filter,
+    /// Byte range in the query text.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Filter {
+    /// A word of the title or content, matched loosely.
+    Word(String),
+    /// Words that must appear in this order, matched exactly.
+    Phrase(Vec<String>),
+    Tag(String),
+    /// A folder name, or a path of names separated by `/`. Notes in folders
+    /// below it match too.
+    Folder(String),
+    Modified(DateRange),
+    Created(DateRange),
+    Pinned,
+    /// The content is encrypted with its own password.
+    Locked,
+    HasAttachment,
+}
+
+/// Milliseconds since the Unix epoch, from inclusive to exclusive.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DateRange {
+    pub from: Option<i64>,
+    pub until: Option<i64>,
+}
+
+impl DateRange {
+    pub fn contains(&self, millis: i64) -> bool {
+        self.from.is_none_or(|from| millis >= from) && self.until.is_none_or(|until| millis < until)
+    }
+}
+
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub kind: QueryDiagnosticKind,
+    pub message: String,
+    /// Byte range in the query text.
+    pub span: Range<usize>,
+}
+
+impl Diagnostic {
+    pub fn new(kind: QueryDiagnosticKind, span: Range<usize>, message: String) -> Self {
+        Diagnostic {
+            kind,
+            message,
+            span,
+        }
+    }
+}
+
+pub fn parse(text: &str) -> (Query, Vec<Diagnostic>) {
+    let mut parser = Parser {
+        text,
+        position: 0,
+        query: Query::default(),
+        diagnostics: Vec::new(),
+    };
+    while parser.skip_whitespace() {
+        parser.clause();
+    }
+    (parser.query, parser.diagnostics)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    /// Byte offset of the next character.
+    position: usize,
+    query: Query,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Parser<'a> {
+    /// Moves to the next clause, returning false at the end of the text.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+        self.position < self.text.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.position..].chars().next()
+    }
+
+    fn clause(&mut self) {
+        let start = self.position;
+        let negated = self.peek() == Some('-');
+        if negated {
+            self.position += 1;
+        }
+
+        let filter = if self.peek() == Some('"') {
+            let phrase = self.quoted();
+            words_filter(words(&phrase), true)
+        } else {
+            let name_start = self.position;
+            let word = self.until(|c| c.is_whitespace() || c == ':' || c == '"');
+            let is_field = !word.is_empty()
+                && word.chars().all(char::is_alphabetic)
+                && self.text[self.position..].starts_with(':')
+                && !self.text[self.position..].starts_with("://");
+            if is_field {
+                self.position += 1;
+                let value_start = self.position;
+                let value = if self.peek() == Some('"') {
+                    self.quoted()
+                } else {
+                    self.until(char::is_whitespace).to_string()
+                };
+                self.field(&word.to_lowercase(), name_start, value.trim(), value_start)
+            } else {
+                // Anything else is text, including a URL or a word with a
+                // quote in it.
+                let rest = self.until(char::is_whitespace);
+                let text = format!("{}{}", word, rest);
+                words_filter(words(&text), false)
+            }
+        };
+
+        if let Some(filter) = filter {
+            self.query.clauses.push(Clause {
+                negated,
+                filter,
+                span: start..self.position,
+            });
+        }
+    }
+
+    /// The text up to the closing quote, or to the end if there is none.
+    fn quoted(&mut self) -> String {
+        let start = self.position;
+        self.position += 1;
+        match self.text[self.position..].find('"') {
+            Some(end) => {
+                let quoted = self.text[self.position..self.position + end].to_string();
+                self.position += end + 1;
+                quoted
+            }
+            None => {
+                self.diagnostics.push(Diagnostic::new(
+                    QueryDiagnosticKind::UnterminatedQuote,
+                    start..self.text.len(),
+                    "missing closing quote".to_string(),
+                ));
+                let quoted = self.text[self.position..].to_string();
+                self.position = self.text.len();
+                quoted
+            }
+        }
+    }
+
+    fn until(&mut self, end: impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        let rest = &self.text[start..];
+        self.position += rest.find(end).unwrap_or(rest.len());
+        &self.text[start..self.position]
+    }
+
+    fn field(
+        &mut self,
+        name: &str,
+        name_start: usize,
+        value: &str,
+        value_start: usize,
+    ) -> Option<Filter> {
+        const FIELDS: [&str; 6] = ["tag", "in", "modified", "created", "is", "has"];
+        let span = name_start..self.position;
+        let value_span = value_start..self.position;
+        if value.is_empty() && FIELDS.contains(&name) {
+            self.diagnostics.push(Diagnostic::new(
+                QueryDiagnosticKind::MissingValue,
+                span,
+                format!("{}: needs a value", name),
+            ));
+            return None;
+        }
+
+        let filter = match name {
+            "tag" => Filter::Tag(value.to_string()),
+            "in" => Filter::Folder(value.to_string()),
+            "modified" | "created" => {
+                let range = match parse_date_range(value) {
+                    Ok(range) => range,
+                    Err(message) => {
+                        self.diagnostics.push(Diagnostic::new(
+                            QueryDiagnosticKind::InvalidDate,
+                            value_span,
+                            message,
+                        ));
+                        return None;
+                    }
+                };
+                if name == "modified" {
+                    Filter::Modified(range)
+                } else {
+                    Filter::Created(range)
+                }
+            }
+            "is" | "has" => {
+                let filter = match (name, value.to_lowercase().as_str()) {
+                    ("is", "pinned") => Filter::Pinned,
+                    ("is", "locked") => Filter::Locked,
+                    ("has", "attachment" | "attachments") => Filter::HasAttachment,
+                    _ => {
+                        let expected = if name == "is" {
+                            "pinned or locked"
+                        } else {
+                            "attachment"
+                        };
+                        self.diagnostics.push(Diagnostic::new(
+                            QueryDiagnosticKind::InvalidValue,
+                            value_span,
+                            format!("{}:{} is not supported; use {}", name, value, expected),
+                        ));
+                        return None;
+                    }
+                };
+                filter
+            }
+            _ => return self.unknown_field(name, span),
+        };
+        Some(filter)
+    }
+
+    /// Reports an unknown field and searches for the clause as text instead.
+    fn unknown_field(&mut self, name: &str, span: Range<usize>) -> Option<Filter> {
+        self.diagnostics.push(Diagnostic::new(
+            QueryDiagnosticKind::UnknownField,
+            span.clone(),
+            format!(
+                "unknown filter {:?}; use tag:, in:, modified:, created:, is: or has:",
+                name
+            ),
+        ));
+        words_filter(words(&self.text[span]), false)
+    }
+}
+
+/// A single word, or the words of a phrase. Words joined by punctuation,
+/// like `e-mail`, are a phrase too.
+fn words_filter(mut words: Vec<String>, quoted: bool) -> Option<Filter> {
+    match words.len() {
+        0 => None,
+        1 if !quoted => words.pop().map(Filter::Word),
+        _ => Some(Filter::Phrase(words)),
+    }
+}
+
+/// `2026-01-01` for that day, or a day after `>`, `>=`, `<` or `<=`. Days
+/// are in UTC.
+fn parse_date_range(value: &str) -> Result<DateRange, String> {
+    let (operator, date) = match value.find(|c: char| c.is_ascii_digit()) {
+        Some(start) => value.split_at(start),
+        None => return Err(format!("{:?} is not a date; write it as 2026-01-31", value)),
+    };
+    let day = parse_date(date)?;
+    let start = day * MILLIS_PER_DAY;
+    let end = start + MILLIS_PER_DAY;
+    Ok(match operator {
+        "" | "=" => DateRange {
+            from: Some(start),
+            until: Some(end),
+        },
+        ">" => DateRange {
+            from: Some(end),
+            until: None,
+        },
+        ">=" => DateRange {
+            from: Some(start),
+            until: None,
+        },
+        "<" => DateRange {
+            from: None,
+            until: Some(start),
+        },
+        "<=" => DateRange {
+            from: None,
+            until: Some(end),
+        },
+        _ => {
+            return Err(format!(
+                "{:?} is not a comparison; use >, >=, < or <=",
+                operator
+            ))
+        }
+    })
+}
+
+/// Days since the Unix epoch of a `YYYY-MM-DD` date.
+fn parse_date(date: &str) -> Result<i64, String> {
+    let invalid = || format!("{:?} is not a date; write it as 2026-01-31", date);
+    let parts: Vec<&str> = date.split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 {
+        return Err(invalid());
+    }
+    let year: i64 = parts[0].parse().map_err(|_| invalid())?;
+    let month: u32 = parts[1].parse().map_err(|_| invalid())?;
+    let day: u32 = parts[2].parse().map_err(|_| invalid())?;
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(format!("{:?} has no month {}", date, month)),
+    };
+    if day == 0 || day > days_in_month {
+        return Err(format!("{:?} has no day {}", date, day));
+    }
+    Ok(days_from_civil(year, month, day))
+}
+
+/// Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(text: &str) -> Vec<(QueryDiagnosticKind, Range<usize>)> {
+        parse(text)
+            .1
+            .into_iter()
+            .map(|diagnostic| (diagnostic.kind, diagnostic.span))
+            .collect()
+    }
+
+    fn day(year: i64, month: u32, day: u32) -> Option<i64> {
+        Some(days_from_civil(year, month, day) * MILLIS_PER_DAY)
+    }
+
+    #[test]
+    fn days_from_civil_counts_from_the_epoch() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 2, 29), 11_016);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2026, 1, 1), 20_454);
+        assert_eq!(days_from_civil(1600, 1, 1), -135_140);
+    }
+
+    #[test]
+    fn parse_date_range_understands_each_comparison() {
+        let (start, end) = (day(2026, 1, 31), day(2026, 2, 1));
+        let range = |from, until| Ok(DateRange { from, until });
+        assert_eq!(parse_date_range("2026-01-31"), range(start, end));
+        assert_eq!(parse_date_range("=2026-01-31"), range(start, end));
+        assert_eq!(parse_date_range(">2026-01-31"), range(end, None));
+        assert_eq!(parse_date_range(">=2026-01-31"), range(start, None));
+        assert_eq!(parse_date_range("<2026-01-31"), range(None, start));
+        assert_eq!(parse_date_range("<=2026-01-31"), range(None, end));
+    }
+
+    #[test]
+    fn parse_date_range_rejects_what_is_not_a_date() {
+        for value in [
+            "yesterday",
+            "2026-1",
+            "26-01-31",
+            "2026-13-01",
+            "2026-02-29",
+            "2026-04-31",
+            "2026-01-00",
+            "~2026-01-31",
+        ] {
+            assert!(parse_date_range(value).is_err(), "{}", value);
+        }
+        assert!(parse_date_range("2024-02-29").is_ok());
+    }
+
+    #[test]
+    fn parse_reads_words_phrases_and_filters() {
+        let (query, diagnostics) =
+            parse(r#"tag:ops -draft "Deploy key" is:locked in:"Work/Old" modified:>2026-01-01"#);
+        assert!(diagnostics.is_empty());
+        let filters: Vec<(bool, &Filter)> = query
+            .clauses
+            .iter()
+            .map(|clause| (clause.negated, &clause.filter))
+            .collect();
+        assert_eq!(
+            filters,
+            [
+                (false, &Filter::Tag("ops".to_string())),
+                (true, &Filter::Word("draft".to_string())),
+                (
+                    false,
+                    &Filter::Phrase(vec!["deploy".to_string(), "key".to_string()])
+                ),
+                (false, &Filter::Locked),
+                (false, &Filter::Folder("Work/Old".to_string())),
+                (
+                    false,
+                    &Filter::Modified(DateRange {
+                        from: day(2026, 1, 2),
+                        until: None,
+                    })
+                ),
+            ]
+        );
+        assert_eq!(query.clauses[1].span, 8..14);
+    }
+
+    #[test]
+    fn parse_reports_what_it_leaves_out() {
+        use QueryDiagnosticKind::*;
+        assert_eq!(kinds(r#"a "open phrase"#), [(UnterminatedQuote, 2..14)]);
+        assert_eq!(kinds("tag: x"), [(MissingValue, 0..4)]);
+        assert_eq!(kinds("is:archived"), [(InvalidValue, 3..11)]);
+        assert_eq!(kinds("has:cats"), [(InvalidValue, 4..8)]);
+        assert_eq!(kinds("created:soon"), [(InvalidDate, 8..12)]);
+        assert_eq!(kinds("modified:2026-02-30"), [(InvalidDate, 9..19)]);
+        assert_eq!(kinds("author:me"), [(UnknownField, 0..9)]);
+    }
+
+    #[test]
+    fn parse_keeps_what_it_can() {
+        // An unknown field is searched for as text.
+        let (query, _) = parse("author:me");
+        assert_eq!(
+            query.clauses[0].filter,
+            Filter::Phrase(vec!["author".to_string(), "me".to_string()])
+        );
+        // An unterminated phrase runs to the end.
+        let (query, _) = parse(r#""open phrase"#);
+        assert_eq!(
+            query.clauses[0].filter,
+            Filter::Phrase(vec!["open".to_string(), "phrase".to_string()])
+        );
+        // A URL is not a field.
+        let (query, diagnostics) = parse("https://example.com");
+        assert!(diagnostics.is_empty());
+        assert_eq!(query.clauses.len(), 1);
+    }
+}
@@ -0,0 +1,514 @@
+//! Full-text search over note titles and contents.
+//!
+//! The inverted index is kept in one sealed file, like the note index, and
+//! is updated as notes are saved and deleted. Terms only ever exist in
+//! plaintext in memory.
+//!
+//! Each word is indexed as written, after `crate::text` normalizes it, and
+//! by its stem when the note is in English or German, so that "meetings"
+//! finds "meeting" while a typo can still be matched against the word.
+
+use crate::crypto::{self, Key};
+use crate::text;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Bound, Range};
+
+/// How much term frequency counts before it saturates.
+const BM25_K1: f64 = 1.2;
+/// How much longer notes are penalised.
+const BM25_B: f64 = 0.75;
+/// A word the query term is the start of counts this much of the word
+/// itself.
+const PREFIX_WEIGHT: f64 = 0.7;
+/// A word with the same stem as the query term counts this much of the
+/// word itself.
+const STEM_WEIGHT: f64 = 0.9;
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchIndex {
+    /// Term to the notes containing it, with how often it occurs in each.
+    postings: BTreeMap<String, BTreeMap<String, u32>>,
+    docs: BTreeMap<String, Doc>,
+}
+
+/// What the index knows about one note.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Doc {
+    /// Number of terms in the note.
+    len: u32,
+    /// Its distinct terms, so that it can be taken out of the postings.
+    terms: Vec<String>,
+}
+
+impl SearchIndex {
+    pub fn decode(key: &Key, data: &[u8]) -> anyhow::Result<Self> {
+        crypto::open_json(key, data)
+    }
+
+    pub fn encode(&self, key: &Key) -> anyhow::Result<Vec<u8>> {
+        crypto::seal_json(key, self)
+    }
+
+    /// Indexes `text` as the content of note `id`, replacing what was
+    /// indexed for it before.
+    pub fn insert(&mut self, id: &str, text: &str) {
+        self.remove(id);
+        let tokens = text::tokens(text, text::detect(text));
+        let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+        for token in &tokens {
+            *counts.entry(token.word.clone()).or_default() += 1;
+            if token.stem != token.word {
+                *counts.entry(token.stem.clone()).or_default() += 1;
+            }
+        }
+        for (term, count) in &counts {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(id.to_string(), *count);
+        }
+        self.docs.insert(
+            id.to_string(),
+            Doc {
+                len: tokens.len() as u32,
+                terms: counts.into_keys().collect(),
+            },
+        );
+    }
+
+    /// Whether note `id` contains the word `term`, or a word with the same
+    /// stem.
+    pub fn has(&self, id: &str, term: &str) -> bool {
+        text::variants(term).iter().any(|variant| {
+            self.postings
+                .get(variant)
+                .is_some_and(|notes| notes.contains_key(id))
+        })
+    }
+
+    /// Ids of the indexed notes.
+    pub fn note_ids(&self) -> impl Iterator<Item = &String> {
+        self.docs.keys()
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        if let Some(doc) = self.docs.remove(id) {
+            for term in &doc.terms {
+                if let Some(notes) = self.postings.get_mut(term) {
+                    notes.remove(id);
+                    if notes.is_empty() {
+                        self.postings.remove(term);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scores the notes matching every one of `words` and `exact`,
+    /// `segment_len` candidates at a time, so the first hits are ready
+    /// before the rest of the index has been scored. Each segment comes
+    /// best match first; a later one may hold better matches.
+    ///
+    /// Both are normalized words, as `text::words` returns them. One of
+    /// `words` matches words it equals or shares a stem with, words it is the
+    /// start of, and words within a small edit distance of it, so a typo
+    /// still finds the note; one of `exact` only matches itself and words
+    /// with its stem. Each note is scored with BM25, discounting matches
+    /// that are not exact.
+    pub fn scoring<'a>(
+        &'a self,
+        words: &'a [String],
+        exact: &'a [String],
+        segment_len: usize,
+    ) -> Scoring<'a> {
+        let terms: Vec<Vec<Matched>> = words
+            .iter()
+            .map(|word| (word.as_str(), false))
+            .chain(exact.iter().map(|word| (word.as_str(), true)))
+            .map(|(term, exact)| {
+                self.matching(term, exact)
+                    .into_iter()
+                    .map(|(word, weight)| {
+                        let notes = &self.postings[word];
+                        let df = notes.len() as f64;
+                        let idf = (1.0 + (self.docs.len() as f64 - df + 0.5) / (df + 0.5)).ln();
+                        Matched {
+                            word,
+                            notes,
+                            weight: weight * idf,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Every hit has a match for each term, so the term with the shortest
+        // postings names all the notes worth scoring.
+        let candidates: BTreeSet<&str> = terms
+            .iter()
+            .min_by_key(|matched| matched.iter().map(|m| m.notes.len()).sum::<usize>())
+            .into_iter()
+            .flatten()
+            .flat_map(|m| m.notes.keys().map(String::as_str))
+            .collect();
+        let average_len = self.docs.values().map(|doc| doc.len as f64).sum::<f64>()
+            / self.docs.len().max(1) as f64;
+        Scoring {
+            index: self,
+            terms,
+            average_len,
+            candidates: candidates.into_iter().collect::<Vec<_>>().into_iter(),
+            segment_len: segment_len.max(1),
+        }
+    }
+
+    /// The indexed words `term` matches, with how much a match counts.
+    fn matching<'a>(&'a self, term: &'a str, exact: bool) -> Vec<(&'a str, f64)> {
+        let mut matches = Vec::new();
+        for (i, variant) in text::variants(term).iter().enumerate() {
+            if let Some((matched, _)) = self.postings.get_key_value(variant.as_str()) {
+                matches.push((matched.as_str(), if i == 0 { 1.0 } else { STEM_WEIGHT }));
+            }
+        }
+        if exact {
+            return matches;
+        }
+        let max_distance = match term.chars().count() {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+        matches.extend(
+            self.postings
+                .range::<str, _>((Bound::Excluded(term), Bound::Unbounded))
+                .take_while(|(matched, _)| matched.starts_with(term))
+                .map(|(matched, _)| (matched.as_str(), PREFIX_WEIGHT)),
+        );
+        if max_distance > 0 {
+            for matched in self.postings.keys() {
+                if matches.iter().any(|&(m, _)| m == matched) || matched.starts_with(term) {
+                    continue;
+                }
+                if let Some(distance) = edit_distance(term, matched, max_distance) {
+                    matches.push((matched.as_str(), 1.0 / (1.0 + distance as f64)));
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// A note matching a search.
+#[derive(Debug, Default)]
+pub struct Hit {
+    pub id: String,
+    pub score: f64,
+    /// The indexed words that matched, to find them in the text again.
+    pub terms: BTreeSet<String>,
+}
+
+/// An indexed word a query term matches.
+struct Matched<'a> {
+    word: &'a str,
+    notes: &'a BTreeMap<String, u32>,
+    /// How much the match counts, times the word's inverse document
+    /// frequency.
+    weight: f64,
+}
+
+/// The segments of `SearchIndex::scoring`.
+pub struct Scoring<'a> {
+    index: &'a SearchIndex,
+    /// By query term, the indexed words it matches.
+    terms: Vec<Vec<Matched<'a>>>,
+    average_len: f64,
+    /// Notes not scored yet, in id order.
+    candidates: std::vec::IntoIter<&'a str>,
+    segment_len: usize,
+}
+
+impl Scoring<'_> {
+    /// The hit for note `id`, if it has a match for every term.
+    fn score(&self, id: &str) -> Option<Hit> {
+        let len = self.index.docs.get(id).map_or(0.0, |doc| doc.len as f64);
+        let norm = 1.0 - BM25_B + BM25_B * len / self.average_len.max(1.0);
+        let mut hit = Hit {
+            id: id.to_string(),
+            ..Default::default()
+        };
+        for matched in &self.terms {
+            let mut best: Option<f64> = None;
+            for m in matched {
+                if let Some(&count) = m.notes.get(id) {
+                    let tf = count as f64;
+                    let score = m.weight * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * norm);
+                    best = Some(best.map_or(score, |best| best.max(score)));
+                    hit.terms.insert(m.word.to_string());
+                }
+            }
+            hit.score += best?;
+        }
+        Some(hit)
+    }
+}
+
+impl Iterator for Scoring<'_> {
+    type Item = Vec<Hit>;
+
+    fn next(&mut self) -> Option<Vec<Hit>> {
+        if self.candidates.len() == 0 {
+            return None;
+        }
+        let segment: Vec<&str> = self.candidates.by_ref().take(self.segment_len).collect();
+        let mut hits: Vec<Hit> = segment
+            .into_iter()
+            .filter_map(|id| self.score(id))
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        Some(hits)
+    }
+}
+
+/// Part of a note's text around its matches.
+#[derive(Debug, Default)]
+pub struct Snippet {
+    pub text: String,
+    /// Byte ranges in `text`.
+    pub matches: Vec<Range<usize>>,
+}
+
+/// Whether the normalized words of `phrase` appear in `text` one after
+/// another.
+pub fn contains_phrase(text: &str, phrase: &[String]) -> bool {
+    phrase.is_empty()
+        || text::words(text)
+            .windows(phrase.len())
+            .any(|words| words == phrase)
+}
+
+/// Byte ranges of the words in `text` that are among `terms`, or whose
+/// stem is. Overlapping ranges, as in a run of Chinese or Japanese, are
+/// merged.
+pub fn find_matches(text: &str, terms: &BTreeSet<String>) -> Vec<Range<usize>> {
+    let mut matches: Vec<Range<usize>> = Vec::new();
+    for token in text::tokens(text, text::Language::Other) {
+        if !text::variants(&token.word)
+            .iter()
+            .any(|variant| terms.contains(variant))
+        {
+            continue;
+        }
+        match matches.last_mut() {
+            Some(last) if token.range.start < last.end => last.end = last.end.max(token.range.end),
+            _ => matches.push(token.range),
+        }
+    }
+    matches
+}
+
+/// At most about `max_len` bytes of `text`, placed to show as many of
+/// `matches` as fit, on one line and cut at word boundaries.
+pub fn snippet(text: &str, matches: &[Range<usize>], max_len: usize) -> Snippet {
+    // The match that starts the run with the most matches fitting in the
+    // window.
+    let first = (0..matches.len())
+        .max_by_key(|&i| {
+            let fitting = matches[i..]
+                .iter()
+                .take_while(|m| m.end - matches[i].start <= max_len)
+                .count();
+            (fitting, std::cmp::Reverse(i))
+        })
+        .map_or(0, |i| matches[i].start);
+
+    let mut start = text.floor_char_boundary(first.saturating_sub(max_len / 4));
+    if start > 0 {
+        if let Some(space) = text[start..first].find(char::is_whitespace) {
+            start += space + 1;
+        }
+    }
+    let mut end = text.floor_char_boundary((start + max_len).min(text.len()));
+    if end < text.len() && first < end {
+        if let Some(space) = text[first..end].rfind(char::is_whitespace) {
+            end = first + space;
+        }
+    }
+
+    let mut snippet = Snippet::default();
+    if start > 0 {
+        snippet.text.push_str("… ");
+    }
+    let offset = snippet.text.len();
+    snippet
+        .text
+        .push_str(&text[start..end].replace(['\n', '\r', '\t'], " "));
+    snippet.matches = matches
+        .iter()
+        .filter(|m| m.start >= start && m.end <= end)
+        .map(|m| m.start - start + offset..m.end - start + offset)
+        .collect();
+    if end < text.len() {
+        snippet.text.push_str(" …");
+    }
+    snippet
+}
+
+/// The Damerau-Levenshtein distance between `a` and `b` (counting adjacent
+/// transpositions as one edit), or `None` if it is above `max`.
+fn edit_distance(a: &str, b: &str, max: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        if current.iter().min().is_some_and(|&min| min > max) {
+            return None;
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    Some(previous[b.len()]).filter(|&distance| distance <= max)
+}
+
+#[cfg(test)]
+// Single match ranges are meant as such.
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn terms(words: &[&str]) -> BTreeSet<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    fn matched(snippet: &Snippet) -> Vec<&str> {
+        snippet
+            .matches
+            .iter()
+            .map(|m| &snippet.text[m.clone()])
+            .collect()
+    }
+
+    #[test]
+    fn scoring_in_segments_scores_like_scoring_at_once() {
+        let mut index = SearchIndex::default();
+        for i in 0..30 {
+            let text = format!("meeting {} notes {}", "agenda ".repeat(i % 4), i);
+            index.insert(&format!("note-{:02}", i), &text);
+        }
+        index.insert("other", "nothing to see");
+        let words = ["meetnig".to_string()];
+        let exact = ["notes".to_string()];
+
+        let at_once = index.scoring(&words, &exact, usize::MAX).next().unwrap();
+        assert_eq!(at_once.len(), 30);
+        assert!(at_once.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let segments: Vec<Vec<Hit>> = index.scoring(&words, &exact, 7).collect();
+        assert_eq!(segments.len(), 5);
+        let mut segmented: Vec<(String, f64)> = segments
+            .into_iter()
+            .flatten()
+            .map(|hit| (hit.id, hit.score))
+            .collect();
+        let mut expected: Vec<(String, f64)> =
+            at_once.into_iter().map(|hit| (hit.id, hit.score)).collect();
+        segmented.sort_by(|a, b| a.0.cmp(&b.0));
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(segmented, expected);
+    }
+
+    #[test]
+    fn edit_distance_counts_edits_up_to_the_limit() {
+        assert_eq!(edit_distance("meeting", "meeting", 2), Some(0));
+        assert_eq!(edit_distance("meeting", "meting", 2), Some(1));
+        assert_eq!(edit_distance("meeting", "meetings", 2), Some(1));
+        assert_eq!(edit_distance("meeting", "meetinq", 2), Some(1));
+        assert_eq!(edit_distance("kitten", "sitting", 3), Some(3));
+        assert_eq!(edit_distance("kitten", "sitting", 2), None);
+        assert_eq!(edit_distance("", "abc", 3), Some(3));
+        assert_eq!(edit_distance("a", "abcd", 2), None);
+        assert_eq!(edit_distance("grüße", "grüsse", 2), Some(2));
+    }
+
+    #[test]
+    fn edit_distance_counts_a_transposition_as_one_edit() {
+        assert_eq!(edit_distance("meeting", "meeitng", 1), Some(1));
+        assert_eq!(edit_distance("ab", "ba", 1), Some(1));
+        assert_eq!(edit_distance("abcd", "badc", 2), Some(2));
+    }
+
+    #[test]
+    fn find_matches_finds_words_and_their_stems() {
+        let text = "Meetings about the meeting, not a meet-up.";
+        assert_eq!(
+            find_matches(text, &terms(&["meet"])),
+            [0..8, 19..26, 34..38]
+        );
+        assert_eq!(find_matches(text, &terms(&["about"])), [9..14]);
+        assert!(find_matches(text, &terms(&["meat"])).is_empty());
+    }
+
+    #[test]
+    fn find_matches_merges_overlapping_words() {
+        let text = "Tokyo 東京タワー";
+        let all: BTreeSet<String> = text::tokens(text, text::Language::Other)
+            .into_iter()
+            .map(|token| token.word)
+            .collect();
+        assert_eq!(find_matches(text, &all), [0..5, 6..text.len()]);
+    }
+
+    #[test]
+    fn snippet_of_short_text_is_the_whole_text() {
+        let snippet = snippet("one\ntwo\tthree", &[4..7], 100);
+        assert_eq!(snippet.text, "one two three");
+        assert_eq!(matched(&snippet), ["two"]);
+    }
+
+    #[test]
+    fn snippet_is_cut_at_words_around_a_match() {
+        let text = format!("{}needle{}", "hay ".repeat(50), " straw".repeat(50));
+        let needle = text.find("needle").unwrap();
+        let snippet = snippet(&text, &[needle..needle + 6], 60);
+        assert!(snippet.text.starts_with("… hay "));
+        assert!(snippet.text.ends_with(" straw …"));
+        assert!(snippet.text.len() <= 60 + "… ".len() + " …".len());
+        assert_eq!(matched(&snippet), ["needle"]);
+    }
+
+    #[test]
+    fn snippet_shows_the_most_matches_that_fit() {
+        let text = format!(
+            "alone {}pair and pair{}",
+            "filler ".repeat(30),
+            " filler".repeat(30)
+        );
+        let matches = find_matches(&text, &terms(&["alone", "pair"]));
+        assert_eq!(matches.len(), 3);
+        let snippet = snippet(&text, &matches, 40);
+        assert_eq!(matched(&snippet), ["pair", "pair"]);
+    }
+
+    #[test]
+    fn snippet_does_not_split_characters() {
+        let text = "ü".repeat(100);
+        let snippet = snippet(&text, &[100..102], 31);
+        assert!(snippet.text.starts_with("… ü"));
+        assert!(snippet.text.ends_with("ü …"));
+    }
+}
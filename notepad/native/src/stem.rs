@@ -0,0 +1,411 @@
+//! English and German stemmers, so that "meetings" finds "meeting" and
+//! "Häuser" finds "Haus".
+//!
+//! English is Porter's original algorithm; German follows the Snowball
+//! German stemmer. Both expect a word already folded by `crate::text`, and
+//! leave anything that is not made of plain letters alone.
+
+/// Porter's stemmer.
+pub fn english(word: &str) -> String {
+    if word.len() <= 2 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+    let mut w = word.as_bytes().to_vec();
+    step_1ab(&mut w);
+    step_1c(&mut w);
+    replace_first(&mut w, STEP_2, 0);
+    replace_first(&mut w, STEP_3, 0);
+    step_4(&mut w);
+    step_5(&mut w);
+    String::from_utf8(w).unwrap_or_else(|_| word.to_string())
+}
+
+const STEP_2: &[(&str, &str)] = &[
+    ("ational", "ate"),
+    ("tional", "tion"),
+    ("enci", "ence"),
+    ("anci", "ance"),
+    ("izer", "ize"),
+    ("bli", "ble"),
+    ("alli", "al"),
+    ("entli", "ent"),
+    ("eli", "e"),
+    ("ousli", "ous"),
+    ("ization", "ize"),
+    ("ation", "ate"),
+    ("ator", "ate"),
+    ("alism", "al"),
+    ("iveness", "ive"),
+    ("fulness", "ful"),
+    ("ousness", "ous"),
+    ("aliti", "al"),
+    ("iviti", "ive"),
+    ("biliti", "ble"),
+    ("logi", "log"),
+];
+
+const STEP_3: &[(&str, &str)] = &[
+    ("icate", "ic"),
+    ("ative", ""),
+    ("alize", "al"),
+    ("iciti", "ic"),
+    ("ical", "ic"),
+    ("ful", ""),
+    ("ness", ""),
+];
+
+const STEP_4: &[&str] = &[
+    "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion", "ou",
+    "ism", "ate", "iti", "ous", "ive", "ize",
+];
+
+fn is_consonant(w: &[u8], i: usize) -> bool {
+    match w[i] {
+        b'a' | b'e' | b'i' | b'o' | b'u' => false,
+        b'y' => i == 0 || !is_consonant(w, i - 1),
+        _ => true,
+    }
+}
+
+/// The number of vowel-consonant sequences in `w`, Porter's m.
+fn measure(w: &[u8]) -> usize {
+    let mut m = 0;
+    let mut i = 0;
+    while i < w.len() && is_consonant(w, i) {
+        i += 1;
+    }
+    loop {
+        while i < w.len() && !is_consonant(w, i) {
+            i += 1;
+        }
+        if i == w.len() {
+            return m;
+        }
+        while i < w.len() && is_consonant(w, i) {
+            i += 1;
+        }
+        m += 1;
+    }
+}
+
+fn has_vowel(w: &[u8]) -> bool {
+    (0..w.len()).any(|i| !is_consonant(w, i))
+}
+
+fn ends_with_double_consonant(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 2 && w[n - 1] == w[n - 2] && is_consonant(w, n - 1)
+}
+
+/// Consonant, vowel, consonant, where the last is not w, x or y.
+fn ends_with_cvc(w: &[u8]) -> bool {
+    let n = w.len();
+    n >= 3
+        && is_consonant(w, n - 3)
+        && !is_consonant(w, n - 2)
+        && is_consonant(w, n - 1)
+        && !matches!(w[n - 1], b'w' | b'x' | b'y')
+}
+
+fn stem_of<'a>(w: &'a [u8], suffix: &str) -> Option<&'a [u8]> {
+    w.strip_suffix(suffix.as_bytes())
+}
+
+fn set_suffix(w: &mut Vec<u8>, suffix: &str, replacement: &str) {
+    w.truncate(w.len() - suffix.len());
+    w.extend_from_slice(replacement.as_bytes());
+}
+
+/// Applies the first rule whose suffix `w` ends with, if the stem's measure
+/// is above `min_measure`.
+fn replace_first(w: &mut Vec<u8>, rules: &[(&str, &str)], min_measure: usize) {
+    for (suffix, replacement) in rules {
+        if let Some(stem) = stem_of(w, suffix) {
+            if measure(stem) > min_measure {
+                set_suffix(w, suffix, replacement);
+            }
+            return;
+        }
+    }
+}
+
+fn step_1ab(w: &mut Vec<u8>) {
+    if w.ends_with(b"sses") || w.ends_with(b"ies") {
+        w.truncate(w.len() - 2);
+    } else if w.ends_with(b"s") && !w.ends_with(b"ss") {
+        w.pop();
+    }
+
+    if let Some(stem) = stem_of(w, "eed") {
+        if measure(stem) > 0 {
+            w.pop();
+        }
+        return;
+    }
+    let suffix = ["ed", "ing"]
+        .into_iter()
+        .find(|suffix| stem_of(w, suffix).is_some_and(has_vowel));
+    if let Some(suffix) = suffix {
+        set_suffix(w, suffix, "");
+        if w.ends_with(b"at") || w.ends_with(b"bl") || w.ends_with(b"iz") {
+            w.push(b'e');
+        } else if ends_with_double_consonant(w) && !matches!(w[w.len() - 1], b'l' | b's' | b'z') {
+            w.pop();
+        } else if measure(w) == 1 && ends_with_cvc(w) {
+            w.push(b'e');
+        }
+    }
+}
+
+fn step_1c(w: &mut [u8]) {
+    if stem_of(w, "y").is_some_and(has_vowel) {
+        let last = w.len() - 1;
+        w[last] = b'i';
+    }
+}
+
+fn step_4(w: &mut Vec<u8>) {
+    for suffix in STEP_4 {
+        if let Some(stem) = stem_of(w, suffix) {
+            if *suffix == "ion" && !matches!(stem.last(), Some(b's' | b't')) {
+                continue;
+            }
+            if measure(stem) > 1 {
+                set_suffix(w, suffix, "");
+            }
+            return;
+        }
+    }
+}
+
+fn step_5(w: &mut Vec<u8>) {
+    if let Some(stem) = stem_of(w, "e") {
+        let m = measure(stem);
+        if m > 1 || (m == 1 && !ends_with_cvc(stem)) {
+            w.pop();
+        }
+    }
+    if w.ends_with(b"ll") && measure(w) > 1 {
+        w.pop();
+    }
+}
+
+/// The Snowball German stemmer. `crate::text` spells umlauts out as `ae`,
+/// `oe` and `ue` and `ß` as `ss`, so those are read back first, and the
+/// stem has no umlauts: "Schlüssel", "Schluessel" and "Schlussel" all
+/// become "schlussel".
+pub fn german(word: &str) -> String {
+    if !word.chars().all(|c| c.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+    let mut w: Vec<char> = Vec::with_capacity(word.len());
+    for c in word.chars() {
+        let n = w.len();
+        let umlaut = match (w.last(), c) {
+            (Some('a'), 'e') => Some('ä'),
+            (Some('o'), 'e') => Some('ö'),
+            (Some('u'), 'e') if n < 2 || w[n - 2] != 'q' => Some('ü'),
+            _ => None,
+        };
+        match umlaut {
+            Some(umlaut) => w[n - 1] = umlaut,
+            None => w.push(c),
+        }
+    }
+    // A u or y between vowels is a consonant.
+    for i in 1..w.len().saturating_sub(1) {
+        if is_german_vowel(w[i - 1]) && is_german_vowel(w[i + 1]) {
+            match w[i] {
+                'u' => w[i] = 'U',
+                'y' => w[i] = 'Y',
+                _ => {}
+            }
+        }
+    }
+
+    let r1 = region_start(&w, 0);
+    let r2 = region_start(&w, r1);
+    let r1 = r1.max(3);
+
+    // Step 1.
+    if let Some(suffix) = longest_suffix(&w, &["em", "ern", "er", "e", "en", "es", "s"]) {
+        let start = w.len() - suffix.chars().count();
+        let valid = suffix != "s" || (start > 0 && "bdfghklmnrt".contains(w[start - 1]));
+        if start >= r1 && valid {
+            w.truncate(start);
+            if matches!(suffix, "e" | "en" | "es") && ends_with(&w, "niss") {
+                w.pop();
+            }
+        }
+    }
+
+    // Step 2.
+    if let Some(suffix) = longest_suffix(&w, &["en", "er", "est", "st"]) {
+        let start = w.len() - suffix.chars().count();
+        let valid = suffix != "st" || (start > 3 && "bdfghklmnt".contains(w[start - 1]));
+        if start >= r1 && valid {
+            w.truncate(start);
+        }
+    }
+
+    // Step 3.
+    if let Some(suffix) = longest_suffix(
+        &w,
+        &["end", "ung", "ig", "ik", "isch", "lich", "heit", "keit"],
+    ) {
+        let start = w.len() - suffix.chars().count();
+        if start >= r2 {
+            match suffix {
+                "end" | "ung" => {
+                    w.truncate(start);
+                    if ends_with(&w, "ig") && !ends_with(&w, "eig") && w.len() - 2 >= r2 {
+                        w.truncate(w.len() - 2);
+                    }
+                }
+                "ig" | "ik" | "isch" => {
+                    if start == 0 || w[start - 1] != 'e' {
+                        w.truncate(start);
+                    }
+                }
+                "lich" | "heit" => {
+                    w.truncate(start);
+                    if (ends_with(&w, "er") || ends_with(&w, "en")) && w.len() - 2 >= r1 {
+                        w.truncate(w.len() - 2);
+                    }
+                }
+                _ => {
+                    w.truncate(start);
+                    if ends_with(&w, "lich") && w.len() - 4 >= r2 {
+                        w.truncate(w.len() - 4);
+                    } else if ends_with(&w, "ig") && w.len() - 2 >= r2 {
+                        w.truncate(w.len() - 2);
+                    }
+                }
+            }
+        }
+    }
+
+    w.into_iter()
+        .map(|c| match c {
+            'U' | 'ü' => 'u',
+            'Y' => 'y',
+            'ä' => 'a',
+            'ö' => 'o',
+            c => c,
+        })
+        .collect()
+}
+
+fn is_german_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü')
+}
+
+/// Where the Snowball region after `from` starts: after the first
+/// non-vowel that follows a vowel at or after `from`.
+fn region_start(w: &[char], from: usize) -> usize {
+    (from + 1..w.len())
+        .find(|&i| !is_german_vowel(w[i]) && is_german_vowel(w[i - 1]))
+        .map_or(w.len(), |i| i + 1)
+}
+
+fn ends_with(w: &[char], suffix: &str) -> bool {
+    let suffix: Vec<char> = suffix.chars().collect();
+    w.ends_with(&suffix)
+}
+
+fn longest_suffix<'a>(w: &[char], suffixes: &[&'a str]) -> Option<&'a str> {
+    suffixes
+        .iter()
+        .copied()
+        .filter(|suffix| ends_with(w, suffix))
+        .max_by_key(|suffix| suffix.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_follows_porters_examples() {
+        // The examples given for each step in Porter's paper.
+        for (word, stem) in [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("ties", "ti"),
+            ("caress", "caress"),
+            ("cats", "cat"),
+            ("feed", "feed"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("bled", "bled"),
+            ("motoring", "motor"),
+            ("sing", "sing"),
+            ("conflated", "conflat"),
+            ("troubled", "troubl"),
+            ("sized", "size"),
+            ("hopping", "hop"),
+            ("tanned", "tan"),
+            ("falling", "fall"),
+            ("hissing", "hiss"),
+            ("fizzed", "fizz"),
+            ("failing", "fail"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("sky", "sky"),
+            ("relational", "relat"),
+            ("conditional", "condit"),
+            ("rational", "ration"),
+            ("generalization", "gener"),
+            ("electricity", "electr"),
+            ("hopeful", "hope"),
+            ("goodness", "good"),
+            ("revival", "reviv"),
+            ("allowance", "allow"),
+            ("inference", "infer"),
+            ("airliner", "airlin"),
+            ("adjustable", "adjust"),
+            ("defensible", "defens"),
+            ("irritant", "irrit"),
+            ("replacement", "replac"),
+            ("adoption", "adopt"),
+            ("probate", "probat"),
+            ("rate", "rate"),
+            ("cease", "ceas"),
+            ("controll", "control"),
+            ("roll", "roll"),
+        ] {
+            assert_eq!(english(word), stem, "{}", word);
+        }
+    }
+
+    #[test]
+    fn german_follows_the_snowball_vocabulary() {
+        // From the Snowball German sample vocabulary, with umlauts spelled
+        // out as `crate::text` folds them.
+        for (word, stem) in [
+            ("aufeinander", "aufeinand"),
+            ("aufeinanderfolge", "aufeinanderfolg"),
+            ("aufeinanderfolgenden", "aufeinanderfolg"),
+            ("kategorie", "kategori"),
+            ("kategorien", "kategori"),
+            ("katzen", "katz"),
+            ("kaeufer", "kauf"),
+            ("laufen", "lauf"),
+            ("freundlichkeit", "freundlich"),
+            ("ergebnisse", "ergebnis"),
+            ("haeuser", "haus"),
+            ("schluessel", "schlussel"),
+            ("schlussel", "schlussel"),
+            ("quelle", "quell"),
+        ] {
+            assert_eq!(german(word), stem, "{}", word);
+        }
+    }
+
+    #[test]
+    fn words_that_are_not_plain_letters_are_left_alone() {
+        assert_eq!(english("r2d2"), "r2d2");
+        assert_eq!(german("r2d2"), "r2d2");
+        assert_eq!(german("straße"), "straße");
+    }
+}
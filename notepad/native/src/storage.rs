@@ -0,0 +1,107 @@
+//! Where a vault keeps its bytes.
+//!
+//! The vault addresses everything it stores by `/`-separated keys such as
+//! `notes/<id>.note` and only ever hands a backend sealed envelopes, so a
+//! backend deals in opaque values and never sees plaintext.
+
+use crate::api::StorageKind;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+mod directory;
+mod memory;
+mod sqlite;
+
+pub use directory::DirectoryStorage;
+pub use memory::MemoryStorage;
+pub use sqlite::SqliteStorage;
+
+pub struct Metadata {
+    pub len: u64,
+    /// Milliseconds since the Unix epoch.
+    pub modified_at: i64,
+}
+
+pub trait StorageBackend: Send + Sync {
+    /// Fails with `NotFound` when there is no entry under `key`.
+    fn read(&self, key: &str) -> io::Result<Vec<u8>>;
+
+    /// Creates or replaces an entry. A reader sees either the old value or
+    /// the new one, also after a crash.
+    fn write(&self, key: &str, data: &[u8]) -> io::Result<()>;
+
+    /// Fails with `NotFound` when there is no entry under `key`.
+    fn delete(&self, key: &str) -> io::Result<()>;
+
+    /// Every key that starts with `prefix`, in no particular order.
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>>;
+
+    fn metadata(&self, key: &str) -> io::Result<Metadata>;
+
+    /// Where the data lives, for display and logs.
+    fn location(&self) -> String;
+
+    /// The file processes sharing this storage lock to coordinate, or
+    /// `None` when nothing outside this process can reach the data.
+    fn lock_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// The file or directory whose changes mean the data changed, or `None`
+    /// when only this process can change it.
+    fn watch_path(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// A directory for scratch files, on the same disk as the data, or
+    /// `None` when the data is not in files. What is left in it counts
+    /// among `temp_files`.
+    fn staging_dir(&self) -> Option<PathBuf> {
+        None
+    }
+
+    /// Leftovers of writes that never completed, for display.
+    fn temp_files(&self) -> io::Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Deletes what `temp_files` lists and returns how many went. Only safe
+    /// while no write can be in progress.
+    fn remove_temp_files(&self) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    fn exists(&self, key: &str) -> io::Result<bool> {
+        match self.metadata(key) {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Deletes every entry whose key starts with `prefix`.
+    fn delete_prefix(&self, prefix: &str) -> io::Result<()> {
+        for key in self.list(prefix)? {
+            match self.delete(&key) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Opens the backend of the given kind. `location` is the vault directory
+/// or the database file; the in-memory backend ignores it.
+pub fn open(kind: StorageKind, location: &Path) -> anyhow::Result<Arc<dyn StorageBackend>> {
+    Ok(match kind {
+        StorageKind::Directory => Arc::new(DirectoryStorage::open(location)?),
+        StorageKind::Memory => Arc::new(MemoryStorage::default()),
+        StorageKind::Sqlite => Arc::new(SqliteStorage::open(location)?),
+    })
+}
+
+pub fn not_found(key: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("no entry {:?}", key))
+}
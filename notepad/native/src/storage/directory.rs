@@ -0,0 +1,151 @@
+use super::{Metadata, StorageBackend};
+use crate::atomic;
+use anyhow::Context;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+const LOCK_FILE: &str = "vault.lock";
+const STAGING_DIR: &str = "staging";
+
+/// One file per key below a root directory, written with `write_atomic`.
+pub struct DirectoryStorage {
+    root: PathBuf,
+}
+
+impl DirectoryStorage {
+    /// Creates the directory if needed.
+    pub fn open(root: &Path) -> anyhow::Result<Self> {
+        fs::create_dir_all(root)
+            .with_context(|| format!("failed to create vault at {:?}", root))?;
+        Ok(DirectoryStorage {
+            root: root.to_path_buf(),
+        })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+
+    fn collect(&self, dir: &Path, keys: &mut Vec<String>) -> io::Result<()> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_type = match entry.file_type() {
+                Ok(file_type) => file_type,
+                Err(_) => continue,
+            };
+            if file_type.is_dir() {
+                self.collect(&path, keys)?;
+            } else if file_type.is_file()
+                && !atomic::is_temp_file(&path)
+                && path != self.path(LOCK_FILE)
+            {
+                let key = path
+                    .strip_prefix(&self.root)
+                    .expect("walked below the root");
+                let key: Vec<_> = key.iter().map(|part| part.to_string_lossy()).collect();
+                keys.push(key.join("/"));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StorageBackend for DirectoryStorage {
+    fn read(&self, key: &str) -> io::Result<Vec<u8>> {
+        fs::read(self.path(key))
+    }
+
+    fn write(&self, key: &str, data: &[u8]) -> io::Result<()> {
+        let path = self.path(key);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        atomic::write_atomic(&path, data)
+    }
+
+    fn delete(&self, key: &str) -> io::Result<()> {
+        fs::remove_file(self.path(key))
+    }
+
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>> {
+        // Only walk the deepest directory the prefix names.
+        let dir = match prefix.rfind('/') {
+            Some(end) => self.path(&prefix[..end]),
+            None => self.root.clone(),
+        };
+        let mut keys = Vec::new();
+        self.collect(&dir, &mut keys)?;
+        keys.retain(|key| key.starts_with(prefix));
+        Ok(keys)
+    }
+
+    fn metadata(&self, key: &str) -> io::Result<Metadata> {
+        let metadata = fs::metadata(self.path(key))?;
+        if !metadata.is_file() {
+            return Err(super::not_found(key));
+        }
+        let modified_at = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or_default();
+        Ok(Metadata {
+            len: metadata.len(),
+            modified_at,
+        })
+    }
+
+    fn location(&self) -> String {
+        self.root.to_string_lossy().to_string()
+    }
+
+    fn lock_path(&self) -> Option<PathBuf> {
+        Some(self.path(LOCK_FILE))
+    }
+
+    fn watch_path(&self) -> Option<PathBuf> {
+        Some(self.root.clone())
+    }
+
+    fn staging_dir(&self) -> Option<PathBuf> {
+        Some(self.path(STAGING_DIR))
+    }
+
+    fn temp_files(&self) -> io::Result<Vec<String>> {
+        Ok(atomic::temp_files(&self.root)?
+            .iter()
+            .map(|path| {
+                path.strip_prefix(&self.root)
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect())
+    }
+
+    fn remove_temp_files(&self) -> io::Result<usize> {
+        atomic::remove_temp_files(&self.root)
+    }
+
+    fn delete_prefix(&self, prefix: &str) -> io::Result<()> {
+        // A whole directory goes in one call, without leaving it behind empty.
+        if let Some(dir) = prefix.strip_suffix('/') {
+            return match fs::remove_dir_all(self.path(dir)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            };
+        }
+        for key in self.list(prefix)? {
+            self.delete(&key)?;
+        }
+        Ok(())
+    }
+}
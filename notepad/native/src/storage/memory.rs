@@ -0,0 +1,72 @@
+use super::{Metadata, StorageBackend};
+use crate::note;
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Mutex, MutexGuard};
+
+/// Keeps everything in a map and loses it when dropped. Meant for tests and
+/// throwaway vaults.
+#[derive(Default)]
+pub struct MemoryStorage {
+    entries: Mutex<BTreeMap<String, Entry>>,
+}
+
+struct Entry {
+    data: Vec<u8>,
+    modified_at: i64,
+}
+
+impl MemoryStorage {
+    fn entries(&self) -> MutexGuard<'_, BTreeMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl StorageBackend for MemoryStorage {
+    fn read(&self, key: &str) -> io::Result<Vec<u8>> {
+        self.entries()
+            .get(key)
+            .map(|entry| entry.data.clone())
+            .ok_or_else(|| super::not_found(key))
+    }
+
+    fn write(&self, key: &str, data: &[u8]) -> io::Result<()> {
+        let entry = Entry {
+            data: data.to_vec(),
+            modified_at: note::now_millis(),
+        };
+        self.entries().insert(key.to_string(), entry);
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> io::Result<()> {
+        self.entries()
+            .remove(key)
+            .map(|_| ())
+            .ok_or_else(|| super::not_found(key))
+    }
+
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>> {
+        Ok(self
+            .entries()
+            .range(prefix.to_string()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    fn metadata(&self, key: &str) -> io::Result<Metadata> {
+        self.entries()
+            .get(key)
+            .map(|entry| Metadata {
+                len: entry.data.len() as u64,
+                modified_at: entry.modified_at,
+            })
+            .ok_or_else(|| super::not_found(key))
+    }
+
+    fn location(&self) -> String {
+        "memory".to_string()
+    }
+}
@@ -0,0 +1,159 @@
+use super::{Metadata, StorageBackend};
+use crate::atomic;
+use crate::note;
+use anyhow::Context;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+/// The whole vault in a single SQLite file, one row per key. Rows only ever
+/// hold sealed envelopes; keys are ids, chunk hashes and fixed names.
+pub struct SqliteStorage {
+    path: PathBuf,
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStorage {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let connection = Connection::open(path)
+            .with_context(|| format!("failed to open vault database {:?}", path))?;
+        connection
+            .execute_batch(
+                "CREATE TABLE IF NOT EXISTS entries (
+                    key TEXT PRIMARY KEY NOT NULL,
+                    data BLOB NOT NULL,
+                    modified_at INTEGER NOT NULL
+                )",
+            )
+            .context("failed to set up vault database")?;
+        Ok(SqliteStorage {
+            path: path.to_path_buf(),
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connection(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn to_io(e: rusqlite::Error) -> io::Error {
+    io::Error::other(e)
+}
+
+impl StorageBackend for SqliteStorage {
+    fn read(&self, key: &str) -> io::Result<Vec<u8>> {
+        self.connection()
+            .query_row("SELECT data FROM entries WHERE key = ?1", [key], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(to_io)?
+            .ok_or_else(|| super::not_found(key))
+    }
+
+    fn write(&self, key: &str, data: &[u8]) -> io::Result<()> {
+        self.connection()
+            .execute(
+                "INSERT OR REPLACE INTO entries (key, data, modified_at) VALUES (?1, ?2, ?3)",
+                params![key, data, note::now_millis()],
+            )
+            .map(|_| ())
+            .map_err(to_io)
+    }
+
+    fn delete(&self, key: &str) -> io::Result<()> {
+        let deleted = self
+            .connection()
+            .execute("DELETE FROM entries WHERE key = ?1", [key])
+            .map_err(to_io)?;
+        if deleted == 0 {
+            return Err(super::not_found(key));
+        }
+        Ok(())
+    }
+
+    fn list(&self, prefix: &str) -> io::Result<Vec<String>> {
+        let connection = self.connection();
+        let mut statement = connection
+            .prepare("SELECT key FROM entries WHERE substr(key, 1, length(?1)) = ?1")
+            .map_err(to_io)?;
+        let keys = statement
+            .query_map([prefix], |row| row.get(0))
+            .map_err(to_io)?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(to_io)?;
+        Ok(keys)
+    }
+
+    fn metadata(&self, key: &str) -> io::Result<Metadata> {
+        self.connection()
+            .query_row(
+                "SELECT length(data), modified_at FROM entries WHERE key = ?1",
+                [key],
+                |row| {
+                    Ok(Metadata {
+                        len: row.get::<_, i64>(0)? as u64,
+                        modified_at: row.get(1)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(to_io)?
+            .ok_or_else(|| super::not_found(key))
+    }
+
+    fn location(&self) -> String {
+        self.path.to_string_lossy().to_string()
+    }
+
+    fn lock_path(&self) -> Option<PathBuf> {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".lock");
+        Some(path.into())
+    }
+
+    fn watch_path(&self) -> Option<PathBuf> {
+        Some(self.path.clone())
+    }
+
+    fn staging_dir(&self) -> Option<PathBuf> {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".staging");
+        Some(path.into())
+    }
+
+    fn temp_files(&self) -> io::Result<Vec<String>> {
+        let dir = self.staging_dir().expect("always set");
+        match atomic::temp_files(&dir) {
+            Ok(paths) => Ok(paths
+                .iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn remove_temp_files(&self) -> io::Result<usize> {
+        let dir = self.staging_dir().expect("always set");
+        match atomic::remove_temp_files(&dir) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(0),
+            result => result,
+        }
+    }
+
+    fn delete_prefix(&self, prefix: &str) -> io::Result<()> {
+        self.connection()
+            .execute(
+                "DELETE FROM entries WHERE substr(key, 1, length(?1)) = ?1",
+                [prefix],
+            )
+            .map(|_| ())
+            .map_err(to_io)
+    }
+}
@@ -0,0 +1,117 @@
+//! Prefix lookup for autocomplete.
+//!
+//! Every candidate is put in a trie under its normalized text from each of
+//! its words on, so "Meeting notes" is suggested for both "mee" and "no".
+//! Candidates are then ranked by how often and how recently they were
+//! picked, with the score of each use halving every few weeks.
+
+use crate::text;
+
+const MILLIS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+/// Days after which a use counts half as much.
+const HALF_LIFE_DAYS: f64 = 14.0;
+/// Characters of a key the trie stores. A longer prefix is looked up by its
+/// start, and the candidates found are checked against the rest.
+pub const MAX_KEY_CHARS: usize = 32;
+
+pub struct Trie {
+    /// The root is the first node.
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Node {
+    /// Sorted by character.
+    children: Vec<(char, u32)>,
+    /// Candidates with a key ending here.
+    entries: Vec<u32>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie {
+            nodes: vec![Node::default()],
+        }
+    }
+}
+
+impl Trie {
+    /// Files candidate `entry` under `key`, a normalized string.
+    pub fn insert(&mut self, key: &str, entry: u32) {
+        let mut node = 0;
+        for c in key.chars().take(MAX_KEY_CHARS) {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&c, |&(c, _)| c)
+            {
+                Ok(i) => self.nodes[node].children[i].1 as usize,
+                Err(i) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(i, (c, child as u32));
+                    child
+                }
+            };
+        }
+        if !self.nodes[node].entries.contains(&entry) {
+            self.nodes[node].entries.push(entry);
+        }
+    }
+
+    /// The candidates with a key starting with the first `MAX_KEY_CHARS`
+    /// characters of `prefix`, each once, in ascending order.
+    pub fn starting_with(&self, prefix: &str) -> Vec<u32> {
+        let mut node = 0;
+        for c in prefix.chars().take(MAX_KEY_CHARS) {
+            match self.nodes[node]
+                .children
+                .binary_search_by_key(&c, |&(c, _)| c)
+            {
+                Ok(i) => node = self.nodes[node].children[i].1 as usize,
+                Err(_) => return Vec::new(),
+            }
+        }
+        let mut entries = Vec::new();
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            entries.extend_from_slice(&self.nodes[node].entries);
+            stack.extend(
+                self.nodes[node]
+                    .children
+                    .iter()
+                    .map(|&(_, child)| child as usize),
+            );
+        }
+        entries.sort_unstable();
+        entries.dedup();
+        entries
+    }
+}
+
+/// `text` as keys and prefixes are compared: folded like search words, with
+/// each run of whitespace as one space.
+pub fn normalize(text: &str) -> String {
+    text::fold(text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The keys `text` is found by: its normalized text from the start of each
+/// word, or of each character of Chinese or Japanese.
+pub fn keys(text: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for token in text::tokens(text, text::Language::Other) {
+        let key = normalize(&text[token.range.start..]);
+        if !key.is_empty() && !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+/// How much uses scoring `score` as of `last_used_at` are worth at `now`.
+pub fn decayed(score: f64, last_used_at: i64, now: i64) -> f64 {
+    let age_days = (now - last_used_at).max(0) as f64 / MILLIS_PER_DAY;
+    score * 0.5f64.powf(age_days / HALF_LIFE_DAYS)
+}
@@ -0,0 +1,196 @@
+//! Turning text into the words search compares.
+//!
+//! Words are normalized so that different spellings of the same word look
+//! alike: NFKC, lowercase, German umlauts and `ß` spelled out (`ü` as `ue`)
+//! and other accents dropped, so "Schlüssel" and "schluessel" are one word
+//! and "café" is "cafe". Chinese, Japanese and Korean are written without
+//! spaces, so runs of those scripts are cut into overlapping pairs of
+//! characters instead, which lets a phrase be found wherever it appears.
+
+use crate::stem;
+use std::ops::Range;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// A word of a text.
+#[derive(Debug)]
+pub struct Token {
+    /// The normalized word.
+    pub word: String,
+    /// `word` stemmed for the text's language.
+    pub stem: String,
+    /// Byte range in the text.
+    pub range: Range<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Language {
+    English,
+    German,
+    /// Anything else, or too little text to tell. Words are not stemmed.
+    Other,
+}
+
+/// The language `text` is written in, if it is one with a stemmer and
+/// there is enough of it to be sure.
+pub fn detect(text: &str) -> Language {
+    match whatlang::detect(text) {
+        Some(info) if info.is_reliable() => match info.lang() {
+            whatlang::Lang::Eng => Language::English,
+            whatlang::Lang::Deu => Language::German,
+            _ => Language::Other,
+        },
+        _ => Language::Other,
+    }
+}
+
+/// The words of `text`, stemmed for `language`.
+pub fn tokens(text: &str, language: Language) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    for (range, cjk) in runs(text) {
+        if !cjk {
+            let word = fold(&text[range.clone()]);
+            if !word.is_empty() {
+                let stem = stem_for(&word, language);
+                tokens.push(Token { word, stem, range });
+            }
+            continue;
+        }
+        let characters = characters(&text[range.clone()], range.start);
+        if characters.len() == 1 {
+            let word = fold(&text[range.clone()]);
+            tokens.push(Token {
+                stem: word.clone(),
+                word,
+                range,
+            });
+        }
+        for pair in characters.windows(2) {
+            let range = pair[0].start..pair[1].end;
+            let word = fold(&text[range.clone()]);
+            tokens.push(Token {
+                stem: word.clone(),
+                word,
+                range,
+            });
+        }
+    }
+    tokens
+}
+
+/// The normalized words of `text`, unstemmed.
+pub fn words(text: &str) -> Vec<String> {
+    tokens(text, Language::Other)
+        .into_iter()
+        .map(|token| token.word)
+        .collect()
+}
+
+/// `word` as search compares it.
+pub fn fold(word: &str) -> String {
+    let mut spelled = String::with_capacity(word.len());
+    for c in word.nfkc().flat_map(char::to_lowercase) {
+        match c {
+            'ß' => spelled.push_str("ss"),
+            'ä' => spelled.push_str("ae"),
+            'ö' => spelled.push_str("oe"),
+            'ü' => spelled.push_str("ue"),
+            'ς' => spelled.push('σ'),
+            c => spelled.push(c),
+        }
+    }
+    // Only accents of the Latin, Greek and Cyrillic alphabets are dropped;
+    // the voicing marks of kana change the word.
+    spelled
+        .nfd()
+        .filter(|&c| !('\u{300}'..='\u{36f}').contains(&c))
+        .nfc()
+        .collect()
+}
+
+/// The forms of a normalized query word an indexed word may have: the word
+/// itself, then its stems, since the language of the notes it should find
+/// is not known.
+pub fn variants(word: &str) -> Vec<String> {
+    let mut variants = vec![word.to_string()];
+    for stem in [stem::english(word), stem::german(word)] {
+        if !variants.contains(&stem) {
+            variants.push(stem);
+        }
+    }
+    variants
+}
+
+fn stem_for(word: &str, language: Language) -> String {
+    match language {
+        Language::English => stem::english(word),
+        Language::German => stem::german(word),
+        Language::Other => word.to_string(),
+    }
+}
+
+/// Byte ranges of the runs of word characters in `text`, and whether each
+/// is in a script written without spaces.
+fn runs(text: &str) -> Vec<(Range<usize>, bool)> {
+    let mut runs: Vec<(Range<usize>, bool)> = Vec::new();
+    let mut current: Option<(Range<usize>, bool)> = None;
+    for (i, c) in text.char_indices() {
+        let end = i + c.len_utf8();
+        // Accents belong to the character before them.
+        if is_combining_mark(c) {
+            if let Some((range, _)) = &mut current {
+                range.end = end;
+            }
+            continue;
+        }
+        let cjk = is_cjk(c);
+        match &mut current {
+            Some((range, run_cjk)) if (c.is_alphanumeric() || cjk) && *run_cjk == cjk => {
+                range.end = end;
+            }
+            _ => {
+                runs.extend(current.take());
+                if c.is_alphanumeric() || cjk {
+                    current = Some((i..end, cjk));
+                }
+            }
+        }
+    }
+    runs.extend(current);
+    runs
+}
+
+/// Byte ranges of the characters of `run`, each with the marks after it,
+/// offset by `start`.
+fn characters(run: &str, start: usize) -> Vec<Range<usize>> {
+    let mut characters: Vec<Range<usize>> = Vec::new();
+    for (i, c) in run.char_indices() {
+        let range = start + i..start + i + c.len_utf8();
+        match characters.last_mut() {
+            Some(last) if is_combining_mark(c) || is_sound_mark(c) => last.end = range.end,
+            _ => characters.push(range),
+        }
+    }
+    characters
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11ff}'     // Hangul Jamo
+        | '\u{3005}'..='\u{3007}'   // 々, 〆, 〇
+        | '\u{3040}'..='\u{30ff}'   // Hiragana, Katakana
+        | '\u{3130}'..='\u{318f}'   // Hangul Compatibility Jamo
+        | '\u{31f0}'..='\u{31ff}'   // Katakana Phonetic Extensions
+        | '\u{3400}'..='\u{4dbf}'   // CJK Extension A
+        | '\u{4e00}'..='\u{9fff}'   // CJK Unified Ideographs
+        | '\u{ac00}'..='\u{d7af}'   // Hangul Syllables
+        | '\u{f900}'..='\u{faff}'   // CJK Compatibility Ideographs
+        | '\u{ff66}'..='\u{ff9f}'   // Halfwidth Katakana
+        | '\u{20000}'..='\u{2fa1f}' // CJK Extensions B to F, supplement
+    )
+}
+
+/// The half-width voicing marks, which NFKC joins to the kana before them.
+fn is_sound_mark(c: char) -> bool {
+    matches!(c, '\u{ff9e}' | '\u{ff9f}')
+}
@@ -0,0 +1,504 @@
+use crate::api::{Note, NoteSummary, RevisionInfo, SaveConflict, SaveResult, StorageKind, VaultSettings};
+use crate::chunks::{Blob, ChunkKeys};
+use crate::crypto::{self, Key};
+use crate::history;
+use crate::index::Index;
+use crate::note::{self, NoteRecord};
+use crate::storage::{self, StorageBackend};
+use anyhow::{anyhow, Context};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+mod attachments;
+mod check;
+mod chunks;
+mod content;
+mod drafts;
+mod folders;
+mod lock;
+mod replace;
+mod search;
+mod stats;
+mod suggest;
+mod tags;
+mod watch;
+
+use chunks::ChunkRefs;
+pub use lock::VaultBusy;
+
+const NOTES_PREFIX: &str = "notes/";
+const NOTE_EXTENSION: &str = "note";
+const LEGACY_NOTE_EXTENSION: &str = "txt";
+const INDEX_FILE: &str = "index";
+const HISTORY_DIR: &str = "history";
+const SETTINGS_FILE: &str = "settings";
+
+static CURRENT: RwLock<Option<Vault>> = RwLock::new(None);
+
+/// A vault is the single store every note is kept in.
+///
+/// Dart hands us the platform directory from `path_provider` through
+/// `open_vault`, so there is exactly one rule for where notes live; the
+/// backend decides how they are laid out there.
+#[derive(Clone)]
+pub struct Vault {
+    storage: Arc<dyn StorageBackend>,
+    key: Key,
+    chunk_keys: ChunkKeys,
+}
+
+impl Vault {
+    pub fn open(kind: StorageKind, location: &Path) -> anyhow::Result<Self> {
+        let key = crypto::derive_key();
+        let vault = Vault {
+            storage: storage::open(kind, location)?,
+            chunk_keys: ChunkKeys::new(&key),
+            key,
+        };
+
+        let _guard = vault.lock_writes()?;
+        vault.migrate_legacy_notes()?;
+        vault.rebuild_unreadable_indexes()?;
+        Ok(vault)
+    }
+
+    /// Rebuilds the note index, chunk reference counts and search index if
+    /// they are missing or no longer decrypt.
+    fn rebuild_unreadable_indexes(&self) -> anyhow::Result<()> {
+        if self.load_index().is_err() {
+            println!("[Rust] Rebuilding note index for {}", self.location());
+            self.save_index(&self.rebuild_index()?)?;
+        }
+        if self.chunk_refs().is_err() {
+            println!("[Rust] Recounting chunk references for {}", self.location());
+            self.rebuild_chunk_refs()?;
+        }
+        if self.load_search_index().is_err() {
+            println!("[Rust] Rebuilding search index for {}", self.location());
+            self.rebuild_search_index()?;
+        }
+        Ok(())
+    }
+
+    /// The directory or database file the vault lives in.
+    pub fn location(&self) -> String {
+        self.storage.location()
+    }
+
+    fn note_key(&self, id: &str) -> anyhow::Result<String> {
+        let id = note::parse_id(id)?;
+        Ok(format!("{}{}.{}", NOTES_PREFIX, id, NOTE_EXTENSION))
+    }
+
+    pub fn read_note(&self, id: &str) -> anyhow::Result<Note> {
+        let _guard = self.lock_reads()?;
+        let record = self.read_note_record(id)?;
+        self.load_content(record)
+            .with_context(|| format!("failed to read the content of note {:?}", id))
+    }
+
+    /// The note file without its content loaded from the chunk store.
+    fn read_note_record(&self, id: &str) -> anyhow::Result<NoteRecord> {
+        let data = self
+            .storage
+            .read(&self.note_key(id)?)
+            .with_context(|| format!("failed to read note {:?}", id))?;
+        note::decode(&self.key, &data).with_context(|| format!("failed to decrypt note {:?}", id))
+    }
+
+    fn load_content(&self, record: NoteRecord) -> anyhow::Result<Note> {
+        let mut note = record.note;
+        if let Some(body) = &record.body {
+            note.content = String::from_utf8(self.read_blob(body)?).context("note content is not valid UTF-8")?;
+        }
+        Ok(note)
+    }
+
+    /// Stores `note` unless the copy on disk has moved past `note.revision`,
+    /// in which case nothing is written and both versions are returned.
+    /// `draft_id` names the draft of a new note, dropped once it is saved.
+    pub fn save_note(&self, note: Note, draft_id: &str) -> anyhow::Result<SaveResult> {
+        let _guard = self.lock_writes()?;
+        let mut note = note;
+        let draft_note_id = note.id.clone();
+        note.tags = note::normalize_tags(note.tags);
+        let now = note::now_millis();
+        if let Some(folder_id) = &note.folder_id {
+            self.folder_tree()?.get(folder_id)?;
+        }
+
+        if note.id.is_empty() {
+            note.id = note::new_id();
+            note.created_at = now;
+            note.revision = 0;
+        } else {
+            note.id = note::parse_id(&note.id)?;
+            let stored = self.read_note(&note.id)?;
+            if stored.revision != note.revision {
+                return Ok(SaveResult {
+                    saved: None,
+                    conflict: Some(SaveConflict {
+                        ours: note,
+                        theirs: stored,
+                    }),
+                });
+            }
+            note.created_at = stored.created_at;
+        }
+
+        note.revision += 1;
+        note.modified_at = now;
+        self.store_note(&note)?;
+        self.clear_draft(&draft_note_id, draft_id)?;
+        Ok(SaveResult {
+            saved: Some(note),
+            conflict: None,
+        })
+    }
+
+    /// Applies `change` to the stored note as one read-modify-write.
+    pub fn update_note(&self, id: &str, change: impl FnOnce(&mut Note)) -> anyhow::Result<Note> {
+        let _guard = self.lock_writes()?;
+        self.update_note_locked(id, change)
+    }
+
+    fn update_note_locked(&self, id: &str, change: impl FnOnce(&mut Note)) -> anyhow::Result<Note> {
+        let mut note = self.read_note(id)?;
+        change(&mut note);
+        note.tags = note::normalize_tags(note.tags);
+        note.revision += 1;
+        note.modified_at = note::now_millis();
+        self.store_note(&note)?;
+        Ok(note)
+    }
+
+    pub fn delete_note(&self, id: &str) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let id = note::parse_id(id)?;
+        self.remove_note_files(&id)?;
+
+        let mut index = self.load_index()?;
+        index.remove(&id);
+        self.save_index(&index)
+    }
+
+    /// Removes a note file together with its history and attachments, and
+    /// releases the chunks they held.
+    fn remove_note_files(&self, id: &str) -> anyhow::Result<()> {
+        let mut bodies = Vec::new();
+        if let Ok(record) = self.read_note_record(id) {
+            bodies.extend(record.body);
+        }
+        for revision in self.revision_numbers(id)? {
+            if let Ok(record) = self.read_revision_record(id, revision) {
+                bodies.extend(record.body);
+            }
+        }
+
+        let mut refs = self.chunk_refs()?;
+        self.storage
+            .delete(&self.note_key(id)?)
+            .with_context(|| format!("failed to delete note {:?}", id))?;
+        watch::note_written(id, None);
+        self.remove_note_attachments(id, &mut refs)?;
+        self.storage.delete_prefix(&self.history_prefix(id)?)?;
+        self.clear_draft(id, "")?;
+        self.unindex_note_text(id)?;
+
+        for body in &bodies {
+            refs.release(body);
+        }
+        self.save_chunk_refs(&mut refs)
+    }
+
+    pub fn list_notes(&self) -> anyhow::Result<Vec<NoteSummary>> {
+        let _guard = self.lock_reads()?;
+        let mut notes: Vec<NoteSummary> = self.load_index()?.notes.into_values().collect();
+        notes.sort_by_cached_key(|note| note.title.to_lowercase());
+        Ok(notes)
+    }
+
+    pub fn settings(&self) -> anyhow::Result<VaultSettings> {
+        let _guard = self.lock_reads()?;
+        match self.storage.read(SETTINGS_FILE) {
+            Ok(data) => crypto::open_json(&self.key, &data).context("vault settings are corrupted"),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(VaultSettings::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save_settings(&self, settings: &VaultSettings) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let data = crypto::seal_json(&self.key, settings)?;
+        self.storage
+            .write(SETTINGS_FILE, &data)
+            .context("failed to write vault settings")
+    }
+
+    /// Lists the kept revisions of a note, newest first.
+    pub fn list_revisions(&self, id: &str) -> anyhow::Result<Vec<RevisionInfo>> {
+        let _guard = self.lock_reads()?;
+        let mut revisions = Vec::new();
+        for revision in self.revision_numbers(id)? {
+            let note = self.read_revision_record(id, revision)?.note;
+            revisions.push(RevisionInfo {
+                revision,
+                modified_at: note.modified_at,
+                title: note.title,
+            });
+        }
+        Ok(revisions)
+    }
+
+    pub fn read_revision(&self, id: &str, revision: u32) -> anyhow::Result<Note> {
+        let _guard = self.lock_reads()?;
+        let record = self.read_revision_record(id, revision)?;
+        self.load_content(record)
+            .with_context(|| format!("failed to read the content of revision {} of {:?}", revision, id))
+    }
+
+    fn read_revision_record(&self, id: &str, revision: u32) -> anyhow::Result<NoteRecord> {
+        let data = self
+            .storage
+            .read(&self.revision_key(id, revision)?)
+            .with_context(|| format!("note {:?} has no revision {}", id, revision))?;
+        note::decode(&self.key, &data)
+            .with_context(|| format!("failed to decrypt revision {} of note {:?}", revision, id))
+    }
+
+    fn store_note(&self, note: &Note) -> anyhow::Result<()> {
+        let key = self.note_key(&note.id)?;
+        let replaced = self.read_note_record(&note.id).ok().and_then(|record| record.body);
+
+        let compression = self.settings()?.compression;
+        let compress = compression.enabled && note.content.len() >= compression.min_note_size as usize;
+
+        // One reference for the note file and one for its revision copy.
+        let mut refs = self.chunk_refs()?;
+        let body = self.put_blob(note.content.as_bytes(), compress, &mut refs)?;
+        refs.retain(&body);
+        self.save_chunk_refs(&mut refs)?;
+
+        let data = note::encode(&self.key, note, Some(&body))?;
+        self.storage
+            .write(&key, &data)
+            .with_context(|| format!("failed to write note {:?}", note.id))?;
+        watch::note_written(&note.id, Some((note.revision, note.modified_at)));
+
+        let mut index = self.load_index()?;
+        index.upsert(note);
+        self.save_index(&index)?;
+        self.index_note_text(note)?;
+
+        self.record_revision(note, &data, &mut refs)?;
+        if let Some(replaced) = &replaced {
+            refs.release(replaced);
+        }
+        self.save_chunk_refs(&mut refs)
+    }
+
+    /// Keeps a copy of every stored version, then drops the ones the
+    /// retention policy has no room for.
+    fn record_revision(&self, note: &Note, data: &[u8], refs: &mut ChunkRefs) -> anyhow::Result<()> {
+        self.storage
+            .write(&self.revision_key(&note.id, note.revision)?, data)
+            .with_context(|| format!("failed to record revision {} of {:?}", note.revision, note.id))?;
+
+        let policy = self.settings()?.retention;
+        let mut revisions = Vec::new();
+        let mut records = BTreeMap::new();
+        for revision in self.revision_numbers(&note.id)? {
+            let record = self.read_revision_record(&note.id, revision).ok();
+            let modified_at = record.as_ref().map(|record| record.note.modified_at);
+            revisions.push((revision, modified_at.unwrap_or_default()));
+            records.insert(revision, record);
+        }
+        for revision in history::revisions_to_prune(&revisions, &policy, note::now_millis()) {
+            self.storage.delete(&self.revision_key(&note.id, revision)?)?;
+            if let Some(body) = records.remove(&revision).flatten().and_then(|record| record.body) {
+                refs.release(&body);
+            }
+        }
+        Ok(())
+    }
+
+    fn history_prefix(&self, id: &str) -> anyhow::Result<String> {
+        Ok(format!("{}/{}/", HISTORY_DIR, note::parse_id(id)?))
+    }
+
+    fn revision_key(&self, id: &str, revision: u32) -> anyhow::Result<String> {
+        Ok(self.history_prefix(id)? + &history::revision_file_name(revision))
+    }
+
+    /// Revision numbers stored for a note, newest first.
+    fn revision_numbers(&self, id: &str) -> anyhow::Result<Vec<u32>> {
+        let prefix = self.history_prefix(id)?;
+        let mut revisions: Vec<u32> = self
+            .storage
+            .list(&prefix)?
+            .iter()
+            .filter_map(|key| history::parse_revision_file_name(&key[prefix.len()..]))
+            .collect();
+        revisions.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(revisions)
+    }
+
+    fn load_index(&self) -> anyhow::Result<Index> {
+        let data = self.storage.read(INDEX_FILE)?;
+        Index::decode(&self.key, &data).context("note index is corrupted")
+    }
+
+    fn save_index(&self, index: &Index) -> anyhow::Result<()> {
+        let data = index.encode(&self.key)?;
+        self.storage
+            .write(INDEX_FILE, &data)
+            .context("failed to write note index")
+    }
+
+    /// Recreates the index from the note files themselves, skipping any that
+    /// no longer decrypt.
+    fn rebuild_index(&self) -> anyhow::Result<Index> {
+        let mut index = Index::default();
+        for id in self.note_ids()? {
+            match self.read_note(&id) {
+                Ok(note) => index.upsert(&note),
+                Err(e) => eprintln!("[Rust] Leaving note {:?} out of the index: {:#}", id, e),
+            }
+        }
+        Ok(index)
+    }
+
+    /// Moves `<title>.txt` notes from older builds to id-named files. Each
+    /// is stored like a saved note, so it is indexed and searchable, its
+    /// content goes to the chunk store and it starts a history.
+    fn migrate_legacy_notes(&self) -> anyhow::Result<()> {
+        let legacy = self.note_names(LEGACY_NOTE_EXTENSION)?;
+        if legacy.is_empty() {
+            return Ok(());
+        }
+
+        // `store_note` updates the indexes, so they must load first.
+        self.rebuild_unreadable_indexes()?;
+        for title in legacy {
+            let key = format!("{}{}.{}", NOTES_PREFIX, title, LEGACY_NOTE_EXTENSION);
+            let modified_at = self
+                .storage
+                .metadata(&key)
+                .map(|meta| meta.modified_at)
+                .unwrap_or_default();
+
+            let mut note = match self
+                .storage
+                .read(&key)
+                .map_err(anyhow::Error::from)
+                .and_then(|data| note::decode_legacy(&self.key, &title, &data, modified_at))
+            {
+                Ok(note) => note,
+                Err(e) => {
+                    eprintln!("[Rust] Cannot migrate legacy note {:?}: {:#}", key, e);
+                    continue;
+                }
+            };
+
+            note.tags = note::normalize_tags(note.tags);
+            note.revision = note.revision.max(1);
+            self.store_note(&note)?;
+            self.storage.delete(&key)?;
+            println!("[Rust] Migrated legacy note {:?} to {}", title, note.id);
+        }
+        Ok(())
+    }
+
+    /// The chunk store bodies of every note and revision.
+    fn note_blobs(&self, strict: bool) -> anyhow::Result<Vec<Blob>> {
+        let mut blobs = Vec::new();
+        let mut add = |record: anyhow::Result<NoteRecord>, what: String| -> anyhow::Result<()> {
+            match record {
+                Ok(record) => blobs.extend(record.body),
+                Err(e) if strict => return Err(e.context(format!("cannot account for the chunks of {}", what))),
+                Err(e) => eprintln!("[Rust] Not counting the chunks of {}: {:#}", what, e),
+            }
+            Ok(())
+        };
+        for id in self.note_ids()? {
+            add(self.read_note_record(&id), format!("note {:?}", id))?;
+            for revision in self.revision_numbers(&id)? {
+                add(
+                    self.read_revision_record(&id, revision),
+                    format!("revision {} of {:?}", revision, id),
+                )?;
+            }
+        }
+        Ok(blobs)
+    }
+
+    /// Ids of the stored notes. Other `.note` files, such as copies a sync
+    /// tool made, are left out.
+    fn note_ids(&self) -> anyhow::Result<Vec<String>> {
+        let mut ids = self.note_names(NOTE_EXTENSION)?;
+        ids.retain(|id| note::parse_id(id).is_ok());
+        Ok(ids)
+    }
+
+    /// Names of the entries in `notes/` with the given extension, without
+    /// the extension: ids for notes, titles for legacy ones.
+    fn note_names(&self, extension: &str) -> anyhow::Result<Vec<String>> {
+        let suffix = format!(".{}", extension);
+        Ok(self
+            .storage
+            .list(NOTES_PREFIX)?
+            .iter()
+            .filter_map(|key| key[NOTES_PREFIX.len()..].strip_suffix(&suffix))
+            .filter(|name| !name.contains('/'))
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// Makes `vault` the one every note operation goes to.
+pub fn set_current(vault: Vault) {
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = Some(vault);
+}
+
+pub fn current() -> anyhow::Result<Vault> {
+    CURRENT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .ok_or_else(|| anyhow!("no vault is open, call open_vault first"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn legacy_notes_are_migrated_into_search_and_history() {
+        let dir = std::env::temp_dir().join(format!("legacy-{:016x}", rand::random::<u64>()));
+        fs::create_dir_all(dir.join(NOTES_PREFIX)).unwrap();
+        fs::write(
+            dir.join(NOTES_PREFIX).join("Groceries.txt"),
+            crypto::xor_encrypt_decrypt(b"buy apples"),
+        )
+        .unwrap();
+
+        let vault = Vault::open(StorageKind::Directory, &dir).unwrap();
+        let ids = vault.note_ids().unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(vault.note_names(LEGACY_NOTE_EXTENSION).unwrap().is_empty());
+
+        let note = vault.read_note(&ids[0]).unwrap();
+        assert_eq!(note.title, "Groceries");
+        assert_eq!(note.content, "buy apples");
+        assert!(vault.read_note_record(&ids[0]).unwrap().body.is_some());
+        assert_eq!(vault.revision_numbers(&ids[0]).unwrap(), [note.revision]);
+        assert!(vault
+            .load_search_index()
+            .unwrap()
+            .note_ids()
+            .any(|id| *id == ids[0]));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,241 @@
+use super::{ChunkRefs, Vault};
+use crate::api::Attachment;
+use crate::atomic::AtomicFile;
+use crate::chunks::Blob;
+use crate::crypto;
+use crate::note;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+
+pub(super) const ATTACHMENTS_PREFIX: &str = "attachments/";
+pub(super) const META_EXTENSION: &str = "meta";
+
+/// What an attachment's `.meta` file holds. It is stored under its note's
+/// id, so that a note's attachments can be found and deleted without
+/// decrypting anything.
+#[derive(Serialize, Deserialize)]
+pub(super) struct AttachmentRecord {
+    #[serde(flatten)]
+    pub(super) attachment: Attachment,
+    pub(super) blob: Blob,
+}
+
+impl Vault {
+    /// Encrypts the file at `source` into the vault and links it to a note.
+    /// The file is chunked and sealed into a temp file before the vault is
+    /// locked, so a large file neither has to fit in memory nor holds up
+    /// other writers, and chunks the vault already holds are not stored
+    /// twice.
+    pub fn add_attachment(
+        &self,
+        note_id: &str,
+        source: &Path,
+        file_name: String,
+        mime_type: String,
+    ) -> anyhow::Result<Attachment> {
+        let note_id = note::parse_id(note_id)?;
+        if file_name.trim().is_empty() {
+            bail!("attachment file name cannot be empty");
+        }
+        let input = BufReader::new(
+            fs::File::open(source).with_context(|| format!("failed to open {:?}", source))?,
+        );
+
+        let staged = self
+            .stage_blob(input, false)
+            .with_context(|| format!("failed to read {:?}", source))?;
+
+        // Taken only to store the staged chunks: chunks the new blob shares
+        // with existing ones must not be freed before its references are
+        // counted.
+        let _guard = self.lock_writes()?;
+        if !self.storage.exists(&self.note_key(&note_id)?)? {
+            bail!("note {:?} does not exist", note_id);
+        }
+        let mut refs = self.chunk_refs()?;
+        let blob = self
+            .store_staged_blob(staged, &mut refs)
+            .with_context(|| format!("failed to store {:?}", source))?;
+        self.save_chunk_refs(&mut refs)?;
+
+        let attachment = Attachment {
+            id: note::new_id(),
+            note_id,
+            file_name,
+            mime_type,
+            size: blob.size,
+            created_at: note::now_millis(),
+        };
+        self.save_attachment_record(&AttachmentRecord {
+            attachment: attachment.clone(),
+            blob,
+        })?;
+        Ok(attachment)
+    }
+
+    /// Attachments of a note, oldest first.
+    pub fn list_attachments(&self, note_id: &str) -> anyhow::Result<Vec<Attachment>> {
+        let _guard = self.lock_reads()?;
+        let note_id = note::parse_id(note_id)?;
+        let mut attachments: Vec<Attachment> = self
+            .attachment_records(&self.attachment_prefix(&note_id)?)?
+            .into_iter()
+            .map(|record| record.attachment)
+            .collect();
+        attachments.sort_by_key(|attachment| attachment.created_at);
+        Ok(attachments)
+    }
+
+    /// Decrypts up to `length` bytes starting at `offset`. Only the chunks
+    /// covering the range are read from disk.
+    pub fn read_attachment_range(
+        &self,
+        id: &str,
+        offset: u64,
+        length: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let _guard = self.lock_reads()?;
+        let blob = self.attachment_blob(id)?;
+        self.read_blob_range(&blob, offset, length)
+    }
+
+    /// Decrypts an attachment into a plain file at `destination`.
+    pub fn export_attachment(&self, id: &str, destination: &Path) -> anyhow::Result<u64> {
+        let _guard = self.lock_reads()?;
+        let blob = self.attachment_blob(id)?;
+        let mut output = BufWriter::new(AtomicFile::create(destination)?);
+        self.write_blob(&blob, &mut output)?;
+        output.into_inner().map_err(|e| e.into_error())?.commit()?;
+        Ok(blob.size)
+    }
+
+    pub fn delete_attachment(&self, id: &str) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let key = self.attachment_key(id)?;
+        let record = self.read_attachment_record(&key)?;
+        let mut refs = self.chunk_refs()?;
+        // The metadata goes first: without it the contents are unreachable,
+        // so a crash in between never leaves a listed attachment without
+        // them.
+        self.delete_attachment_record(&key)?;
+        refs.release(&record.blob);
+        self.save_chunk_refs(&mut refs)
+    }
+
+    /// Removes every attachment linked to `note_id`, releasing its chunks in
+    /// `refs`. Called with the write lock held, as part of deleting the note.
+    /// Records that cannot be decrypted are deleted too; their chunks are
+    /// not counted by a rebuild, so garbage collection frees them.
+    pub(super) fn remove_note_attachments(
+        &self,
+        note_id: &str,
+        refs: &mut ChunkRefs,
+    ) -> anyhow::Result<()> {
+        for key in self.attachment_keys(&self.attachment_prefix(note_id)?)? {
+            match self.read_attachment_record(&key) {
+                Ok(record) => refs.release(&record.blob),
+                Err(e) => eprintln!(
+                    "[Rust] Deleting attachment {:?} without releasing its chunks: {:#}",
+                    key, e
+                ),
+            }
+            self.delete_attachment_record(&key)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn attachment_blobs(&self, strict: bool) -> anyhow::Result<Vec<Blob>> {
+        let mut blobs = Vec::new();
+        for key in self.attachment_keys(ATTACHMENTS_PREFIX)? {
+            match self.read_attachment_record(&key) {
+                Ok(record) => blobs.push(record.blob),
+                Err(e) if strict => {
+                    return Err(e.context("cannot account for the chunks of an attachment"))
+                }
+                Err(e) => eprintln!(
+                    "[Rust] Not counting the chunks of attachment {:?}: {:#}",
+                    key, e
+                ),
+            }
+        }
+        Ok(blobs)
+    }
+
+    fn delete_attachment_record(&self, key: &str) -> anyhow::Result<()> {
+        match self.storage.delete(key) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(super) fn read_attachment_record(&self, key: &str) -> anyhow::Result<AttachmentRecord> {
+        let data = self
+            .storage
+            .read(key)
+            .with_context(|| format!("failed to read {:?}", key))?;
+        crypto::open_json(&self.key, &data)
+            .with_context(|| format!("failed to decrypt attachment {:?}", key))
+    }
+
+    fn attachment_blob(&self, id: &str) -> anyhow::Result<Blob> {
+        Ok(self.read_attachment_record(&self.attachment_key(id)?)?.blob)
+    }
+
+    /// The attachments whose keys start with `prefix`, skipping any that
+    /// cannot be decrypted.
+    pub(super) fn attachment_records(&self, prefix: &str) -> anyhow::Result<Vec<AttachmentRecord>> {
+        let mut records = Vec::new();
+        for key in self.attachment_keys(prefix)? {
+            match self.read_attachment_record(&key) {
+                Ok(record) => records.push(record),
+                Err(e) => eprintln!("[Rust] Skipping attachment {:?}: {:#}", key, e),
+            }
+        }
+        Ok(records)
+    }
+
+    fn save_attachment_record(&self, record: &AttachmentRecord) -> anyhow::Result<()> {
+        let attachment = &record.attachment;
+        let key = format!(
+            "{}{}.{}",
+            self.attachment_prefix(&attachment.note_id)?,
+            note::parse_id(&attachment.id)?,
+            META_EXTENSION
+        );
+        let data = crypto::seal_json(&self.key, record)?;
+        self.storage
+            .write(&key, &data)
+            .context("failed to write attachment metadata")
+    }
+
+    fn attachment_keys(&self, prefix: &str) -> anyhow::Result<Vec<String>> {
+        let suffix = format!(".{}", META_EXTENSION);
+        Ok(self
+            .storage
+            .list(prefix)?
+            .into_iter()
+            .filter(|key| key.ends_with(&suffix))
+            .collect())
+    }
+
+    /// Where the attachment `id` is stored, whichever note it belongs to.
+    fn attachment_key(&self, id: &str) -> anyhow::Result<String> {
+        let id = note::parse_id(id)?;
+        let name = format!("/{}.{}", id, META_EXTENSION);
+        self.attachment_keys(ATTACHMENTS_PREFIX)?
+            .into_iter()
+            .find(|key| key.ends_with(&name))
+            .with_context(|| format!("attachment {:?} does not exist", id))
+    }
+
+    pub(super) fn attachment_prefix(&self, note_id: &str) -> anyhow::Result<String> {
+        Ok(format!(
+            "{}{}/",
+            ATTACHMENTS_PREFIX,
+            note::parse_id(note_id)?
+        ))
+    }
+}
@@ -0,0 +1,608 @@
+//! Integrity checks and repair.
+//!
+//! A check reads every entry the backend lists and sorts it by what its key
+//! says it is, so files the vault did not write are reported instead of
+//! skipped. Chunks are verified once up front; every record is then checked
+//! against the set of intact chunks without decrypting its content again.
+
+use super::attachments::{ATTACHMENTS_PREFIX, META_EXTENSION};
+use super::chunks::{ChunkRefs, CHUNKS_PREFIX, CHUNK_REFS_FILE};
+use super::drafts::DRAFTS_PREFIX;
+use super::folders::FOLDERS_FILE;
+use super::replace::REPLACE_JOURNAL_FILE;
+use super::search::SEARCH_INDEX_FILE;
+use super::suggest::USAGE_FILE;
+use super::{
+    Vault, HISTORY_DIR, INDEX_FILE, LEGACY_NOTE_EXTENSION, NOTES_PREFIX, NOTE_EXTENSION,
+    SETTINGS_FILE,
+};
+use crate::api::{RepairOptions, RepairReport, VaultCheckReport, VaultProblem, VaultProblemKind};
+use crate::chunks::Blob;
+use crate::index::Index;
+use crate::{crypto, history, note};
+use std::collections::{BTreeSet, HashSet};
+
+pub(super) const QUARANTINE_DIR: &str = "quarantine";
+
+/// What a storage key names.
+pub(super) enum Entry {
+    Note(String),
+    Revision(String, u32),
+    /// The note it belongs to, and its own id.
+    Attachment(String, String),
+    Chunk(String),
+    /// A single sealed file such as the settings or a draft.
+    Sealed,
+    /// Rebuilt from the records, and compared against them separately.
+    Derived,
+    Quarantined,
+    Unknown(&'static str),
+}
+
+impl Entry {
+    pub(super) fn of(key: &str) -> Entry {
+        if key.starts_with(&format!("{}/", QUARANTINE_DIR)) {
+            return Entry::Quarantined;
+        }
+        if key == SETTINGS_FILE
+            || key == FOLDERS_FILE
+            || key == REPLACE_JOURNAL_FILE
+            || key == USAGE_FILE
+            || key.starts_with(DRAFTS_PREFIX)
+        {
+            return Entry::Sealed;
+        }
+        if key == INDEX_FILE || key == SEARCH_INDEX_FILE || key == CHUNK_REFS_FILE {
+            return Entry::Derived;
+        }
+        if let Some(name) = key.strip_prefix(NOTES_PREFIX) {
+            if let Some(id) = name
+                .strip_suffix(&format!(".{}", NOTE_EXTENSION))
+                .and_then(canonical_id)
+            {
+                return Entry::Note(id);
+            }
+            if name.ends_with(&format!(".{}", LEGACY_NOTE_EXTENSION)) {
+                return Entry::Unknown("legacy note that could not be migrated");
+            }
+            return Entry::Unknown("not a note, possibly a sync tool's conflict copy");
+        }
+        if let Some(rest) = key.strip_prefix(&format!("{}/", HISTORY_DIR)) {
+            if let Some((id, name)) = rest.split_once('/') {
+                if let (Some(id), Some(revision)) =
+                    (canonical_id(id), history::parse_revision_file_name(name))
+                {
+                    return Entry::Revision(id, revision);
+                }
+            }
+            return Entry::Unknown("not a revision");
+        }
+        if let Some(rest) = key.strip_prefix(ATTACHMENTS_PREFIX) {
+            if let Some((note_id, name)) = rest.split_once('/') {
+                if let (Some(note_id), Some(id)) = (
+                    canonical_id(note_id),
+                    name.strip_suffix(&format!(".{}", META_EXTENSION))
+                        .and_then(canonical_id),
+                ) {
+                    return Entry::Attachment(note_id, id);
+                }
+            }
+            return Entry::Unknown("not an attachment");
+        }
+        if key.starts_with(CHUNKS_PREFIX) {
+            return match key.rsplit('/').next() {
+                Some(id) if super::chunks::is_chunk_id(id) => Entry::Chunk(id.to_string()),
+                _ => Entry::Unknown("not a chunk"),
+            };
+        }
+        Entry::Unknown("not a vault file")
+    }
+}
+
+/// `id` if it is a note id written the way the vault writes them.
+fn canonical_id(id: &str) -> Option<String> {
+    note::parse_id(id).ok().filter(|parsed| parsed == id)
+}
+
+/// Collects problems while the entries are walked, along with what the
+/// index and chunk counts should say.
+struct Checker {
+    report: VaultCheckReport,
+    intact_chunks: HashSet<String>,
+    index: Index,
+    refs: ChunkRefs,
+}
+
+impl Checker {
+    fn problem(
+        &mut self,
+        kind: VaultProblemKind,
+        key: &str,
+        note_id: Option<&str>,
+        detail: String,
+    ) {
+        self.report.problems.push(VaultProblem {
+            kind,
+            key: key.to_string(),
+            note_id: note_id.map(str::to_string),
+            detail,
+        });
+    }
+
+    /// Counts the references a readable record holds and reports whether its
+    /// content is all there.
+    fn check_blob(&mut self, key: &str, note_id: &str, blob: Option<&Blob>) -> bool {
+        let blob = match blob {
+            Some(blob) => blob,
+            None => return true,
+        };
+        self.refs.retain(blob);
+        let missing = blob
+            .chunks
+            .iter()
+            .filter(|chunk| !self.intact_chunks.contains(&chunk.id))
+            .count();
+        if missing > 0 {
+            self.problem(
+                VaultProblemKind::MissingContent,
+                key,
+                Some(note_id),
+                format!(
+                    "{} of {} chunk(s) are missing or damaged",
+                    missing,
+                    blob.chunks.len()
+                ),
+            );
+        }
+        missing == 0
+    }
+}
+
+impl Vault {
+    /// Reads every file in the vault and reports what is wrong with it.
+    pub fn check(&self) -> anyhow::Result<VaultCheckReport> {
+        let _guard = self.lock_reads()?;
+        let mut keys = self.storage.list("")?;
+        keys.sort();
+        let entries: Vec<(String, Entry)> = keys
+            .into_iter()
+            .map(|key| {
+                let entry = Entry::of(&key);
+                (key, entry)
+            })
+            .filter(|(_, entry)| !matches!(entry, Entry::Quarantined))
+            .collect();
+
+        let mut checker = Checker {
+            report: VaultCheckReport {
+                files_checked: entries.len() as u32,
+                problems: Vec::new(),
+            },
+            intact_chunks: HashSet::new(),
+            index: Index::default(),
+            refs: ChunkRefs::default(),
+        };
+        for (key, entry) in &entries {
+            if let Entry::Chunk(id) = entry {
+                match self.verify_chunk(key, id) {
+                    Ok(()) => {
+                        checker.intact_chunks.insert(id.clone());
+                    }
+                    Err(e) => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        None,
+                        format!("{:#}", e),
+                    ),
+                }
+            }
+        }
+        let note_files: BTreeSet<&str> = entries
+            .iter()
+            .filter_map(|(_, entry)| match entry {
+                Entry::Note(id) => Some(id.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        for (key, entry) in &entries {
+            match entry {
+                Entry::Note(id) => match self.read_note_record(id) {
+                    Ok(record) if record.note.id != *id => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        Some(id),
+                        format!("holds note {:?}", record.note.id),
+                    ),
+                    Ok(record) => {
+                        if checker.check_blob(key, id, record.body.as_ref()) {
+                            checker.index.upsert(&record.note);
+                        }
+                    }
+                    Err(e) => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        Some(id),
+                        format!("{:#}", e),
+                    ),
+                },
+                Entry::Revision(id, revision) => match self.read_revision_record(id, *revision) {
+                    Ok(record) if record.note.id != *id => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        Some(id),
+                        format!("holds a revision of note {:?}", record.note.id),
+                    ),
+                    Ok(record) => {
+                        checker.check_blob(key, id, record.body.as_ref());
+                        if !note_files.contains(id.as_str()) {
+                            checker.problem(
+                                VaultProblemKind::OrphanedRevision,
+                                key,
+                                Some(id),
+                                "its note no longer exists".to_string(),
+                            );
+                        }
+                    }
+                    Err(e) => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        Some(id),
+                        format!("{:#}", e),
+                    ),
+                },
+                Entry::Attachment(note_id, id) => match self.read_attachment_record(key) {
+                    Ok(record)
+                        if record.attachment.id != *id || record.attachment.note_id != *note_id =>
+                    {
+                        checker.problem(
+                            VaultProblemKind::CorruptFile,
+                            key,
+                            Some(note_id),
+                            format!(
+                                "holds attachment {:?} of note {:?}",
+                                record.attachment.id, record.attachment.note_id
+                            ),
+                        )
+                    }
+                    Ok(record) => {
+                        checker.check_blob(key, note_id, Some(&record.blob));
+                        if !note_files.contains(note_id.as_str()) {
+                            checker.problem(
+                                VaultProblemKind::OrphanedAttachment,
+                                key,
+                                Some(note_id),
+                                "its note no longer exists".to_string(),
+                            );
+                        }
+                    }
+                    Err(e) => checker.problem(
+                        VaultProblemKind::CorruptFile,
+                        key,
+                        Some(note_id),
+                        format!("{:#}", e),
+                    ),
+                },
+                Entry::Sealed => {
+                    if let Err(e) = self
+                        .storage
+                        .read(key)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|data| crypto::open(&self.key, &data))
+                    {
+                        checker.problem(
+                            VaultProblemKind::CorruptFile,
+                            key,
+                            None,
+                            format!("{:#}", e),
+                        );
+                    }
+                }
+                Entry::Unknown(what) => {
+                    checker.problem(VaultProblemKind::UnknownFile, key, None, what.to_string())
+                }
+                Entry::Chunk(_) | Entry::Derived | Entry::Quarantined => {}
+            }
+        }
+
+        for (key, entry) in &entries {
+            if let Entry::Chunk(id) = entry {
+                if checker.intact_chunks.contains(id) && !checker.refs.uses(id) {
+                    checker.problem(
+                        VaultProblemKind::OrphanedChunk,
+                        key,
+                        None,
+                        "no record uses it".to_string(),
+                    );
+                }
+            }
+        }
+        self.check_index(&mut checker);
+        self.check_search_index(&mut checker);
+        match self.chunk_refs() {
+            Ok(refs) if refs == checker.refs => {}
+            Ok(_) => checker.problem(
+                VaultProblemKind::ChunkRefsMismatch,
+                CHUNK_REFS_FILE,
+                None,
+                "the counts differ from the records".to_string(),
+            ),
+            Err(e) => checker.problem(
+                VaultProblemKind::ChunkRefsMismatch,
+                CHUNK_REFS_FILE,
+                None,
+                format!("{:#}", e),
+            ),
+        }
+        for key in self.storage.temp_files()? {
+            checker.problem(
+                VaultProblemKind::TempFile,
+                &key,
+                None,
+                "an interrupted write".to_string(),
+            );
+        }
+        Ok(checker.report)
+    }
+
+    fn check_index(&self, checker: &mut Checker) {
+        let index = match self.load_index() {
+            Ok(index) => index,
+            Err(e) => {
+                checker.problem(
+                    VaultProblemKind::IndexMismatch,
+                    INDEX_FILE,
+                    None,
+                    format!("{:#}", e),
+                );
+                return;
+            }
+        };
+        let mut problems = Vec::new();
+        for (id, expected) in &checker.index.notes {
+            match index.notes.get(id) {
+                Some(listed) if listed == expected => {}
+                Some(_) => problems.push((id, "the index entry is out of date")),
+                None => problems.push((id, "the note is missing from the index")),
+            }
+        }
+        for id in index.notes.keys() {
+            if !checker.index.notes.contains_key(id) {
+                problems.push((id, "the index lists a note that cannot be read"));
+            }
+        }
+        for (id, detail) in problems {
+            checker.report.problems.push(VaultProblem {
+                kind: VaultProblemKind::IndexMismatch,
+                key: INDEX_FILE.to_string(),
+                note_id: Some(id.clone()),
+                detail: detail.to_string(),
+            });
+        }
+        if index.tags != checker.index.tags {
+            checker.problem(
+                VaultProblemKind::IndexMismatch,
+                INDEX_FILE,
+                None,
+                "the tag index differs from the notes".to_string(),
+            );
+        }
+    }
+
+    /// Only checks which notes the search index covers; comparing its terms
+    /// would mean decrypting every note's content again.
+    fn check_search_index(&self, checker: &mut Checker) {
+        let detail = match self.load_search_index() {
+            Ok(index) if index.note_ids().eq(checker.index.notes.keys()) => return,
+            Ok(_) => "the indexed notes differ from the notes".to_string(),
+            Err(e) => format!("{:#}", e),
+        };
+        checker.problem(VaultProblemKind::IndexMismatch, SEARCH_INDEX_FILE, None, detail);
+    }
+
+    fn verify_chunk(&self, key: &str, id: &str) -> anyhow::Result<()> {
+        let plaintext = crypto::open(&self.key, &self.storage.read(key)?)?;
+        if self.chunk_keys.chunk_id(&plaintext) != id {
+            anyhow::bail!("the contents do not match the chunk id");
+        }
+        Ok(())
+    }
+
+    /// Quarantines every damaged file, restoring damaged notes from their
+    /// history where it allows, and rebuilds the index and chunk counts from
+    /// what survives. A note that cannot be restored is quarantined with its
+    /// history and attachments, and every quarantined record with the chunks
+    /// only it uses.
+    ///
+    /// Refuses to run when no note decrypts, which points at a changed key
+    /// rather than damage, and would otherwise quarantine every record.
+    pub fn repair(&self, options: &RepairOptions) -> anyhow::Result<RepairReport> {
+        let _guard = self.lock_writes()?;
+        let note_ids = self.note_ids()?;
+        if !note_ids.is_empty() && !note_ids.iter().any(|id| self.read_note_record(id).is_ok()) {
+            anyhow::bail!(
+                "no note in the vault can be decrypted, which usually means the vault key \
+                 changed; nothing was repaired"
+            );
+        }
+        let mut report = RepairReport {
+            quarantine_dir: format!("{}/{}/", QUARANTINE_DIR, note::now_millis()),
+            ..RepairReport::default()
+        };
+
+        let mut quarantined_blobs = Vec::new();
+        for problem in self.check()?.problems {
+            if !matches!(
+                problem.kind,
+                VaultProblemKind::CorruptFile | VaultProblemKind::MissingContent
+            ) {
+                continue;
+            }
+            // Already moved along with its note.
+            if !self.storage.exists(&problem.key)? {
+                continue;
+            }
+            let entry = Entry::of(&problem.key);
+            quarantined_blobs.extend(match &entry {
+                Entry::Note(id) => self.read_note_record(id).ok().and_then(|r| r.body),
+                Entry::Revision(id, revision) => self
+                    .read_revision_record(id, *revision)
+                    .ok()
+                    .and_then(|r| r.body),
+                Entry::Attachment(..) => self
+                    .read_attachment_record(&problem.key)
+                    .ok()
+                    .map(|r| r.blob),
+                _ => None,
+            });
+            self.quarantine(&problem.key, &report.quarantine_dir)?;
+            println!("[Rust] Quarantined {}: {}", problem.key, problem.detail);
+            report.quarantined.push(problem.key);
+            if let Entry::Note(id) = entry {
+                if self.restore_from_history(&id)? {
+                    report.restored_notes.push(id);
+                } else {
+                    quarantined_blobs.extend(self.quarantine_note_files(&id, &mut report)?);
+                }
+            }
+        }
+        self.quarantine_chunks(&quarantined_blobs, &mut report)?;
+
+        // Removing a revision or attachment can orphan its chunks, so look
+        // again until nothing is left.
+        if options.remove_orphans {
+            loop {
+                let orphans = self.orphans()?;
+                if orphans.is_empty() {
+                    break;
+                }
+                for key in orphans {
+                    self.storage.delete(&key)?;
+                    report.removed.push(key);
+                }
+            }
+        }
+        if options.remove_temp_files {
+            let temp_files = self.storage.temp_files()?;
+            self.storage.remove_temp_files()?;
+            report.removed.extend(temp_files);
+        }
+
+        self.save_index(&self.rebuild_index()?)?;
+        self.rebuild_search_index()?;
+        self.rebuild_chunk_refs()?;
+        self.unfile_lost_folders()?;
+        report.remaining = self.check()?.problems;
+        Ok(report)
+    }
+
+    fn orphans(&self) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .check()?
+            .problems
+            .into_iter()
+            .filter(|problem| {
+                matches!(
+                    problem.kind,
+                    VaultProblemKind::OrphanedRevision
+                        | VaultProblemKind::OrphanedAttachment
+                        | VaultProblemKind::OrphanedChunk
+                )
+            })
+            .map(|problem| problem.key)
+            .collect())
+    }
+
+    /// Moves the revisions and attachments of note `id` into quarantine,
+    /// returning the blobs of those that decrypt.
+    fn quarantine_note_files(
+        &self,
+        id: &str,
+        report: &mut RepairReport,
+    ) -> anyhow::Result<Vec<Blob>> {
+        let mut blobs = Vec::new();
+        for revision in self.revision_numbers(id)? {
+            if let Ok(record) = self.read_revision_record(id, revision) {
+                blobs.extend(record.body);
+            }
+        }
+        for record in self.attachment_records(&self.attachment_prefix(id)?)? {
+            blobs.push(record.blob);
+        }
+        let mut keys = self.storage.list(&self.history_prefix(id)?)?;
+        keys.extend(self.storage.list(&self.attachment_prefix(id)?)?);
+        for key in keys {
+            self.quarantine(&key, &report.quarantine_dir)?;
+            report.quarantined.push(key);
+        }
+        Ok(blobs)
+    }
+
+    /// Moves the chunks of `blobs` that no stored record uses into
+    /// quarantine, so that the records quarantined with them can still be
+    /// read back.
+    fn quarantine_chunks(&self, blobs: &[Blob], report: &mut RepairReport) -> anyhow::Result<()> {
+        if blobs.is_empty() {
+            return Ok(());
+        }
+        let live: HashSet<String> = self
+            .stored_blobs(false)?
+            .into_iter()
+            .flat_map(|blob| blob.chunks)
+            .map(|chunk| chunk.id)
+            .collect();
+        let mut moved = HashSet::new();
+        for chunk in blobs.iter().flat_map(|blob| &blob.chunks) {
+            if live.contains(&chunk.id) || !moved.insert(chunk.id.as_str()) {
+                continue;
+            }
+            let key = self.chunk_key(&chunk.id)?;
+            if self.storage.exists(&key)? {
+                self.quarantine(&key, &report.quarantine_dir)?;
+                report.quarantined.push(key);
+            }
+        }
+        Ok(())
+    }
+
+    fn quarantine(&self, key: &str, dir: &str) -> anyhow::Result<()> {
+        let data = self.storage.read(key)?;
+        self.storage.write(&format!("{}{}", dir, key), &data)?;
+        self.storage.delete(key)?;
+        Ok(())
+    }
+
+    /// Puts the newest revision of note `id` that is fully readable back in
+    /// place of the note file.
+    fn restore_from_history(&self, id: &str) -> anyhow::Result<bool> {
+        for revision in self.revision_numbers(id)? {
+            let readable = match self.read_revision_record(id, revision) {
+                Ok(record) => record.note.id == id && self.load_content(record).is_ok(),
+                Err(_) => false,
+            };
+            if readable {
+                let data = self.storage.read(&self.revision_key(id, revision)?)?;
+                self.storage.write(&self.note_key(id)?, &data)?;
+                println!("[Rust] Restored note {} from revision {}", id, revision);
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Moves notes whose folder was lost with a quarantined folder tree to
+    /// the top level, where they can be saved again.
+    fn unfile_lost_folders(&self) -> anyhow::Result<()> {
+        let tree = self.folder_tree()?;
+        for summary in self.load_index()?.notes.into_values() {
+            let lost = summary
+                .folder_id
+                .as_ref()
+                .is_some_and(|folder_id| !tree.folders.contains_key(folder_id));
+            if lost {
+                self.update_note_locked(&summary.id, |note| note.folder_id = None)?;
+            }
+        }
+        Ok(())
+    }
+}
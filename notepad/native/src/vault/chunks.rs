@@ -0,0 +1,535 @@
+use super::Vault;
+use crate::api::GcReport;
+use crate::atomic::TempFile;
+use crate::chunks::{self, Blob, ChunkRef};
+use crate::crypto;
+use anyhow::{bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+pub(super) const CHUNKS_PREFIX: &str = "chunks/";
+pub(super) const CHUNK_REFS_FILE: &str = "chunk-refs";
+
+/// A blob whose chunks are sealed but not yet stored.
+pub(super) struct StagedBlob {
+    blob: Blob,
+    /// Where each chunk's sealed bytes start in `staging`, and their length.
+    sealed: Vec<(u64, usize)>,
+    staging: Staging,
+}
+
+/// Where sealed chunks wait to be stored: a temp file in the storage's
+/// staging directory, or memory when the storage has none.
+enum Staging {
+    File(TempFile),
+    Memory(Vec<u8>),
+}
+
+impl Staging {
+    fn append(&mut self, data: &[u8]) -> std::io::Result<()> {
+        match self {
+            Staging::File(file) => file.file().write_all(data),
+            Staging::Memory(buffer) => {
+                buffer.extend_from_slice(data);
+                Ok(())
+            }
+        }
+    }
+
+    fn read_at(&mut self, offset: u64, len: usize) -> std::io::Result<Vec<u8>> {
+        match self {
+            Staging::File(file) => {
+                let mut data = vec![0; len];
+                let file = file.file();
+                file.seek(SeekFrom::Start(offset))?;
+                file.read_exact(&mut data)?;
+                Ok(data)
+            }
+            Staging::Memory(buffer) => Ok(buffer[offset as usize..][..len].to_vec()),
+        }
+    }
+}
+
+/// How many stored blobs use each chunk. A chunk is deleted as soon as its
+/// count drops to zero; `collect_garbage` recounts from scratch and sweeps
+/// whatever an interrupted write left unreferenced.
+///
+/// Counts are always raised before the record that needs them is written
+/// and lowered only after it is gone, so a crash can leave a count too high
+/// but never too low.
+#[derive(Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChunkRefs {
+    counts: BTreeMap<String, u32>,
+    #[serde(skip)]
+    freed: Vec<String>,
+}
+
+impl ChunkRefs {
+    pub fn retain(&mut self, blob: &Blob) {
+        for chunk in &blob.chunks {
+            *self.counts.entry(chunk.id.clone()).or_default() += 1;
+        }
+    }
+
+    pub fn uses(&self, id: &str) -> bool {
+        self.counts.contains_key(id)
+    }
+
+    pub fn release(&mut self, blob: &Blob) {
+        for chunk in &blob.chunks {
+            if let Some(count) = self.counts.get_mut(&chunk.id) {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(&chunk.id);
+                    self.freed.push(chunk.id.clone());
+                }
+            }
+        }
+    }
+}
+
+impl Vault {
+    pub(super) fn chunk_refs(&self) -> anyhow::Result<ChunkRefs> {
+        match self.storage.read(CHUNK_REFS_FILE) {
+            Ok(data) => {
+                crypto::open_json(&self.key, &data).context("chunk reference counts are corrupted")
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ChunkRefs::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Persists the counts, then deletes the chunks nothing uses any more.
+    pub(super) fn save_chunk_refs(&self, refs: &mut ChunkRefs) -> anyhow::Result<()> {
+        let data = crypto::seal_json(&self.key, refs)?;
+        self.storage
+            .write(CHUNK_REFS_FILE, &data)
+            .context("failed to write chunk reference counts")?;
+
+        for id in refs.freed.drain(..) {
+            if refs.counts.contains_key(&id) {
+                continue;
+            }
+            match self.storage.delete(&self.chunk_key(&id)?) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    eprintln!("[Rust] Failed to remove chunk {}: {}", id, e)
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Recounts references from every record in the vault. Used on open when
+    /// the counts file is unreadable; records that no longer decrypt are
+    /// skipped, since their chunks are lost to them either way.
+    pub(super) fn rebuild_chunk_refs(&self) -> anyhow::Result<()> {
+        let mut refs = ChunkRefs::default();
+        for blob in self.stored_blobs(false)? {
+            refs.retain(&blob);
+        }
+        self.save_chunk_refs(&mut refs)
+    }
+
+    /// Splits `reader` into chunks, stores the ones the vault does not have
+    /// yet and counts one reference to each. Must be called with the write
+    /// lock held, and `refs` saved before the blob is recorded anywhere.
+    ///
+    /// With `compress`, new chunks are compressed before they are sealed.
+    /// Chunks the vault already holds are kept the way they were stored.
+    pub(super) fn put_blob(
+        &self,
+        reader: impl Read,
+        compress: bool,
+        refs: &mut ChunkRefs,
+    ) -> anyhow::Result<Blob> {
+        let mut blob = Blob::default();
+        for chunk in self.chunk_keys.chunker(reader) {
+            let chunk = chunk?;
+            let id = self.chunk_keys.chunk_id(&chunk);
+            let key = self.chunk_key(&id)?;
+            if !self.storage.exists(&key)? {
+                let sealed = if compress {
+                    crypto::seal_compressed(&self.key, &chunk)
+                } else {
+                    crypto::seal(&self.key, &chunk)
+                };
+                self.storage
+                    .write(&key, &sealed)
+                    .context("failed to write chunk")?;
+            }
+            blob.size += chunk.len() as u64;
+            blob.chunks.push(ChunkRef {
+                id,
+                len: chunk.len() as u32,
+                lines: chunks::count_lines(&chunk),
+            });
+        }
+        refs.retain(&blob);
+        Ok(blob)
+    }
+
+    /// Chunks and seals `reader` into the storage's staging directory
+    /// without touching the vault, so the slow part of storing a large file
+    /// needs no lock. A staging file a crash leaves behind is removed with
+    /// the other temp files when the vault recovers.
+    pub(super) fn stage_blob(
+        &self,
+        reader: impl Read,
+        compress: bool,
+    ) -> anyhow::Result<StagedBlob> {
+        let mut staging = match self.storage.staging_dir() {
+            Some(dir) => fs::create_dir_all(&dir)
+                .and_then(|()| TempFile::create_in(&dir))
+                .with_context(|| format!("failed to create a staging file in {:?}", dir))
+                .map(Staging::File)?,
+            None => Staging::Memory(Vec::new()),
+        };
+        let mut blob = Blob::default();
+        let mut sealed = Vec::new();
+        let mut offset = 0;
+        for chunk in self.chunk_keys.chunker(reader) {
+            let chunk = chunk?;
+            let data = if compress {
+                crypto::seal_compressed(&self.key, &chunk)
+            } else {
+                crypto::seal(&self.key, &chunk)
+            };
+            staging
+                .append(&data)
+                .context("failed to write a staging file")?;
+            sealed.push((offset, data.len()));
+            offset += data.len() as u64;
+            blob.size += chunk.len() as u64;
+            blob.chunks.push(ChunkRef {
+                id: self.chunk_keys.chunk_id(&chunk),
+                len: chunk.len() as u32,
+                lines: chunks::count_lines(&chunk),
+            });
+        }
+        Ok(StagedBlob {
+            blob,
+            sealed,
+            staging,
+        })
+    }
+
+    /// Stores the chunks of `staged` the vault does not have yet and counts
+    /// one reference to each. The same rules as for `put_blob` apply.
+    pub(super) fn store_staged_blob(
+        &self,
+        mut staged: StagedBlob,
+        refs: &mut ChunkRefs,
+    ) -> anyhow::Result<Blob> {
+        for (chunk, &(offset, len)) in staged.blob.chunks.iter().zip(&staged.sealed) {
+            let key = self.chunk_key(&chunk.id)?;
+            if self.storage.exists(&key)? {
+                continue;
+            }
+            let data = staged
+                .staging
+                .read_at(offset, len)
+                .context("failed to read a staging file")?;
+            self.storage
+                .write(&key, &data)
+                .context("failed to write chunk")?;
+        }
+        refs.retain(&staged.blob);
+        Ok(staged.blob)
+    }
+
+    pub(super) fn read_blob(&self, blob: &Blob) -> anyhow::Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(blob.size as usize);
+        self.write_blob(blob, &mut data)?;
+        Ok(data)
+    }
+
+    pub(super) fn write_blob(&self, blob: &Blob, out: &mut impl Write) -> anyhow::Result<()> {
+        for chunk in &blob.chunks {
+            out.write_all(&self.read_chunk(chunk)?)?;
+        }
+        Ok(())
+    }
+
+    /// Reads `len` bytes from `offset`, clamped to the blob, decrypting only
+    /// the chunks that overlap the range.
+    pub(super) fn read_blob_range(
+        &self,
+        blob: &Blob,
+        offset: u64,
+        len: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let end = offset.saturating_add(len).min(blob.size);
+        let mut data = Vec::with_capacity(end.saturating_sub(offset) as usize);
+        for (start, chunk) in blob.chunks_in_range(offset, len) {
+            let plaintext = self.read_chunk(chunk)?;
+            let from = offset.saturating_sub(start) as usize;
+            let to = ((end - start) as usize).min(plaintext.len());
+            data.extend_from_slice(&plaintext[from..to]);
+        }
+        Ok(data)
+    }
+
+    pub(super) fn read_chunk(&self, chunk: &ChunkRef) -> anyhow::Result<Vec<u8>> {
+        let data = self
+            .storage
+            .read(&self.chunk_key(&chunk.id)?)
+            .with_context(|| format!("chunk {} is missing", chunk.id))?;
+        let plaintext = crypto::open(&self.key, &data)
+            .with_context(|| format!("failed to decrypt chunk {}", chunk.id))?;
+        // The envelope proves the vault wrote these bytes; the id proves they
+        // are the bytes this blob asked for.
+        if plaintext.len() != chunk.len as usize || self.chunk_keys.chunk_id(&plaintext) != chunk.id
+        {
+            bail!("chunk {} does not match its id", chunk.id);
+        }
+        Ok(plaintext)
+    }
+
+    /// Recounts every chunk reference and deletes the chunks nothing refers
+    /// to. Refuses to run while any record fails to decrypt, since the chunks
+    /// it points at cannot be told apart from garbage.
+    pub fn collect_garbage(&self) -> anyhow::Result<GcReport> {
+        let _guard = self.lock_writes()?;
+        let mut refs = ChunkRefs::default();
+        for blob in self.stored_blobs(true)? {
+            refs.retain(&blob);
+        }
+        self.save_chunk_refs(&mut refs)?;
+
+        let mut report = GcReport {
+            live_chunks: refs.counts.len() as u32,
+            ..GcReport::default()
+        };
+        for (id, key) in self.stored_chunks()? {
+            if refs.counts.contains_key(&id) {
+                continue;
+            }
+            let len = self
+                .storage
+                .metadata(&key)
+                .map(|meta| meta.len)
+                .unwrap_or_default();
+            self.storage.delete(&key)?;
+            report.removed_chunks += 1;
+            report.freed_bytes += len;
+        }
+        Ok(report)
+    }
+
+    /// Every blob referenced by a note, revision or attachment. Unless
+    /// `strict`, records that cannot be read are logged and skipped.
+    pub(super) fn stored_blobs(&self, strict: bool) -> anyhow::Result<Vec<Blob>> {
+        let mut blobs = self.note_blobs(strict)?;
+        blobs.extend(self.attachment_blobs(strict)?);
+        Ok(blobs)
+    }
+
+    /// Every stored chunk, as `(id, key)`.
+    fn stored_chunks(&self) -> anyhow::Result<Vec<(String, String)>> {
+        Ok(self
+            .storage
+            .list(CHUNKS_PREFIX)?
+            .into_iter()
+            .filter_map(|key| {
+                let id = key
+                    .rsplit('/')
+                    .next()
+                    .filter(|id| is_chunk_id(id))?
+                    .to_string();
+                Some((id, key))
+            })
+            .collect())
+    }
+
+    pub(super) fn chunk_key(&self, id: &str) -> anyhow::Result<String> {
+        if !is_chunk_id(id) {
+            bail!("invalid chunk id {:?}", id);
+        }
+        Ok(format!("{}{}/{}", CHUNKS_PREFIX, &id[..2], id))
+    }
+}
+
+pub(super) fn is_chunk_id(id: &str) -> bool {
+    id.len() == 64
+        && id
+            .bytes()
+            .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Note, StorageKind};
+    use std::path::Path;
+
+    fn vault() -> Vault {
+        Vault::open(StorageKind::Memory, Path::new("")).unwrap()
+    }
+
+    /// Enough varied text to be cut into several chunks.
+    fn long_text() -> String {
+        (0..5000)
+            .map(|i| format!("line {} {}\n", i, "x".repeat(i % 40)))
+            .collect()
+    }
+
+    fn blob(ids: &[&str]) -> Blob {
+        Blob {
+            size: 0,
+            chunks: ids
+                .iter()
+                .map(|id| ChunkRef {
+                    id: id.to_string(),
+                    len: 0,
+                    lines: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn a_chunk_is_freed_when_its_last_user_releases_it() {
+        let mut refs = ChunkRefs::default();
+        refs.retain(&blob(&["a", "b"]));
+        refs.retain(&blob(&["b"]));
+
+        refs.release(&blob(&["a", "b"]));
+        assert!(!refs.uses("a"));
+        assert!(refs.uses("b"));
+        assert_eq!(refs.freed, ["a"]);
+
+        refs.release(&blob(&["b"]));
+        assert!(!refs.uses("b"));
+        assert_eq!(refs.freed, ["a", "b"]);
+    }
+
+    #[test]
+    fn releasing_an_uncounted_chunk_changes_nothing() {
+        let mut refs = ChunkRefs::default();
+        refs.retain(&blob(&["a"]));
+        refs.release(&blob(&["b"]));
+        assert!(refs.uses("a"));
+        assert!(refs.freed.is_empty());
+    }
+
+    #[test]
+    fn saving_and_deleting_notes_leaves_no_garbage() {
+        let vault = vault();
+        let note = Note {
+            title: "Long".to_string(),
+            content: long_text(),
+            ..Note::default()
+        };
+        let saved = vault.save_note(note, "").unwrap().saved.unwrap();
+        assert!(vault.stored_chunks().unwrap().len() > 2);
+        assert_eq!(vault.collect_garbage().unwrap().removed_chunks, 0);
+
+        let mut edited = saved.clone();
+        edited.content.push_str("one more line\n");
+        vault.save_note(edited, "").unwrap().saved.unwrap();
+        assert_eq!(vault.collect_garbage().unwrap().removed_chunks, 0);
+
+        vault.delete_note(&saved.id).unwrap();
+        assert!(vault.stored_chunks().unwrap().is_empty());
+        let report = vault.collect_garbage().unwrap();
+        assert_eq!(report.live_chunks, 0);
+        assert_eq!(report.removed_chunks, 0);
+    }
+
+    #[test]
+    fn collect_garbage_removes_chunks_nothing_records() {
+        let vault = vault();
+        let saved = vault
+            .save_note(
+                Note {
+                    title: "Kept".to_string(),
+                    content: "kept".to_string(),
+                    ..Note::default()
+                },
+                "",
+            )
+            .unwrap()
+            .saved
+            .unwrap();
+        // Stored as by a write that stopped before its record was.
+        let mut refs = vault.chunk_refs().unwrap();
+        vault
+            .put_blob("never recorded".as_bytes(), false, &mut refs)
+            .unwrap();
+
+        let report = vault.collect_garbage().unwrap();
+        assert_eq!(report.removed_chunks, 1);
+        assert_eq!(report.live_chunks, 1);
+        assert_eq!(vault.read_note(&saved.id).unwrap().content, "kept");
+    }
+
+    #[test]
+    fn attachments_are_staged_inside_the_vault() {
+        let dir = std::env::temp_dir().join(format!("staging-{:016x}", rand::random::<u64>()));
+        let vault = Vault::open(StorageKind::Directory, &dir).unwrap();
+        let staging = vault.storage.staging_dir().unwrap();
+        assert!(staging.starts_with(&dir));
+
+        let data = long_text().into_bytes();
+        let staged = vault.stage_blob(&data[..], false).unwrap();
+        assert_eq!(crate::atomic::temp_files(&staging).unwrap().len(), 1);
+        let blob = vault
+            .store_staged_blob(staged, &mut ChunkRefs::default())
+            .unwrap();
+        assert!(crate::atomic::temp_files(&staging).unwrap().is_empty());
+        assert_eq!(vault.read_blob(&blob).unwrap(), data);
+
+        // As left by a crash while staging.
+        std::mem::forget(vault.stage_blob(&data[..], false).unwrap());
+        assert_eq!(vault.storage.temp_files().unwrap().len(), 1);
+        assert_eq!(vault.storage.remove_temp_files().unwrap(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn staging_without_files_keeps_chunks_in_memory() {
+        let vault = vault();
+        let data = long_text().into_bytes();
+        let staged = vault.stage_blob(&data[..], true).unwrap();
+        let blob = vault
+            .store_staged_blob(staged, &mut ChunkRefs::default())
+            .unwrap();
+        assert_eq!(vault.read_blob(&blob).unwrap(), data);
+    }
+
+    #[test]
+    fn read_blob_range_spans_chunk_boundaries() {
+        let vault = vault();
+        let data = long_text().into_bytes();
+        let blob = vault
+            .put_blob(&data[..], false, &mut ChunkRefs::default())
+            .unwrap();
+        assert!(blob.chunks.len() > 2);
+
+        let first = blob.chunks[0].len as u64;
+        let second = blob.chunks[1].len as u64;
+        for (offset, len) in [
+            (0, 10),
+            (first - 5, 10),
+            (first - 5, second + 10),
+            (first, second),
+            (blob.size - 3, 10),
+            (blob.size + 5, 10),
+            (0, blob.size),
+        ] {
+            let start = offset.min(blob.size) as usize;
+            let end = (offset + len).min(blob.size) as usize;
+            assert_eq!(
+                vault.read_blob_range(&blob, offset, len).unwrap(),
+                data[start..end],
+                "{} bytes from {}",
+                len,
+                offset
+            );
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Paged reads of note content, so a large note can be shown without
+//! decrypting all of it or handing all of it to Dart at once.
+//!
+//! Content is not sealed with the STREAM construction. Each chunk is sealed
+//! on its own and named by a keyed hash of its plaintext, and the `Blob`
+//! listing the chunks in order, with their lengths and the total size, is
+//! kept inside the note's own sealed record. That covers what STREAM's
+//! segment counter and last-segment flag are for:
+//!
+//! - Reordering: the manifest fixes the order, and `read_chunk` checks the
+//!   chunk at each position against the id listed there.
+//! - Truncation: the manifest lists every chunk and the size, so content
+//!   with a chunk dropped from the end fails to read instead of looking
+//!   complete.
+//! - Splicing in other data: a chunk only has a listed id if it has the
+//!   plaintext that id was made from.
+//!
+//! The manifest cannot be changed without failing the record's own
+//! authentication. Sealing chunks separately is what lets one chunk be
+//! stored once for every note, revision and attachment holding it, which
+//! STREAM's per-stream nonce rules out.
+
+use super::Vault;
+use crate::api::NoteWindow;
+use crate::chunks::{self, Blob};
+use anyhow::{bail, Context};
+
+/// A stretch of content that is decrypted as a whole: one chunk of a stored
+/// body, or the whole content of a note that keeps it inline.
+struct Segment {
+    start: u64,
+    len: u64,
+    lines: u32,
+}
+
+/// The content of one note, read a segment at a time.
+enum Source {
+    Blob(Blob),
+    Inline(Vec<u8>),
+}
+
+impl Vault {
+    /// `line_count` lines of a note starting at `first_line`, counted from 0.
+    /// Only the chunks holding those lines are decrypted.
+    pub fn read_note_lines(
+        &self,
+        id: &str,
+        first_line: u32,
+        line_count: u32,
+    ) -> anyhow::Result<NoteWindow> {
+        let _guard = self.lock_reads()?;
+        let source = self.content_source(id)?;
+        let segments = segments(&source);
+        let start = self.line_start(&source, &segments, first_line)?;
+        let end = self.line_start(&source, &segments, first_line.saturating_add(line_count))?;
+        let text = String::from_utf8(self.read_source(&source, start, end - start)?)
+            .context("note content is not valid UTF-8")?;
+        Ok(window(
+            &source,
+            &segments,
+            text,
+            start,
+            first_line.min(total_lines(&segments) - 1),
+        ))
+    }
+
+    /// Up to `length` bytes of a note's content from `offset`, shrunk to
+    /// whole characters.
+    pub fn read_note_range(
+        &self,
+        id: &str,
+        offset: u64,
+        length: u64,
+    ) -> anyhow::Result<NoteWindow> {
+        let _guard = self.lock_reads()?;
+        let source = self.content_source(id)?;
+        let segments = segments(&source);
+        let mut data = self.read_source(&source, offset, length)?;
+
+        // A character cut at the start is skipped, one cut at the end dropped.
+        let skip = data
+            .iter()
+            .take(3)
+            .take_while(|&&byte| byte & 0xc0 == 0x80)
+            .count();
+        data.drain(..skip);
+        let valid = match std::str::from_utf8(&data) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => bail!("note content is not valid UTF-8"),
+        };
+        data.truncate(valid);
+        let text = String::from_utf8(data).expect("checked above");
+
+        let start = (offset + skip as u64).min(source_size(&source));
+        let first_line = self.lines_before(&source, &segments, start)?;
+        Ok(window(&source, &segments, text, start, first_line))
+    }
+
+    fn content_source(&self, id: &str) -> anyhow::Result<Source> {
+        let record = self.read_note_record(id)?;
+        Ok(match record.body {
+            Some(body) => Source::Blob(body),
+            None => Source::Inline(record.note.content.into_bytes()),
+        })
+    }
+
+    fn segment_data(&self, source: &Source, index: usize) -> anyhow::Result<Vec<u8>> {
+        match source {
+            Source::Blob(blob) => self.read_chunk(&blob.chunks[index]),
+            Source::Inline(content) => Ok(content.clone()),
+        }
+    }
+
+    fn read_source(&self, source: &Source, offset: u64, length: u64) -> anyhow::Result<Vec<u8>> {
+        match source {
+            Source::Blob(blob) => self.read_blob_range(blob, offset, length),
+            Source::Inline(content) => {
+                let start = offset.min(content.len() as u64) as usize;
+                let end = offset.saturating_add(length).min(content.len() as u64) as usize;
+                Ok(content[start..end].to_vec())
+            }
+        }
+    }
+
+    /// The offset `line` starts at, or the end of the content when there
+    /// are not that many lines.
+    fn line_start(&self, source: &Source, segments: &[Segment], line: u32) -> anyhow::Result<u64> {
+        if line == 0 {
+            return Ok(0);
+        }
+        let mut seen = 0;
+        for (index, segment) in segments.iter().enumerate() {
+            if seen + segment.lines >= line {
+                let data = self.segment_data(source, index)?;
+                let newline = data
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &byte)| byte == b'\n')
+                    .nth((line - seen - 1) as usize)
+                    .map(|(at, _)| at)
+                    .context("chunk holds fewer lines than its manifest says")?;
+                return Ok(segment.start + newline as u64 + 1);
+            }
+            seen += segment.lines;
+        }
+        Ok(source_size(source))
+    }
+
+    /// The number of the line `offset` falls on.
+    fn lines_before(
+        &self,
+        source: &Source,
+        segments: &[Segment],
+        offset: u64,
+    ) -> anyhow::Result<u32> {
+        let mut lines = 0;
+        for (index, segment) in segments.iter().enumerate() {
+            if segment.start + segment.len <= offset {
+                lines += segment.lines;
+                continue;
+            }
+            if segment.start < offset {
+                let data = self.segment_data(source, index)?;
+                lines += chunks::count_lines(&data[..(offset - segment.start) as usize]);
+            }
+            break;
+        }
+        Ok(lines)
+    }
+}
+
+fn source_size(source: &Source) -> u64 {
+    match source {
+        Source::Blob(blob) => blob.size,
+        Source::Inline(content) => content.len() as u64,
+    }
+}
+
+fn segments(source: &Source) -> Vec<Segment> {
+    let blob = match source {
+        Source::Blob(blob) => blob,
+        Source::Inline(content) => {
+            return vec![Segment {
+                start: 0,
+                len: content.len() as u64,
+                lines: chunks::count_lines(content),
+            }]
+        }
+    };
+    let mut segments = Vec::with_capacity(blob.chunks.len());
+    let mut start = 0;
+    for chunk in &blob.chunks {
+        segments.push(Segment {
+            start,
+            len: chunk.len as u64,
+            lines: chunk.lines,
+        });
+        start += chunk.len as u64;
+    }
+    segments
+}
+
+/// Lines as the editor counts them: an empty note has one, and a trailing
+/// newline starts another.
+fn total_lines(segments: &[Segment]) -> u32 {
+    segments.iter().map(|segment| segment.lines).sum::<u32>() + 1
+}
+
+fn window(
+    source: &Source,
+    segments: &[Segment],
+    text: String,
+    offset: u64,
+    first_line: u32,
+) -> NoteWindow {
+    NoteWindow {
+        text,
+        offset,
+        first_line,
+        total_size: source_size(source),
+        total_lines: total_lines(segments),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Note, StorageKind};
+    use std::path::Path;
+
+    /// A vault holding one note long enough to be stored in several chunks,
+    /// its id, its content and the line each chunk after the first starts on.
+    fn long_note() -> (Vault, String, String, Vec<u32>) {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        let content: String = (0..5000)
+            .map(|i| format!("line {} {}ü\n", i, "x".repeat(i % 40)))
+            .collect();
+        let note = Note {
+            title: "Long".to_string(),
+            content: content.clone(),
+            ..Note::default()
+        };
+        let id = vault.save_note(note, "").unwrap().saved.unwrap().id;
+        let body = vault.read_note_record(&id).unwrap().body.unwrap();
+        assert!(body.chunks.len() > 2);
+        let boundaries = body
+            .chunks
+            .iter()
+            .scan(0, |line, chunk| {
+                *line += chunk.lines;
+                Some(*line)
+            })
+            .collect();
+        (vault, id, content, boundaries)
+    }
+
+    #[test]
+    fn read_note_lines_spans_chunk_boundaries() {
+        let (vault, id, content, boundaries) = long_note();
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        for &boundary in &boundaries[..2] {
+            for (first, count) in [(boundary - 2, 4), (boundary, 1), (boundary - 1, 1)] {
+                let window = vault.read_note_lines(&id, first, count).unwrap();
+                let expected = lines[first as usize..(first + count) as usize].concat();
+                assert_eq!(window.text, expected, "{} lines from {}", count, first);
+                assert_eq!(window.first_line, first);
+                assert_eq!(
+                    window.offset as usize,
+                    lines[..first as usize].concat().len()
+                );
+                assert_eq!(window.total_lines, 5001);
+                assert_eq!(window.total_size as usize, content.len());
+            }
+        }
+    }
+
+    #[test]
+    fn read_note_lines_stops_at_the_end() {
+        let (vault, id, content, _) = long_note();
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let window = vault.read_note_lines(&id, 4998, 10).unwrap();
+        assert_eq!(window.text, lines[4998..].concat());
+        let window = vault.read_note_lines(&id, 6000, 10).unwrap();
+        assert_eq!(window.text, "");
+        assert_eq!(window.first_line, 5000);
+        assert_eq!(window.offset as usize, content.len());
+    }
+
+    #[test]
+    fn read_note_range_keeps_whole_characters_across_chunk_boundaries() {
+        let (vault, id, content, boundaries) = long_note();
+        let boundary = content
+            .split_inclusive('\n')
+            .take(boundaries[0] as usize)
+            .map(str::len)
+            .sum::<usize>() as u64;
+        for (offset, length) in [(boundary - 20, 40), (boundary - 3, 2), (0, 100)] {
+            let window = vault.read_note_range(&id, offset, length).unwrap();
+            let start = window.offset as usize;
+            assert!(start >= offset as usize && start < offset as usize + 4);
+            assert!(start + window.text.len() <= (offset + length) as usize);
+            assert_eq!(window.text, content[start..start + window.text.len()]);
+            assert_eq!(
+                window.first_line as usize,
+                content[..start].matches('\n').count()
+            );
+        }
+        // Starting inside the "ü" that ends a line skips the rest of it.
+        let window = vault.read_note_range(&id, boundary - 2, 10).unwrap();
+        assert_eq!(window.offset, boundary - 1);
+        assert!(window.text.starts_with('\n'));
+    }
+}
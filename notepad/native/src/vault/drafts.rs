@@ -0,0 +1,90 @@
+use super::Vault;
+use crate::api::Draft;
+use crate::{crypto, note};
+use anyhow::Context;
+use std::io;
+
+pub(super) const DRAFTS_PREFIX: &str = "drafts/";
+const DRAFT_EXTENSION: &str = "draft";
+
+impl Vault {
+    /// Keeps the editor's unsaved content for `note_id`, replacing the
+    /// previous draft. A new note has no id yet, so its draft is kept under
+    /// the `draft_id` the editor gave it instead. Drafts live apart from the
+    /// notes and never touch their history.
+    pub fn save_draft(
+        &self,
+        note_id: &str,
+        draft_id: &str,
+        title: String,
+        content: String,
+    ) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let key = self
+            .draft_key(note_id, draft_id)?
+            .context("a new note's draft needs a draft id")?;
+        let (note_id, draft_id) = if note_id.is_empty() {
+            (String::new(), note::parse_id(draft_id)?)
+        } else {
+            (note::parse_id(note_id)?, String::new())
+        };
+        let draft = Draft {
+            note_id,
+            draft_id,
+            title,
+            content,
+            saved_at: note::now_millis(),
+        };
+        let data = crypto::seal_json(&self.key, &draft)?;
+        self.storage
+            .write(&key, &data)
+            .context("failed to write draft")
+    }
+
+    /// Drafts left by editing sessions that ended without a save, newest
+    /// first.
+    pub fn pending_drafts(&self) -> anyhow::Result<Vec<Draft>> {
+        let _guard = self.lock_reads()?;
+        let mut drafts = Vec::new();
+        for key in self.storage.list(DRAFTS_PREFIX)? {
+            let draft = self
+                .storage
+                .read(&key)
+                .map_err(anyhow::Error::from)
+                .and_then(|data| crypto::open_json::<Draft>(&self.key, &data));
+            match draft {
+                Ok(draft) => drafts.push(draft),
+                Err(e) => eprintln!("[Rust] Skipping unreadable draft {:?}: {:#}", key, e),
+            }
+        }
+        drafts.sort_by_key(|draft| std::cmp::Reverse(draft.saved_at));
+        Ok(drafts)
+    }
+
+    pub fn discard_draft(&self, note_id: &str, draft_id: &str) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        self.clear_draft(note_id, draft_id)
+    }
+
+    /// Drops the draft once what it held is saved or its note is deleted.
+    pub(super) fn clear_draft(&self, note_id: &str, draft_id: &str) -> anyhow::Result<()> {
+        let Some(key) = self.draft_key(note_id, draft_id)? else {
+            return Ok(());
+        };
+        match self.storage.delete(&key) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Where the draft of `note_id` is kept, or of the new note `draft_id`
+    /// stands for when `note_id` is empty. `None` when both are.
+    fn draft_key(&self, note_id: &str, draft_id: &str) -> anyhow::Result<Option<String>> {
+        let name = match (note_id.is_empty(), draft_id.is_empty()) {
+            (false, _) => note::parse_id(note_id)?,
+            (true, false) => note::parse_id(draft_id)?,
+            (true, true) => return Ok(None),
+        };
+        Ok(Some(format!("{}{}.{}", DRAFTS_PREFIX, name, DRAFT_EXTENSION)))
+    }
+}
@@ -0,0 +1,258 @@
+use super::Vault;
+use crate::api::{Folder, FolderInfo, Note, NoteSummary};
+use crate::{crypto, note};
+use anyhow::{anyhow, bail, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+pub(super) const FOLDERS_FILE: &str = "folders";
+
+/// The folder hierarchy. Unlike the note index it cannot be rebuilt from the
+/// notes, so it lives in its own encrypted file.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FolderTree {
+    pub folders: BTreeMap<String, Folder>,
+}
+
+impl FolderTree {
+    pub fn get(&self, id: &str) -> anyhow::Result<&Folder> {
+        self.folders
+            .get(id)
+            .ok_or_else(|| anyhow!("folder {:?} does not exist", id))
+    }
+
+    /// `id` followed by every folder nested below it.
+    pub fn subtree(&self, id: &str) -> Vec<String> {
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for folder in self.folders.values() {
+            if let Some(parent) = &folder.parent_id {
+                children.entry(parent).or_default().push(&folder.id);
+            }
+        }
+
+        let mut subtree = vec![id.to_string()];
+        let mut next = 0;
+        while next < subtree.len() {
+            if let Some(ids) = children.get(subtree[next].as_str()) {
+                subtree.extend(ids.iter().map(|id| id.to_string()));
+            }
+            next += 1;
+        }
+        subtree
+    }
+
+    /// The names from the top level down to `id`, joined by `/`.
+    pub fn path(&self, id: &str) -> String {
+        let mut names = Vec::new();
+        let mut next = Some(id);
+        // The parents are checked on every change, but a cycle in a
+        // damaged file must not hang the caller.
+        while let Some(folder) = next.and_then(|id| self.folders.get(id)) {
+            if names.len() > self.folders.len() {
+                break;
+            }
+            names.push(folder.name.as_str());
+            next = folder.parent_id.as_deref();
+        }
+        names.reverse();
+        names.join("/")
+    }
+
+    fn check_parent(&self, parent_id: Option<&str>) -> anyhow::Result<()> {
+        if let Some(parent_id) = parent_id {
+            self.get(parent_id)?;
+        }
+        Ok(())
+    }
+
+    fn check_name(&self, name: &str, parent_id: Option<&str>, except: Option<&str>) -> anyhow::Result<()> {
+        if name.trim().is_empty() {
+            bail!("folder name cannot be empty");
+        }
+        let taken = self.folders.values().any(|folder| {
+            folder.name == name
+                && folder.parent_id.as_deref() == parent_id
+                && Some(folder.id.as_str()) != except
+        });
+        if taken {
+            bail!("a folder named {:?} already exists there", name);
+        }
+        Ok(())
+    }
+}
+
+impl Vault {
+    pub fn folder_tree(&self) -> anyhow::Result<FolderTree> {
+        let _guard = self.lock_reads()?;
+        match self.storage.read(FOLDERS_FILE) {
+            Ok(data) => crypto::open_json(&self.key, &data).context("folder tree is corrupted"),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(FolderTree::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save_folder_tree(&self, tree: &FolderTree) -> anyhow::Result<()> {
+        let data = crypto::seal_json(&self.key, tree)?;
+        self.storage
+            .write(FOLDERS_FILE, &data)
+            .context("failed to write folder tree")
+    }
+
+    pub fn create_folder(&self, name: String, parent_id: Option<String>) -> anyhow::Result<Folder> {
+        let _guard = self.lock_writes()?;
+        let mut tree = self.folder_tree()?;
+        tree.check_parent(parent_id.as_deref())?;
+        tree.check_name(&name, parent_id.as_deref(), None)?;
+
+        let folder = Folder {
+            id: note::new_id(),
+            name,
+            parent_id,
+            created_at: note::now_millis(),
+        };
+        tree.folders.insert(folder.id.clone(), folder.clone());
+        self.save_folder_tree(&tree)?;
+        Ok(folder)
+    }
+
+    pub fn rename_folder(&self, id: &str, name: String) -> anyhow::Result<Folder> {
+        let _guard = self.lock_writes()?;
+        let mut tree = self.folder_tree()?;
+        let parent_id = tree.get(id)?.parent_id.clone();
+        tree.check_name(&name, parent_id.as_deref(), Some(id))?;
+
+        let folder = tree.folders.get_mut(id).expect("checked above");
+        folder.name = name;
+        let folder = folder.clone();
+        self.save_folder_tree(&tree)?;
+        Ok(folder)
+    }
+
+    /// Moves a folder, with everything in it, below `parent_id` or to the top
+    /// level when it is `None`.
+    pub fn move_folder(&self, id: &str, parent_id: Option<String>) -> anyhow::Result<Folder> {
+        let _guard = self.lock_writes()?;
+        let mut tree = self.folder_tree()?;
+        let name = tree.get(id)?.name.clone();
+        tree.check_parent(parent_id.as_deref())?;
+        if let Some(parent_id) = &parent_id {
+            if tree.subtree(id).contains(parent_id) {
+                bail!("cannot move a folder into itself");
+            }
+        }
+        tree.check_name(&name, parent_id.as_deref(), Some(id))?;
+
+        let folder = tree.folders.get_mut(id).expect("checked above");
+        folder.parent_id = parent_id;
+        let folder = folder.clone();
+        self.save_folder_tree(&tree)?;
+        Ok(folder)
+    }
+
+    /// Deletes a folder. Without `recursive` it refuses to delete one that
+    /// still holds notes or folders; with it, those are deleted too.
+    pub fn delete_folder(&self, id: &str, recursive: bool) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let mut tree = self.folder_tree()?;
+        tree.get(id)?;
+        let subtree = tree.subtree(id);
+        let mut index = self.load_index()?;
+        let notes: Vec<String> = index
+            .notes
+            .values()
+            .filter(|note| note.folder_id.as_ref().is_some_and(|folder| subtree.contains(folder)))
+            .map(|note| note.id.clone())
+            .collect();
+
+        if !recursive && (subtree.len() > 1 || !notes.is_empty()) {
+            bail!(
+                "folder is not empty: {} note(s) and {} subfolder(s)",
+                notes.len(),
+                subtree.len() - 1
+            );
+        }
+
+        for note_id in &notes {
+            self.remove_note_files(note_id)?;
+            index.remove(note_id);
+        }
+        self.save_index(&index)?;
+
+        for folder_id in &subtree {
+            tree.folders.remove(folder_id);
+        }
+        self.save_folder_tree(&tree)
+    }
+
+    pub fn move_note(&self, id: &str, folder_id: Option<String>) -> anyhow::Result<Note> {
+        let _guard = self.lock_writes()?;
+        if let Some(folder_id) = &folder_id {
+            self.folder_tree()?.get(folder_id)?;
+        }
+        self.update_note_locked(id, |note| note.folder_id = folder_id)
+    }
+
+    /// Every folder with the number of notes directly in it and in its
+    /// whole subtree.
+    pub fn list_folders(&self) -> anyhow::Result<Vec<FolderInfo>> {
+        let _guard = self.lock_reads()?;
+        let tree = self.folder_tree()?;
+        let index = self.load_index()?;
+
+        let mut direct: HashMap<&str, u32> = HashMap::new();
+        for note in index.notes.values() {
+            if let Some(folder_id) = &note.folder_id {
+                *direct.entry(folder_id).or_default() += 1;
+            }
+        }
+
+        let mut folders: Vec<FolderInfo> = tree
+            .folders
+            .values()
+            .map(|folder| FolderInfo {
+                folder: folder.clone(),
+                note_count: direct.get(folder.id.as_str()).copied().unwrap_or_default(),
+                total_note_count: tree
+                    .subtree(&folder.id)
+                    .iter()
+                    .map(|id| direct.get(id.as_str()).copied().unwrap_or_default())
+                    .sum(),
+            })
+            .collect();
+        folders.sort_by_cached_key(|info| info.folder.name.to_lowercase());
+        Ok(folders)
+    }
+
+    /// Notes directly in `folder_id` (`None` meaning the top level), or
+    /// anywhere below it with `include_subfolders`.
+    pub fn list_notes_in_folder(
+        &self,
+        folder_id: Option<&str>,
+        include_subfolders: bool,
+    ) -> anyhow::Result<Vec<NoteSummary>> {
+        let _guard = self.lock_reads()?;
+        let tree = self.folder_tree()?;
+        let folders: Option<HashSet<String>> = match folder_id {
+            Some(id) => {
+                tree.get(id)?;
+                Some(if include_subfolders {
+                    tree.subtree(id).into_iter().collect()
+                } else {
+                    HashSet::from([id.to_string()])
+                })
+            }
+            None => None,
+        };
+
+        let notes = self.list_notes()?;
+        Ok(notes
+            .into_iter()
+            .filter(|note| match (&folders, &note.folder_id) {
+                (Some(folders), Some(folder)) => folders.contains(folder),
+                (Some(_), None) => false,
+                (None, folder) => include_subfolders || folder.is_none(),
+            })
+            .collect())
+    }
+}
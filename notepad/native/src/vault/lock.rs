@@ -0,0 +1,206 @@
+//! Coordination between every thread and process using a vault.
+//!
+//! Writers take an exclusive OS advisory lock on the vault's lock file and
+//! readers a shared one, so a CLI or a second window can work on the same
+//! vault without interleaving writes. The OS drops the lock when its holder
+//! dies; what it cannot undo is a half-finished write, so a writer records
+//! its pid in the lock file while it holds it and clears it on release. A
+//! record still present when the next writer gets the lock means the last
+//! one crashed, and the derived state is rebuilt.
+
+use super::Vault;
+use crate::note;
+use std::cell::Cell;
+use std::fmt;
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long to wait for another process before giving up with `VaultBusy`.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const RETRY_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Serializes writers within this process, including on backends without a
+/// lock file.
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Held {
+    None,
+    Shared,
+    Exclusive,
+}
+
+thread_local! {
+    // Vault methods call each other, so a thread that already holds the lock
+    // must not wait for it again.
+    static HELD: Cell<Held> = const { Cell::new(Held::None) };
+}
+
+/// Another process kept the vault locked for longer than `LOCK_TIMEOUT`.
+#[derive(Debug)]
+pub struct VaultBusy {
+    /// The writer holding the lock, when it is a writer.
+    pub holder_pid: Option<u32>,
+}
+
+impl fmt::Display for VaultBusy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.holder_pid {
+            Some(pid) => write!(f, "vault is busy: process {} is writing to it", pid),
+            None => write!(f, "vault is busy: another process is using it"),
+        }
+    }
+}
+
+impl std::error::Error for VaultBusy {}
+
+/// Releases the lock when dropped.
+pub struct VaultGuard {
+    file: Option<File>,
+    writes: Option<MutexGuard<'static, ()>>,
+    /// Set once this writer's pid is in the lock file.
+    recorded: bool,
+    outer: bool,
+}
+
+impl Drop for VaultGuard {
+    fn drop(&mut self) {
+        if !self.outer {
+            return;
+        }
+        if let (Some(file), true) = (&mut self.file, self.recorded) {
+            if let Err(e) = file.set_len(0).and_then(|_| file.sync_all()) {
+                eprintln!("[Rust] Failed to clear the vault lock owner: {}", e);
+            }
+        }
+        self.file.take();
+        self.writes.take();
+        HELD.with(|held| held.set(Held::None));
+    }
+}
+
+impl Vault {
+    /// Takes the vault for writing. Recovers first if the previous writer
+    /// died while holding it.
+    pub(super) fn lock_writes(&self) -> anyhow::Result<VaultGuard> {
+        match HELD.with(|held| held.get()) {
+            Held::Exclusive => return Ok(nested()),
+            Held::Shared => anyhow::bail!("cannot write to the vault while reading it"),
+            Held::None => {}
+        }
+
+        let writes = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let file = match self.storage.lock_path() {
+            Some(path) => Some(acquire(&path, true)?),
+            None => None,
+        };
+        HELD.with(|held| held.set(Held::Exclusive));
+        let mut guard = VaultGuard {
+            file,
+            writes: Some(writes),
+            recorded: false,
+            outer: true,
+        };
+
+        if let Some(file) = &mut guard.file {
+            // The record is left alone if recovery fails, so the next writer
+            // tries again.
+            if let Some(pid) = read_owner(file) {
+                eprintln!(
+                    "[Rust] Process {} stopped while writing to the vault, recovering",
+                    pid
+                );
+                self.recover()?;
+            }
+            file.set_len(0)?;
+            file.rewind()?;
+            write!(file, "{} {}", std::process::id(), note::now_millis())?;
+            file.sync_all()?;
+            guard.recorded = true;
+        }
+        Ok(guard)
+    }
+
+    /// Takes the vault for reading, which only waits while a writer holds it.
+    pub(super) fn lock_reads(&self) -> anyhow::Result<VaultGuard> {
+        if HELD.with(|held| held.get()) != Held::None {
+            return Ok(nested());
+        }
+        let file = match self.storage.lock_path() {
+            Some(path) => Some(acquire(&path, false)?),
+            None => None,
+        };
+        HELD.with(|held| held.set(Held::Shared));
+        Ok(VaultGuard {
+            file,
+            writes: None,
+            recorded: false,
+            outer: true,
+        })
+    }
+
+    /// Rebuilds what an interrupted write may have left inconsistent, and
+    /// removes its temp files. The lock is held, so none of them can belong
+    /// to a write still in progress.
+    fn recover(&self) -> anyhow::Result<()> {
+        let removed = self.storage.remove_temp_files()?;
+        if removed > 0 {
+            println!("[Rust] Removed {} interrupted write(s)", removed);
+        }
+        self.save_index(&self.rebuild_index()?)?;
+        self.rebuild_search_index()?;
+        self.rebuild_chunk_refs()?;
+        self.recover_replace()
+    }
+}
+
+fn nested() -> VaultGuard {
+    VaultGuard {
+        file: None,
+        writes: None,
+        recorded: false,
+        outer: false,
+    }
+}
+
+fn acquire(path: &Path, exclusive: bool) -> anyhow::Result<File> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)?;
+    let deadline = Instant::now() + LOCK_TIMEOUT;
+    loop {
+        let attempt = if exclusive {
+            file.try_lock()
+        } else {
+            file.try_lock_shared()
+        };
+        match attempt {
+            Ok(()) => return Ok(file),
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                thread::sleep(RETRY_INTERVAL)
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(VaultBusy {
+                    holder_pid: read_owner(&mut file),
+                }
+                .into())
+            }
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+        }
+    }
+}
+
+/// The pid a writer left in the lock file, if any.
+fn read_owner(file: &mut File) -> Option<u32> {
+    let mut record = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut record).ok()?;
+    record.split_whitespace().next()?.parse().ok()
+}
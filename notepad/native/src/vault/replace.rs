@@ -0,0 +1,334 @@
+//! Regex find-and-replace across every note.
+//!
+//! A preview decrypts each note in memory and lists every match; applying
+//! takes the matches the user kept and writes each changed note as a new
+//! revision. The batch is all or nothing: every note is checked before the
+//! first is written, and the originals are journaled so that a failure, or
+//! a crash seen by the next writer, puts them back.
+
+use super::search::utf16_ranges;
+use super::Vault;
+use crate::api::{
+    Note, NoteSummary, ReplaceField, ReplaceMatch, ReplacePreview, ReplaceQuery, ReplaceReport,
+};
+use crate::{crypto, note};
+use anyhow::Context;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+pub(super) const REPLACE_JOURNAL_FILE: &str = "replace-journal";
+
+/// Characters of the line shown on each side of a match.
+const CONTEXT_CHARS: usize = 40;
+
+/// The notes a batch is about to change, as they were before it.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Journal {
+    originals: Vec<Note>,
+}
+
+impl Vault {
+    /// Every match of `query.pattern`, with the text around it and what it
+    /// would be replaced with. Nothing is written.
+    pub fn preview_replace(&self, query: &ReplaceQuery) -> anyhow::Result<ReplacePreview> {
+        let regex = compile(query)?;
+        let _guard = self.lock_reads()?;
+        let mut preview = ReplacePreview::default();
+        for summary in self.list_notes()? {
+            let note = match self.read_note(&summary.id) {
+                Ok(note) => note,
+                Err(e) => {
+                    eprintln!(
+                        "[Rust] Skipping note {:?} in find and replace: {:#}",
+                        summary.id, e
+                    );
+                    continue;
+                }
+            };
+            preview.notes_searched += 1;
+            if note.content_encrypted {
+                preview.locked_notes.push(note.id.clone());
+            }
+            for field in fields(query, &note) {
+                preview.matches.extend(
+                    find(&regex, &query.replacement, &note, field)
+                        .into_iter()
+                        .map(|found| found.found),
+                );
+            }
+        }
+        Ok(preview)
+    }
+
+    /// Replaces `chosen`, a subset of what `preview_replace` returned for
+    /// the same query, writing one new revision per changed note.
+    ///
+    /// If any of the notes changed since the preview, nothing is written and
+    /// they are listed in `stale_notes`.
+    pub fn apply_replace(
+        &self,
+        query: &ReplaceQuery,
+        chosen: &[ReplaceMatch],
+    ) -> anyhow::Result<ReplaceReport> {
+        let regex = compile(query)?;
+        let _guard = self.lock_writes()?;
+
+        let mut ids: Vec<&str> = chosen.iter().map(|m| m.note_id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        let mut report = ReplaceReport::default();
+        let mut originals = Vec::new();
+        let mut changed = Vec::new();
+        for id in ids {
+            let original = match self.read_note(id) {
+                Ok(original) => original,
+                Err(_) => {
+                    report.stale_notes.push(id.to_string());
+                    continue;
+                }
+            };
+            let chosen: Vec<&ReplaceMatch> = chosen.iter().filter(|m| m.note_id == id).collect();
+            let mut note = original.clone();
+            let mut replaced = 0;
+            for field in [ReplaceField::Title, ReplaceField::Content] {
+                let selected: Vec<&ReplaceMatch> = chosen
+                    .iter()
+                    .copied()
+                    .filter(|m| m.field == field)
+                    .collect();
+                if selected.is_empty() {
+                    continue;
+                }
+                let found = find(&regex, &query.replacement, &original, field);
+                let still_there = selected.iter().all(|m| {
+                    m.revision == original.revision
+                        && found.iter().any(|f| {
+                            f.found.start == m.start
+                                && f.found.end == m.end
+                                && f.found.matched == m.matched
+                        })
+                });
+                if !still_there || (field == ReplaceField::Content && original.content_encrypted) {
+                    report.stale_notes.push(id.to_string());
+                    break;
+                }
+                let text = field_text(&original, field);
+                let mut result = String::with_capacity(text.len());
+                let mut last = 0;
+                for f in &found {
+                    if selected.iter().any(|s| s.start == f.found.start) {
+                        result.push_str(&text[last..f.bytes.start]);
+                        result.push_str(&f.found.replacement);
+                        last = f.bytes.end;
+                        replaced += 1;
+                    }
+                }
+                result.push_str(&text[last..]);
+                match field {
+                    ReplaceField::Title => note.title = result,
+                    ReplaceField::Content => note.content = result,
+                }
+            }
+            if note.title != original.title && note::validate_title(&note.title).is_err() {
+                report.rejected_notes.push(id.to_string());
+                continue;
+            }
+            report.replacements += replaced;
+            originals.push(original);
+            changed.push(note);
+        }
+        if !report.stale_notes.is_empty() || !report.rejected_notes.is_empty() {
+            report.replacements = 0;
+            return Ok(report);
+        }
+
+        self.write_journal(&Journal {
+            originals: originals.clone(),
+        })?;
+        let now = note::now_millis();
+        for note in &mut changed {
+            note.revision += 1;
+            note.modified_at = now;
+            if let Err(e) = self.store_note(note) {
+                self.revert(&originals)
+                    .context("failed to undo a partly applied replace")?;
+                return Err(e);
+            }
+        }
+        self.storage.delete(REPLACE_JOURNAL_FILE)?;
+
+        report.changed_notes = changed.iter().map(NoteSummary::from).collect();
+        Ok(report)
+    }
+
+    /// Puts back the notes of a batch that did not finish, if one was
+    /// interrupted.
+    pub(super) fn recover_replace(&self) -> anyhow::Result<()> {
+        let data = match self.storage.read(REPLACE_JOURNAL_FILE) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let journal: Journal =
+            crypto::open_json(&self.key, &data).context("replace journal is corrupted")?;
+        println!("[Rust] Undoing an interrupted find and replace");
+        self.revert(&journal.originals)
+    }
+
+    /// Stores each original again as a new revision, so the replaced version
+    /// stays in its history, then drops the journal.
+    fn revert(&self, originals: &[Note]) -> anyhow::Result<()> {
+        for original in originals {
+            let current = match self.read_note(&original.id) {
+                Ok(current) => current,
+                Err(e) => {
+                    eprintln!("[Rust] Cannot restore note {:?}: {:#}", original.id, e);
+                    continue;
+                }
+            };
+            if current.revision == original.revision {
+                continue;
+            }
+            let mut note = original.clone();
+            note.revision = current.revision + 1;
+            note.modified_at = note::now_millis();
+            self.store_note(&note)?;
+        }
+        self.storage.delete(REPLACE_JOURNAL_FILE)?;
+        Ok(())
+    }
+
+    fn write_journal(&self, journal: &Journal) -> anyhow::Result<()> {
+        let data = crypto::seal_json(&self.key, journal)?;
+        self.storage
+            .write(REPLACE_JOURNAL_FILE, &data)
+            .context("failed to write replace journal")
+    }
+}
+
+fn compile(query: &ReplaceQuery) -> anyhow::Result<Regex> {
+    RegexBuilder::new(&query.pattern)
+        .case_insensitive(query.case_insensitive)
+        .build()
+        .with_context(|| format!("invalid pattern {:?}", query.pattern))
+}
+
+/// The parts of `note` the query looks at. The content of a note encrypted
+/// with its own password is ciphertext and never touched.
+fn fields(query: &ReplaceQuery, note: &Note) -> Vec<ReplaceField> {
+    let mut fields = Vec::new();
+    if query.include_titles {
+        fields.push(ReplaceField::Title);
+    }
+    if !note.content_encrypted {
+        fields.push(ReplaceField::Content);
+    }
+    fields
+}
+
+fn field_text(note: &Note, field: ReplaceField) -> &str {
+    match field {
+        ReplaceField::Title => &note.title,
+        ReplaceField::Content => &note.content,
+    }
+}
+
+/// A match, with where it is in bytes to splice the replacement in.
+struct Found {
+    found: ReplaceMatch,
+    bytes: Range<usize>,
+}
+
+fn find(regex: &Regex, replacement: &str, note: &Note, field: ReplaceField) -> Vec<Found> {
+    let text = field_text(note, field);
+    regex
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            // An empty match replaces nothing the user could pick out.
+            if whole.is_empty() {
+                return None;
+            }
+            let mut expanded = String::new();
+            captures.expand(replacement, &mut expanded);
+
+            let line_start = text[..whole.start()].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = text[whole.end()..]
+                .find('\n')
+                .map_or(text.len(), |i| whole.end() + i);
+            let before = &text[line_start..whole.start()];
+            let before_start = before
+                .char_indices()
+                .rev()
+                .nth(CONTEXT_CHARS - 1)
+                .map_or(0, |(i, _)| i);
+            let after = &text[whole.end()..line_end];
+            let after_end = after
+                .char_indices()
+                .nth(CONTEXT_CHARS)
+                .map_or(after.len(), |(i, _)| i);
+
+            let utf16 = utf16_ranges(text, &[whole.range()])[0].clone();
+            Some(Found {
+                found: ReplaceMatch {
+                    note_id: note.id.clone(),
+                    note_title: note.title.clone(),
+                    revision: note.revision,
+                    field,
+                    start: utf16.start as u64,
+                    end: utf16.end as u64,
+                    line: text[..whole.start()].matches('\n').count() as u32,
+                    before: before[before_start..].to_string(),
+                    matched: whole.as_str().to_string(),
+                    after: after[..after_end].to_string(),
+                    replacement: expanded,
+                },
+                bytes: whole.range(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::StorageKind;
+    use std::path::Path;
+
+    #[test]
+    fn matches_are_reported_in_utf16_and_applied_by_them() {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        let content = "Grüße 👋 from café, café!";
+        let note = Note {
+            title: "Trip".to_string(),
+            content: content.to_string(),
+            ..Note::default()
+        };
+        let id = vault.save_note(note, "").unwrap().saved.unwrap().id;
+        let query = ReplaceQuery {
+            pattern: "café".to_string(),
+            replacement: "bar".to_string(),
+            case_insensitive: false,
+            include_titles: false,
+        };
+
+        let preview = vault.preview_replace(&query).unwrap();
+        let utf16: Vec<u16> = content.encode_utf16().collect();
+        let offsets: Vec<(u64, u64)> = preview.matches.iter().map(|m| (m.start, m.end)).collect();
+        assert_eq!(offsets, [(14, 18), (20, 24)]);
+        for m in &preview.matches {
+            let matched = String::from_utf16(&utf16[m.start as usize..m.end as usize]).unwrap();
+            assert_eq!(matched, m.matched);
+        }
+
+        let report = vault.apply_replace(&query, &preview.matches[1..]).unwrap();
+        assert_eq!(report.replacements, 1);
+        assert_eq!(
+            vault.read_note(&id).unwrap().content,
+            "Grüße 👋 from café, bar!"
+        );
+    }
+}
@@ -0,0 +1,420 @@
+use super::attachments::ATTACHMENTS_PREFIX;
+use super::folders::FolderTree;
+use super::Vault;
+use crate::api::{
+    Note, QueryDiagnostic, QueryDiagnosticKind, SearchBatch, SearchHit, SearchResults, TextMatch,
+};
+use crate::index::Index;
+use crate::note;
+use crate::query::{self, Filter};
+use crate::search::{self, SearchIndex};
+use anyhow::Context;
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub(super) const SEARCH_INDEX_FILE: &str = "search-index";
+
+/// Roughly how much of a note's content a hit shows, in bytes.
+const SNIPPET_LEN: usize = 160;
+/// Candidate notes scored and looked at for each batch of a streamed
+/// search.
+const STREAM_BATCH_SIZE: usize = 50;
+
+/// Bumped by every streamed search and by `cancel_search`, so a running
+/// search sees it has been superseded.
+static SEARCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// What a search needs at hand for every candidate note.
+struct Evaluation {
+    query: query::Query,
+    search_index: SearchIndex,
+    index: Index,
+    /// The folders each `in:` clause stands for, by clause.
+    folders: HashMap<usize, HashSet<String>>,
+    /// Notes with attachments, if a clause asks about them.
+    attached: Option<HashSet<String>>,
+}
+
+impl Vault {
+    /// Notes matching `query`, in the language of `crate::query`, best
+    /// first, with where their words matched.
+    pub fn search(&self, query: &str, limit: usize) -> anyhow::Result<SearchResults> {
+        let mut results = SearchResults::default();
+        self.search_in_batches(query, limit, usize::MAX, |batch| {
+            results.hits.extend(batch.hits);
+            results.diagnostics.extend(batch.diagnostics);
+            true
+        })?;
+        Ok(results)
+    }
+
+    /// Runs a search like `search`, handing each batch of hits to
+    /// `on_batch` as soon as it is scored, until `on_batch` returns false or
+    /// the search is superseded by another streamed search or
+    /// `cancel_search`. A failure is handed over as an error, after which
+    /// nothing more comes.
+    ///
+    /// Returns once the search ends, so the caller decides what thread it
+    /// runs on; the bridge runs it on its own worker pool.
+    pub fn search_stream(
+        &self,
+        query: &str,
+        limit: usize,
+        mut on_batch: impl FnMut(anyhow::Result<SearchBatch>) -> bool,
+    ) {
+        let generation = SEARCH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let current = || SEARCH_GENERATION.load(Ordering::SeqCst) == generation;
+        let result = self.search_in_batches(query, limit, STREAM_BATCH_SIZE, |batch| {
+            if !current() {
+                return false;
+            }
+            let empty = batch.hits.is_empty() && batch.diagnostics.is_empty();
+            (empty && !batch.done) || on_batch(Ok(batch))
+        });
+        if let Err(e) = result {
+            if current() {
+                on_batch(Err(e));
+            }
+        }
+    }
+
+    /// Stops the streamed search in progress, if any.
+    pub fn cancel_search(&self) {
+        SEARCH_GENERATION.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Runs a search like `search`, scoring `batch_size` candidate notes at
+    /// a time and handing the hits among them to `on_batch`, so a batch may
+    /// be empty. Each batch is best first, but a later batch may hold better
+    /// hits; with `batch_size` at `usize::MAX` there is a single batch, in
+    /// order. The first batch carries the query's diagnostics and the last
+    /// has `done` set; the search stops early when `on_batch` returns false
+    /// or `limit` hits have been handed over.
+    ///
+    /// The vault is only locked while a batch is being read, so a long
+    /// search does not hold up saves.
+    pub fn search_in_batches(
+        &self,
+        query: &str,
+        limit: usize,
+        batch_size: usize,
+        mut on_batch: impl FnMut(SearchBatch) -> bool,
+    ) -> anyhow::Result<()> {
+        let query_text = query;
+        let (query, mut diagnostics) = query::parse(query_text);
+        let guard = self.lock_reads()?;
+        let search_index = self.load_search_index()?;
+        let index = self.load_index()?;
+
+        let mut words = Vec::new();
+        let mut exact = Vec::new();
+        for clause in query.clauses.iter().filter(|clause| !clause.negated) {
+            match &clause.filter {
+                Filter::Word(word) => words.push(word.clone()),
+                Filter::Phrase(phrase) => exact.extend(phrase.iter().cloned()),
+                _ => {}
+            }
+        }
+        // Without words every note is a candidate, in the order notes are
+        // listed.
+        let mut listed = Vec::new();
+        if words.is_empty() && exact.is_empty() {
+            listed = self.list_notes()?;
+        }
+
+        let mut folders = HashMap::new();
+        let mut attached = None;
+        for (i, clause) in query.clauses.iter().enumerate() {
+            match &clause.filter {
+                Filter::Folder(path) => {
+                    let ids = folder_ids(&self.folder_tree()?, path);
+                    if ids.is_empty() {
+                        diagnostics.push(query::Diagnostic::new(
+                            QueryDiagnosticKind::UnknownFolder,
+                            clause.span.clone(),
+                            format!("there is no folder {:?}", path),
+                        ));
+                    }
+                    folders.insert(i, ids);
+                }
+                Filter::HasAttachment if attached.is_none() => {
+                    let records = self.attachment_records(ATTACHMENTS_PREFIX)?;
+                    let ids: HashSet<String> = records
+                        .into_iter()
+                        .map(|record| record.attachment.note_id)
+                        .collect();
+                    attached = Some(ids);
+                }
+                _ => {}
+            }
+        }
+        drop(guard);
+
+        let evaluation = Evaluation {
+            query,
+            search_index,
+            index,
+            folders,
+            attached,
+        };
+        let mut batch = SearchBatch {
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    let span = utf16_ranges(query_text, &[diagnostic.span])[0].clone();
+                    QueryDiagnostic {
+                        kind: diagnostic.kind,
+                        message: diagnostic.message,
+                        start: span.start,
+                        end: span.end,
+                    }
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut segments: Box<dyn Iterator<Item = Vec<search::Hit>>> = if listed.is_empty() {
+            Box::new(evaluation.search_index.scoring(&words, &exact, batch_size))
+        } else {
+            let mut listed = listed.into_iter().map(|summary| search::Hit {
+                id: summary.id,
+                ..Default::default()
+            });
+            Box::new(std::iter::from_fn(move || {
+                let segment: Vec<search::Hit> = listed.by_ref().take(batch_size).collect();
+                (!segment.is_empty()).then_some(segment)
+            }))
+        };
+        let mut found = 0;
+        loop {
+            let candidates = match segments.next() {
+                Some(candidates) => candidates,
+                None => {
+                    batch.done = true;
+                    on_batch(batch);
+                    return Ok(());
+                }
+            };
+            let guard = self.lock_reads()?;
+            for hit in candidates {
+                if found == limit {
+                    break;
+                }
+                if let Some(hit) = self.evaluate(&evaluation, hit) {
+                    batch.hits.push(hit);
+                    found += 1;
+                }
+            }
+            drop(guard);
+
+            if found == limit {
+                batch.done = true;
+                on_batch(batch);
+                return Ok(());
+            }
+            if !on_batch(std::mem::take(&mut batch)) {
+                return Ok(());
+            }
+        }
+    }
+
+    /// The hit for a candidate note, if it passes every clause.
+    fn evaluate(&self, evaluation: &Evaluation, hit: search::Hit) -> Option<SearchHit> {
+        let summary = evaluation.index.notes.get(&hit.id)?;
+        // Read only when a clause or the snippet needs the content.
+        let note = OnceCell::new();
+        let note =
+            || -> Option<&Note> { note.get_or_init(|| self.read_note(&hit.id).ok()).as_ref() };
+
+        let search_index = &evaluation.search_index;
+        for (i, clause) in evaluation.query.clauses.iter().enumerate() {
+            let holds = match &clause.filter {
+                // Notes without the word were never candidates.
+                Filter::Word(_) if !clause.negated => true,
+                Filter::Word(word) => search_index.has(&hit.id, word),
+                Filter::Phrase(phrase) => {
+                    phrase.iter().all(|word| search_index.has(&hit.id, word))
+                        && note().is_some_and(|note| contains_phrase(note, phrase))
+                }
+                Filter::Tag(tag) => summary
+                    .tags
+                    .iter()
+                    .any(|note_tag| note::tag_matches(note_tag, tag)),
+                Filter::Folder(_) => summary
+                    .folder_id
+                    .as_ref()
+                    .is_some_and(|folder| evaluation.folders[&i].contains(folder)),
+                Filter::Modified(range) => range.contains(summary.modified_at),
+                Filter::Created(range) => range.contains(summary.created_at),
+                Filter::Pinned => summary.pinned,
+                Filter::Locked => self
+                    .read_note_record(&hit.id)
+                    .is_ok_and(|record| record.note.content_encrypted),
+                Filter::HasAttachment => evaluation
+                    .attached
+                    .as_ref()
+                    .is_some_and(|attached| attached.contains(&hit.id)),
+            };
+            if holds == clause.negated {
+                return None;
+            }
+        }
+
+        let title_matches = search::find_matches(&summary.title, &hit.terms);
+        let snippet = match note() {
+            Some(note) if !hit.terms.is_empty() && !note.content_encrypted => {
+                let matches = search::find_matches(&note.content, &hit.terms);
+                search::snippet(&note.content, &matches, SNIPPET_LEN)
+            }
+            _ => Default::default(),
+        };
+        Some(SearchHit {
+            title_matches: utf16_ranges(&summary.title, &title_matches),
+            snippet_matches: utf16_ranges(&snippet.text, &snippet.matches),
+            snippet: snippet.text,
+            note: summary.clone(),
+            score: hit.score,
+        })
+    }
+
+    pub(super) fn load_search_index(&self) -> anyhow::Result<SearchIndex> {
+        let data = self.storage.read(SEARCH_INDEX_FILE)?;
+        SearchIndex::decode(&self.key, &data).context("search index is corrupted")
+    }
+
+    pub(super) fn save_search_index(&self, index: &SearchIndex) -> anyhow::Result<()> {
+        let data = index.encode(&self.key)?;
+        self.storage
+            .write(SEARCH_INDEX_FILE, &data)
+            .context("failed to write search index")
+    }
+
+    /// Reads every note to index it again, skipping any that no longer
+    /// decrypt.
+    pub(super) fn rebuild_search_index(&self) -> anyhow::Result<()> {
+        let mut index = SearchIndex::default();
+        for id in self.note_ids()? {
+            match self.read_note(&id) {
+                Ok(note) => index.insert(&id, &searchable_text(&note)),
+                Err(e) => eprintln!(
+                    "[Rust] Leaving note {:?} out of the search index: {:#}",
+                    id, e
+                ),
+            }
+        }
+        self.save_search_index(&index)
+    }
+
+    pub(super) fn index_note_text(&self, note: &Note) -> anyhow::Result<()> {
+        let mut index = self.load_search_index()?;
+        index.insert(&note.id, &searchable_text(note));
+        self.save_search_index(&index)
+    }
+
+    pub(super) fn unindex_note_text(&self, id: &str) -> anyhow::Result<()> {
+        let mut index = self.load_search_index()?;
+        index.remove(id);
+        self.save_search_index(&index)
+    }
+}
+
+/// The title and, unless the user encrypted it with `encrypt_text`, the
+/// content.
+pub(super) fn searchable_text(note: &Note) -> String {
+    if note.content_encrypted {
+        note.title.clone()
+    } else {
+        format!("{}\n{}", note.title, note.content)
+    }
+}
+
+/// Whether the title or the searchable content has `phrase`; a phrase
+/// does not run on from one into the other.
+fn contains_phrase(note: &Note, phrase: &[String]) -> bool {
+    search::contains_phrase(&note.title, phrase)
+        || (!note.content_encrypted && search::contains_phrase(&note.content, phrase))
+}
+
+/// The folders `path` names, by name or by path from the top level, with
+/// the folders below them.
+fn folder_ids(tree: &FolderTree, path: &str) -> HashSet<String> {
+    let path = path.trim_matches('/').to_lowercase();
+    tree.folders
+        .values()
+        .filter(|folder| {
+            folder.name.to_lowercase() == path || tree.path(&folder.id).to_lowercase() == path
+        })
+        .flat_map(|folder| tree.subtree(&folder.id))
+        .collect()
+}
+
+/// Byte ranges in `text` as UTF-16 offsets, which is how Dart indexes
+/// strings.
+pub(super) fn utf16_ranges(text: &str, ranges: &[Range<usize>]) -> Vec<TextMatch> {
+    let offset = |byte: usize| text[..byte].encode_utf16().count() as u32;
+    ranges
+        .iter()
+        .map(|range| TextMatch {
+            start: offset(range.start),
+            end: offset(range.end),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::StorageKind;
+    use std::path::Path;
+
+    // One test, since a streamed search elsewhere would supersede these.
+    #[test]
+    fn a_streamed_search_sends_hits_batch_by_batch_until_superseded() {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        for i in 0..120 {
+            let note = Note {
+                title: format!("Meeting {}", i),
+                content: "agenda ".repeat(i % 5 + 1),
+                ..Note::default()
+            };
+            vault.save_note(note, "").unwrap();
+        }
+
+        let mut batches = Vec::new();
+        vault.search_stream("agenda", usize::MAX, |batch| {
+            batches.push(batch.unwrap());
+            true
+        });
+        assert!(batches.len() > 2);
+        assert!(batches
+            .iter()
+            .all(|batch| batch.hits.len() <= STREAM_BATCH_SIZE));
+        assert!(!batches[0].done && batches.last().unwrap().done);
+
+        let mut streamed: Vec<String> = batches
+            .iter()
+            .flat_map(|batch| batch.hits.iter().map(|hit| hit.note.id.clone()))
+            .collect();
+        let mut at_once: Vec<String> = vault
+            .search("agenda", usize::MAX)
+            .unwrap()
+            .hits
+            .into_iter()
+            .map(|hit| hit.note.id)
+            .collect();
+        assert_eq!(streamed.len(), 120);
+        streamed.sort();
+        at_once.sort();
+        assert_eq!(streamed, at_once);
+
+        let mut sent = 0;
+        vault.search_stream("meeting", usize::MAX, |batch| {
+            assert!(!batch.unwrap().done);
+            sent += 1;
+            vault.cancel_search();
+            true
+        });
+        assert_eq!(sent, 1);
+    }
+}
@@ -0,0 +1,162 @@
+use super::check::{Entry, QUARANTINE_DIR};
+use super::drafts::DRAFTS_PREFIX;
+use super::Vault;
+use crate::api::{NoteStats, QuarantineStats, VaultStats};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io;
+
+impl Vault {
+    /// How much the vault stores and what for. Sizes are what the backend
+    /// holds, so they are encrypted sizes unless said otherwise.
+    pub fn stats(&self) -> anyhow::Result<VaultStats> {
+        let _guard = self.lock_reads()?;
+        let mut stats = VaultStats::default();
+        let mut entries = Vec::new();
+        for key in self.storage.list("")? {
+            let len = match self.storage.metadata(&key) {
+                Ok(metadata) => metadata.len,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            stats.total_bytes += len;
+            entries.push((Entry::of(&key), key, len));
+        }
+
+        let mut chunk_sizes = HashMap::new();
+        for (entry, _, len) in &entries {
+            if let Entry::Chunk(id) = entry {
+                chunk_sizes.insert(id.as_str(), *len);
+                stats.chunk_count += 1;
+                stats.chunk_bytes += len;
+            }
+        }
+
+        let mut quarantine: BTreeMap<&str, QuarantineStats> = BTreeMap::new();
+        let mut notes: HashMap<String, NoteStats> = HashMap::new();
+        let mut present = HashSet::new();
+        for (entry, key, len) in &entries {
+            match entry {
+                Entry::Note(id) => {
+                    let record = match self.read_note_record(id) {
+                        Ok(record) => record,
+                        Err(e) => {
+                            eprintln!("[Rust] Leaving note {:?} out of the stats: {:#}", id, e);
+                            continue;
+                        }
+                    };
+                    let note = notes.entry(id.clone()).or_default();
+                    note.id = id.clone();
+                    note.title = record.note.title.clone();
+                    note.stored_bytes += len;
+                    match &record.body {
+                        Some(body) => {
+                            note.content_bytes = body.size;
+                            note.stored_bytes += body
+                                .chunks
+                                .iter()
+                                .filter_map(|chunk| chunk_sizes.get(chunk.id.as_str()))
+                                .sum::<u64>();
+                        }
+                        None => note.content_bytes = record.note.content.len() as u64,
+                    }
+                    let modified_at = record.note.modified_at;
+                    stats.oldest_modified_at = Some(
+                        stats
+                            .oldest_modified_at
+                            .map_or(modified_at, |t| t.min(modified_at)),
+                    );
+                    stats.newest_modified_at = Some(
+                        stats
+                            .newest_modified_at
+                            .map_or(modified_at, |t| t.max(modified_at)),
+                    );
+                    present.insert(id.clone());
+                }
+                Entry::Revision(id, _) => {
+                    let note = notes.entry(id.clone()).or_default();
+                    note.revision_count += 1;
+                    note.revision_bytes += len;
+                    stats.revision_count += 1;
+                    stats.revision_bytes += len;
+                }
+                Entry::Attachment(..) => match self.read_attachment_record(key) {
+                    Ok(record) => {
+                        let size = record.attachment.size;
+                        let note = notes.entry(record.attachment.note_id).or_default();
+                        note.attachment_count += 1;
+                        note.attachment_bytes += size;
+                        stats.attachment_count += 1;
+                        stats.attachment_bytes += size;
+                    }
+                    Err(e) => eprintln!(
+                        "[Rust] Leaving attachment {:?} out of the stats: {:#}",
+                        key, e
+                    ),
+                },
+                Entry::Sealed if key.starts_with(DRAFTS_PREFIX) => {
+                    stats.draft_count += 1;
+                    stats.draft_bytes += len;
+                }
+                Entry::Quarantined => {
+                    // Keys are `quarantine/<millis>/<original key>`.
+                    let batch = key[QUARANTINE_DIR.len() + 1..]
+                        .split('/')
+                        .next()
+                        .unwrap_or_default();
+                    let stats = quarantine.entry(batch).or_insert_with(|| QuarantineStats {
+                        quarantined_at: batch.parse().unwrap_or_default(),
+                        ..QuarantineStats::default()
+                    });
+                    stats.file_count += 1;
+                    stats.bytes += len;
+                }
+                _ => {}
+            }
+        }
+
+        stats.notes = notes
+            .into_iter()
+            .filter(|(id, _)| present.contains(id))
+            .map(|(_, note)| note)
+            .collect();
+        stats.notes.sort_by(|a, b| {
+            b.stored_bytes
+                .cmp(&a.stored_bytes)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        stats.note_count = stats.notes.len() as u32;
+        stats.quarantine = quarantine.into_values().collect();
+        stats
+            .quarantine
+            .sort_by_key(|batch| std::cmp::Reverse(batch.quarantined_at));
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::StorageKind;
+    use std::path::Path;
+
+    #[test]
+    fn quarantine_is_reported_per_repair_newest_first() {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        for (key, len) in [
+            ("quarantine/1000/notes/a.note", 10),
+            ("quarantine/1000/chunks/ab/abcd", 5),
+            ("quarantine/2000/history/b/0000000001.rev", 7),
+        ] {
+            vault.storage.write(key, &vec![0; len]).unwrap();
+        }
+
+        let quarantine: Vec<(i64, u32, u64)> = vault
+            .stats()
+            .unwrap()
+            .quarantine
+            .iter()
+            .map(|batch| (batch.quarantined_at, batch.file_count, batch.bytes))
+            .collect();
+        assert_eq!(quarantine, [(2000, 1, 7), (1000, 2, 15)]);
+    }
+}
@@ -0,0 +1,266 @@
+//! Autocomplete for titles, tags and folders.
+//!
+//! The trie is built from the note index and the folder tree, and kept for
+//! as long as neither file changes, so a suggestion costs reading the two
+//! files rather than decrypting them. What was picked and when is kept in
+//! its own sealed file.
+
+use super::folders::{FolderTree, FOLDERS_FILE};
+use super::{Vault, INDEX_FILE};
+use crate::api::{Suggestion, SuggestionKind};
+use crate::index::Index;
+use crate::suggest::{self, Trie};
+use crate::{crypto, note};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+pub(super) const USAGE_FILE: &str = "usage";
+
+/// Uses remembered; the least valuable are forgotten first.
+const MAX_USAGE_ENTRIES: usize = 2000;
+/// What being among the most recently edited notes, the most used tags or
+/// the first folders counts for, against a use worth 1.
+const PRIOR_WEIGHT: f64 = 0.5;
+/// What matching from the first word on counts for.
+const START_WEIGHT: f64 = 0.25;
+
+static CACHE: Mutex<Option<Candidates>> = Mutex::new(None);
+
+/// Keyed by `usage_key`.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct UsageLog {
+    uses: BTreeMap<String, Usage>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Usage {
+    /// Uses so far, each decayed to `last_used_at`.
+    score: f64,
+    /// Milliseconds since the Unix epoch.
+    last_used_at: i64,
+}
+
+/// Everything that can be suggested, for one version of the index and
+/// folder tree.
+struct Candidates {
+    fingerprint: [u8; 32],
+    candidates: Vec<Candidate>,
+    trie: Trie,
+}
+
+struct Candidate {
+    suggestion: Suggestion,
+    /// The normalized text, to tell a match of its start.
+    normalized: String,
+    /// Between 0 and 1, how it ranks among its kind before any use: notes
+    /// by when they were edited, tags by how many notes carry them.
+    prior: f64,
+}
+
+impl Vault {
+    /// Up to `limit` suggestions for `prefix`, optionally of one kind only.
+    pub fn suggest(
+        &self,
+        prefix: &str,
+        kind: Option<SuggestionKind>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Suggestion>> {
+        let prefix = suggest::normalize(prefix);
+        let _guard = self.lock_reads()?;
+        let usage = self.load_usage()?;
+        let index = self.storage.read(INDEX_FILE)?;
+        let folders = match self.storage.read(FOLDERS_FILE) {
+            Ok(data) => Some(data),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.location().as_bytes());
+        hasher.update((index.len() as u64).to_le_bytes());
+        hasher.update(&index);
+        hasher.update(folders.as_deref().unwrap_or_default());
+        let fingerprint: [u8; 32] = hasher.finalize().into();
+
+        let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if cache
+            .as_ref()
+            .is_none_or(|cached| cached.fingerprint != fingerprint)
+        {
+            let index = Index::decode(&self.key, &index).context("note index is corrupted")?;
+            let tree = match folders {
+                Some(data) => {
+                    crypto::open_json(&self.key, &data).context("folder tree is corrupted")?
+                }
+                None => FolderTree::default(),
+            };
+            *cache = Some(Candidates::build(fingerprint, &index, &tree));
+        }
+        let cached = cache.as_ref().context("suggestions were not built")?;
+
+        let now = note::now_millis();
+        let long = prefix.chars().count() > suggest::MAX_KEY_CHARS;
+        let mut ranked: Vec<(f64, &Candidate)> = cached
+            .trie
+            .starting_with(&prefix)
+            .into_iter()
+            .map(|i| &cached.candidates[i as usize])
+            .filter(|candidate| kind.is_none_or(|kind| candidate.suggestion.kind == kind))
+            // The trie only knows the start of long prefixes.
+            .filter(|candidate| {
+                !long
+                    || suggest::keys(&candidate.suggestion.text)
+                        .iter()
+                        .any(|key| key.starts_with(&prefix))
+            })
+            .map(|candidate| {
+                let used = usage
+                    .uses
+                    .get(&usage_key(
+                        candidate.suggestion.kind,
+                        &candidate.suggestion.id,
+                    ))
+                    .map_or(0.0, |usage| {
+                        suggest::decayed(usage.score, usage.last_used_at, now)
+                    });
+                let start = if candidate.normalized.starts_with(&prefix) {
+                    START_WEIGHT
+                } else {
+                    0.0
+                };
+                (used + PRIOR_WEIGHT * candidate.prior + start, candidate)
+            })
+            .collect();
+        ranked.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.normalized.cmp(&b.normalized))
+        });
+        Ok(ranked
+            .into_iter()
+            .take(limit)
+            .map(|(_, candidate)| candidate.suggestion.clone())
+            .collect())
+    }
+
+    /// Counts a use of the note, tag or folder `id`.
+    pub fn record_suggestion_use(&self, kind: SuggestionKind, id: &str) -> anyhow::Result<()> {
+        let _guard = self.lock_writes()?;
+        let mut log = self.load_usage()?;
+        let now = note::now_millis();
+        let usage = log.uses.entry(usage_key(kind, id)).or_default();
+        usage.score = suggest::decayed(usage.score, usage.last_used_at, now) + 1.0;
+        usage.last_used_at = now;
+
+        if log.uses.len() > MAX_USAGE_ENTRIES {
+            let mut worth: Vec<(f64, String)> = log
+                .uses
+                .iter()
+                .map(|(key, usage)| {
+                    (
+                        suggest::decayed(usage.score, usage.last_used_at, now),
+                        key.clone(),
+                    )
+                })
+                .collect();
+            worth.sort_by(|a, b| a.0.total_cmp(&b.0));
+            for (_, key) in worth.into_iter().take(log.uses.len() - MAX_USAGE_ENTRIES) {
+                log.uses.remove(&key);
+            }
+        }
+
+        let data = crypto::seal_json(&self.key, &log)?;
+        self.storage
+            .write(USAGE_FILE, &data)
+            .context("failed to write suggestion usage")
+    }
+
+    fn load_usage(&self) -> anyhow::Result<UsageLog> {
+        match self.storage.read(USAGE_FILE) {
+            Ok(data) => {
+                crypto::open_json(&self.key, &data).context("suggestion usage is corrupted")
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(UsageLog::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl Candidates {
+    fn build(fingerprint: [u8; 32], index: &Index, tree: &FolderTree) -> Self {
+        let mut notes: Vec<_> = index.notes.values().collect();
+        notes.sort_by_key(|note| note.modified_at);
+        let mut tags: Vec<_> = index.tags.iter().collect();
+        tags.sort_by_key(|(_, ids)| ids.len());
+        let mut folders: Vec<(String, &str)> = tree
+            .folders
+            .keys()
+            .map(|id| (tree.path(id), id.as_str()))
+            .collect();
+        // Deepest first, so that top-level folders rank highest.
+        folders.sort_by_key(|(path, _)| std::cmp::Reverse(path.matches('/').count()));
+
+        let mut candidates = Vec::new();
+        let ranked = |i: usize, count: usize| (i + 1) as f64 / count as f64;
+        for (i, note) in notes.iter().enumerate() {
+            candidates.push((
+                SuggestionKind::Title,
+                note.title.clone(),
+                note.id.clone(),
+                ranked(i, notes.len()),
+            ));
+        }
+        for (i, (tag, _)) in tags.iter().enumerate() {
+            candidates.push((
+                SuggestionKind::Tag,
+                tag.to_string(),
+                tag.to_string(),
+                ranked(i, tags.len()),
+            ));
+        }
+        for (i, (path, id)) in folders.iter().enumerate() {
+            candidates.push((
+                SuggestionKind::Folder,
+                path.clone(),
+                id.to_string(),
+                ranked(i, folders.len()),
+            ));
+        }
+
+        let mut trie = Trie::default();
+        let candidates: Vec<Candidate> = candidates
+            .into_iter()
+            .filter(|(_, text, _, _)| !text.trim().is_empty())
+            .enumerate()
+            .map(|(i, (kind, text, id, prior))| {
+                for key in suggest::keys(&text) {
+                    trie.insert(&key, i as u32);
+                }
+                Candidate {
+                    normalized: suggest::normalize(&text),
+                    suggestion: Suggestion { kind, text, id },
+                    prior,
+                }
+            })
+            .collect();
+        Candidates {
+            fingerprint,
+            candidates,
+            trie,
+        }
+    }
+}
+
+fn usage_key(kind: SuggestionKind, id: &str) -> String {
+    let kind = match kind {
+        SuggestionKind::Title => "note",
+        SuggestionKind::Tag => "tag",
+        SuggestionKind::Folder => "folder",
+    };
+    format!("{}:{}", kind, id)
+}
@@ -0,0 +1,153 @@
+use super::Vault;
+use crate::api::{NoteSummary, TagCount, TagQuery};
+use crate::note;
+use anyhow::bail;
+use std::collections::BTreeSet;
+
+impl Vault {
+    /// Every tag in use, with the number of notes carrying it. Spellings
+    /// that `tag_matches` takes for one tag are listed once, under the
+    /// first in sort order.
+    pub fn list_tags(&self) -> anyhow::Result<Vec<TagCount>> {
+        let _guard = self.lock_reads()?;
+        let index = self.load_index()?;
+        let mut tags: Vec<TagCount> = Vec::new();
+        for tag in index.tags.keys() {
+            if !tags
+                .iter()
+                .any(|listed| note::tag_matches(&listed.tag, tag))
+            {
+                tags.push(TagCount {
+                    tag: tag.clone(),
+                    count: index.tagged(tag).len() as u32,
+                });
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Renames `from` to `to` on every note. When a note already has `to`,
+    /// the two simply collapse into one. Returns the number of notes changed.
+    pub fn rename_tag(&self, from: &str, to: &str) -> anyhow::Result<u32> {
+        self.merge_tags(&[from.to_string()], to)
+    }
+
+    /// Replaces every tag in `sources` with `target`. Returns the number of
+    /// notes changed.
+    pub fn merge_tags(&self, sources: &[String], target: &str) -> anyhow::Result<u32> {
+        let target = target.trim();
+        if target.is_empty() {
+            bail!("tag cannot be empty");
+        }
+        let sources: BTreeSet<&str> = sources
+            .iter()
+            .map(|tag| tag.trim())
+            .filter(|tag| *tag != target)
+            .collect();
+
+        let _guard = self.lock_writes()?;
+        let index = self.load_index()?;
+        let affected: BTreeSet<String> = sources.iter().flat_map(|tag| index.tagged(tag)).collect();
+
+        for id in &affected {
+            self.update_note_locked(id, |note| {
+                for tag in note.tags.iter_mut() {
+                    if sources.iter().any(|source| note::tag_matches(tag, source)) {
+                        *tag = target.to_string();
+                    }
+                }
+            })?;
+        }
+        Ok(affected.len() as u32)
+    }
+
+    pub fn list_notes_by_tags(&self, query: &TagQuery) -> anyhow::Result<Vec<NoteSummary>> {
+        // Tags are stored trimmed, so the query must be too.
+        let all_of = note::normalize_tags(query.all_of.clone());
+        let any_of = note::normalize_tags(query.any_of.clone());
+        let none_of = note::normalize_tags(query.none_of.clone());
+
+        let _guard = self.lock_reads()?;
+        let index = self.load_index()?;
+
+        let mut ids: BTreeSet<String> = index.notes.keys().cloned().collect();
+        for tag in &all_of {
+            ids = ids.intersection(&index.tagged(tag)).cloned().collect();
+        }
+        if !any_of.is_empty() {
+            let any: BTreeSet<String> = any_of.iter().flat_map(|tag| index.tagged(tag)).collect();
+            ids = ids.intersection(&any).cloned().collect();
+        }
+        for tag in &none_of {
+            ids = ids.difference(&index.tagged(tag)).cloned().collect();
+        }
+
+        Ok(self
+            .list_notes()?
+            .into_iter()
+            .filter(|note| ids.contains(&note.id))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Note, StorageKind};
+    use std::path::Path;
+
+    fn save(vault: &Vault, title: &str, tags: &[&str]) -> String {
+        let note = Note {
+            title: title.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Note::default()
+        };
+        vault.save_note(note, "").unwrap().saved.unwrap().id
+    }
+
+    #[test]
+    fn tag_filters_and_tag_queries_agree_on_case() {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        let upper = save(&vault, "Upper", &["Work", " WORK "]);
+        let lower = save(&vault, "Lower", &["work"]);
+        save(&vault, "Other", &["home"]);
+        assert_eq!(vault.read_note(&upper).unwrap().tags, ["Work"]);
+        let listed: Vec<(String, u32)> = vault
+            .list_tags()
+            .unwrap()
+            .into_iter()
+            .map(|tag| (tag.tag, tag.count))
+            .collect();
+        assert_eq!(listed, [("Work".to_string(), 2), ("home".to_string(), 1)]);
+
+        let expected = BTreeSet::from([upper, lower]);
+        let by_query: BTreeSet<String> = vault
+            .list_notes_by_tags(&TagQuery {
+                all_of: vec!["wOrK".to_string()],
+                ..TagQuery::default()
+            })
+            .unwrap()
+            .into_iter()
+            .map(|note| note.id)
+            .collect();
+        let by_filter: BTreeSet<String> = vault
+            .search("tag:wOrK", 10)
+            .unwrap()
+            .hits
+            .into_iter()
+            .map(|hit| hit.note.id)
+            .collect();
+        assert_eq!(by_query, expected);
+        assert_eq!(by_filter, expected);
+    }
+
+    #[test]
+    fn renaming_a_tag_catches_every_spelling() {
+        let vault = Vault::open(StorageKind::Memory, Path::new("")).unwrap();
+        let upper = save(&vault, "Upper", &["Work"]);
+        let lower = save(&vault, "Lower", &["work", "home"]);
+        assert_eq!(vault.rename_tag("WORK", "job").unwrap(), 2);
+        assert_eq!(vault.read_note(&upper).unwrap().tags, ["job"]);
+        assert_eq!(vault.read_note(&lower).unwrap().tags, ["job", "home"]);
+    }
+}
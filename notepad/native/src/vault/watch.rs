@@ -0,0 +1,366 @@
+//! Turns file changes under a vault into note-level events.
+//!
+//! Raw events only say which files changed, so after a burst of them has
+//! settled the watcher rereads the note records among them and compares
+//! them with the previous scan. Bursts that touch no note record, such as
+//! this process's own writes of chunks, history and indexes, are skipped
+//! without reading anything, and so are changes to notes this process made
+//! itself. Notes changed behind the index's back, as a sync tool does, are
+//! put back into it. Where events do not name the files, as for a SQLite
+//! vault, every record is reread instead.
+
+use super::search::searchable_text;
+use super::{Vault, NOTES_PREFIX, NOTE_EXTENSION};
+use crate::api::{NoteSummary, VaultEvent, VaultEventKind};
+use crate::note;
+use anyhow::bail;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// How long the files must be quiet before a burst of events is processed.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A note's `(revision, modified_at)`, which changes with every save.
+type NoteState = (u32, i64);
+
+/// Bumped by every `watch`, which retires the watcher started before it.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// The state this process last wrote for each note, or
+/// `None` once it deleted it.
+static OWN_WRITES: Mutex<Option<HashMap<String, Option<NoteState>>>> = Mutex::new(None);
+
+/// Records a write by this process, so the watcher does not report it back.
+pub(super) fn note_written(id: &str, state: Option<NoteState>) {
+    OWN_WRITES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(HashMap::new)
+        .insert(id.to_string(), state);
+}
+
+fn own_write(id: &str) -> Option<Option<NoteState>> {
+    OWN_WRITES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()?
+        .get(id)
+        .copied()
+}
+
+#[derive(Clone)]
+struct Scan {
+    notes: BTreeMap<String, NoteSummary>,
+    states: BTreeMap<String, NoteState>,
+    /// Keys of copies sync tools make when two devices changed a note.
+    conflict_copies: BTreeSet<String>,
+}
+
+impl Vault {
+    /// Calls `on_event` from a background thread for every change to the
+    /// vault's notes, until it returns `false` or `watch` is called again.
+    pub fn watch(
+        &self,
+        mut on_event: impl FnMut(VaultEvent) -> bool + Send + 'static,
+    ) -> anyhow::Result<()> {
+        let path = match self.storage.watch_path() {
+            Some(path) => path,
+            None => bail!("this vault's storage cannot be watched"),
+        };
+        let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = sender.send(event);
+        })?;
+        watcher.watch(&path, RecursiveMode::Recursive)?;
+        // A directory's events name the files that changed; a database
+        // file's say nothing about which records did.
+        let keyed = path.is_dir();
+        // Some platforms report events under the resolved path.
+        let roots = vec![path.canonicalize().unwrap_or_else(|_| path.clone()), path];
+        let mut previous = self.scan()?;
+
+        let vault = self.clone();
+        thread::spawn(move || {
+            // Owned by the thread so it stops when the thread does.
+            let _watcher = watcher;
+            loop {
+                let mut burst = Burst::default();
+                match events.recv_timeout(POLL_INTERVAL) {
+                    Ok(event) => burst.add(event),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        if GENERATION.load(Ordering::SeqCst) != generation {
+                            return;
+                        }
+                        continue;
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                }
+                while let Ok(event) = events.recv_timeout(DEBOUNCE) {
+                    burst.add(event);
+                }
+                if GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                let scan = match burst.changed_keys(&roots).filter(|_| keyed) {
+                    Some(keys) if !keys.iter().any(|key| is_note_file(key)) => continue,
+                    Some(keys) => vault.rescan(&previous, &keys),
+                    None => vault.scan(),
+                };
+                let scan = match scan {
+                    Ok(scan) => scan,
+                    Err(e) => {
+                        eprintln!("[Rust] Failed to rescan the vault: {:#}", e);
+                        continue;
+                    }
+                };
+                let changes = diff(&previous, &scan);
+                if let Err(e) = vault.sync_index(&scan, &changes) {
+                    eprintln!(
+                        "[Rust] Failed to update the index after external changes: {:#}",
+                        e
+                    );
+                }
+                previous = scan;
+                for event in changes {
+                    if !on_event(event) {
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn scan(&self) -> anyhow::Result<Scan> {
+        let _guard = self.lock_reads()?;
+        let mut scan = Scan {
+            notes: BTreeMap::new(),
+            states: BTreeMap::new(),
+            conflict_copies: BTreeSet::new(),
+        };
+        for id in self.note_ids()? {
+            match self.read_note_record(&id) {
+                Ok(record) => {
+                    let note = record.note;
+                    scan.states
+                        .insert(id.clone(), (note.revision, note.modified_at));
+                    scan.notes.insert(id, NoteSummary::from(&note));
+                }
+                Err(e) => eprintln!("[Rust] Watcher skipped note {:?}: {:#}", id, e),
+            }
+        }
+        for key in self.storage.list(NOTES_PREFIX)? {
+            if conflict_copy_of(&key).is_some() {
+                scan.conflict_copies.insert(key);
+            }
+        }
+        Ok(scan)
+    }
+
+    /// `previous` with the note files among `keys` read again.
+    fn rescan(&self, previous: &Scan, keys: &BTreeSet<String>) -> anyhow::Result<Scan> {
+        let _guard = self.lock_reads()?;
+        let mut scan = previous.clone();
+        for key in keys {
+            if conflict_copy_of(key).is_some() {
+                if self.storage.exists(key)? {
+                    scan.conflict_copies.insert(key.clone());
+                } else {
+                    scan.conflict_copies.remove(key);
+                }
+                continue;
+            }
+            let Some(id) = note_id_of(key) else {
+                continue;
+            };
+            match self.read_note_record(&id) {
+                Ok(record) => {
+                    let note = record.note;
+                    scan.states
+                        .insert(id.clone(), (note.revision, note.modified_at));
+                    scan.notes.insert(id, NoteSummary::from(&note));
+                }
+                Err(_) if !self.storage.exists(key)? => {
+                    scan.states.remove(&id);
+                    scan.notes.remove(&id);
+                }
+                Err(e) => eprintln!("[Rust] Watcher skipped note {:?}: {:#}", id, e),
+            }
+        }
+        Ok(scan)
+    }
+
+    /// Brings the index in line with notes changed by something that did not
+    /// update it. Notes that cannot be read are left for the next change.
+    fn sync_index(&self, scan: &Scan, changes: &[VaultEvent]) -> anyhow::Result<()> {
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let _guard = self.lock_writes()?;
+        let mut index = self.load_index()?;
+        let mut search_index = self.load_search_index()?;
+        let mut changed = false;
+        for event in changes {
+            let id = &event.note_id;
+            match scan.notes.get(id) {
+                Some(summary) => {
+                    let stale = index
+                        .notes
+                        .get(id)
+                        .is_none_or(|indexed| indexed.modified_at != summary.modified_at);
+                    if stale {
+                        let note = match self.read_note(id) {
+                            Ok(note) => note,
+                            Err(e) => {
+                                eprintln!("[Rust] Not indexing note {:?}: {:#}", id, e);
+                                continue;
+                            }
+                        };
+                        index.upsert(&note);
+                        search_index.insert(id, &searchable_text(&note));
+                        changed = true;
+                    }
+                }
+                None if index.notes.contains_key(id) => {
+                    index.remove(id);
+                    search_index.remove(id);
+                    changed = true;
+                }
+                None => {}
+            }
+        }
+        if changed {
+            self.save_index(&index)?;
+            self.save_search_index(&search_index)?;
+        }
+        Ok(())
+    }
+}
+
+/// The files changed by one burst of events.
+#[derive(Default)]
+struct Burst {
+    paths: BTreeSet<PathBuf>,
+    /// Set when an event named no file, or events were lost.
+    unknown: bool,
+}
+
+impl Burst {
+    fn add(&mut self, event: notify::Result<Event>) {
+        match event {
+            // Reads, the watcher's own included, change nothing.
+            Ok(event) if matches!(event.kind, EventKind::Access(_)) => {}
+            Ok(event) => {
+                self.unknown |= event.need_rescan() || event.paths.is_empty();
+                self.paths.extend(event.paths);
+            }
+            Err(e) => {
+                eprintln!("[Rust] Vault watcher error: {}", e);
+                self.unknown = true;
+            }
+        }
+    }
+
+    /// The storage keys of the changed files below one of `roots`, or
+    /// `None` when the burst cannot be put down to particular keys.
+    fn changed_keys(&self, roots: &[PathBuf]) -> Option<BTreeSet<String>> {
+        if self.unknown {
+            return None;
+        }
+        let mut keys = BTreeSet::new();
+        for path in &self.paths {
+            let parts = roots
+                .iter()
+                .find_map(|root| path.strip_prefix(root).ok())?
+                .iter()
+                .map(|part| part.to_str())
+                .collect::<Option<Vec<_>>>()?;
+            // The root itself, whose own changes say nothing about notes.
+            if !parts.is_empty() {
+                keys.insert(parts.join("/"));
+            }
+        }
+        Some(keys)
+    }
+}
+
+/// The id of the note stored under `key`, if it is a note file.
+fn note_id_of(key: &str) -> Option<String> {
+    let id = key
+        .strip_prefix(NOTES_PREFIX)?
+        .strip_suffix(&format!(".{}", NOTE_EXTENSION))?;
+    note::parse_id(id).ok().filter(|parsed| parsed == id)
+}
+
+fn is_note_file(key: &str) -> bool {
+    note_id_of(key).is_some() || conflict_copy_of(key).is_some()
+}
+
+fn diff(previous: &Scan, current: &Scan) -> Vec<VaultEvent> {
+    let mut events = Vec::new();
+    for (id, state) in &current.states {
+        let own = own_write(id);
+        let kind = match previous.states.get(id) {
+            _ if own == Some(Some(*state)) => continue,
+            Some(old) if old == state => continue,
+            // Another writer produced the same revision this process wrote:
+            // both saved on top of the same version.
+            Some(_) if matches!(own, Some(Some((revision, _))) if revision == state.0) => {
+                VaultEventKind::Conflict
+            }
+            Some(_) => VaultEventKind::Modified,
+            None => VaultEventKind::Created,
+        };
+        events.push(VaultEvent {
+            kind,
+            note_id: id.clone(),
+            summary: current.notes.get(id).cloned(),
+            conflict_copy: None,
+        });
+    }
+    for id in previous.states.keys() {
+        if !current.states.contains_key(id) && own_write(id) != Some(None) {
+            events.push(VaultEvent {
+                kind: VaultEventKind::Deleted,
+                note_id: id.clone(),
+                summary: None,
+                conflict_copy: None,
+            });
+        }
+    }
+    for key in current
+        .conflict_copies
+        .difference(&previous.conflict_copies)
+    {
+        let id = conflict_copy_of(key).expect("only conflict copies are collected");
+        events.push(VaultEvent {
+            kind: VaultEventKind::Conflict,
+            summary: current.notes.get(&id).cloned(),
+            note_id: id,
+            conflict_copy: Some(key.clone()),
+        });
+    }
+    events
+}
+
+/// The note a sync tool's conflict copy belongs to, e.g.
+/// `<id>.sync-conflict-20240101-120000-ABCDEFG.note` or
+/// `<id> (conflicted copy 2024-01-01).note`.
+fn conflict_copy_of(key: &str) -> Option<String> {
+    let name = key.strip_prefix(NOTES_PREFIX)?;
+    if name.contains('/') || !name.ends_with(&format!(".{}", NOTE_EXTENSION)) {
+        return None;
+    }
+    let id = note::parse_id(name.get(..36)?).ok()?;
+    let rest = &name[36..];
+    (rest != format!(".{}", NOTE_EXTENSION)).then_some(id)
+}